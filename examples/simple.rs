@@ -183,11 +183,8 @@ fn interpret_input(
             writeln!(&mut io::stdout(), "Done.");
         }
         "signals" => {
-            let mut nb = 1i32;
-
-            for sig in signals {
+            for (nb, sig) in (1i32..).zip(signals.iter()) {
                 writeln!(&mut io::stdout(), "{nb:2}:{sig:?}");
-                nb += 1;
             }
         }
         "cpus" => {
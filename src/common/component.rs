@@ -14,6 +14,8 @@ use crate::{ComponentInner, ComponentsInner};
 /// ```
 pub struct Components {
     pub(crate) inner: ComponentsInner,
+    // Whether the temperature of each component changed during the last refresh.
+    changed: Vec<bool>,
 }
 
 impl Default for Components {
@@ -32,6 +34,7 @@ impl From<Vec<Component>> for Components {
     fn from(components: Vec<Component>) -> Self {
         Self {
             inner: ComponentsInner::from_vec(components),
+            changed: Vec::new(),
         }
     }
 }
@@ -86,6 +89,7 @@ impl Components {
     pub fn new() -> Self {
         Self {
             inner: ComponentsInner::new(),
+            changed: Vec::new(),
         }
     }
 
@@ -145,18 +149,83 @@ impl Components {
     /// components.refresh(false);
     /// ```
     pub fn refresh(&mut self, remove_not_listed_components: bool) {
+        let previous_temperatures = self
+            .list()
+            .iter()
+            .map(|c| c.temperature())
+            .collect::<Vec<_>>();
         self.inner.refresh();
+        self.changed = changed_temperatures(
+            &previous_temperatures,
+            self.list().iter().map(|c| c.temperature()),
+        );
         if remove_not_listed_components {
             // Remove interfaces which are gone.
+            let mut changed = std::mem::take(&mut self.changed).into_iter();
             self.inner.components.retain_mut(|c| {
+                let changed = changed.next().unwrap_or(false);
                 if !c.inner.updated {
                     return false;
                 }
+                self.changed.push(changed);
                 c.inner.updated = false;
                 true
             });
         }
     }
+
+    /// Refreshes only the component whose identifier is `id` (as returned by
+    /// [`Component::id`]) and returns `true` if it was found.
+    ///
+    /// It allows to poll a single sensor without reading all the other ones. If the component
+    /// wasn't listed yet, you need to call [`Components::refresh`] first.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// let id = "/sys/class/hwmon/hwmon2/temp1_input";
+    /// // We wait some time...
+    /// if components.refresh_component(id) {
+    ///     println!("{:?}", components.iter().find(|c| c.id() == id));
+    /// }
+    /// ```
+    pub fn refresh_component(&mut self, id: &str) -> bool {
+        let Some(pos) = self.list().iter().position(|c| c.id() == id) else {
+            return false;
+        };
+        let component = &mut self.list_mut()[pos];
+        let previous_temperature = component.temperature();
+        component.refresh();
+        let changed = previous_temperature != component.temperature();
+        if let Some(c) = self.changed.get_mut(pos) {
+            *c = changed;
+        }
+        true
+    }
+
+    /// Returns the components whose temperature changed during the last call to
+    /// [`Components::refresh`] (including the components which were added by it). The entry of a
+    /// component refreshed with [`Components::refresh_component`] is updated as well.
+    ///
+    /// It's useful to only update what needs to be updated when displaying a lot of components.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// // We wait some time...
+    /// components.refresh(false);
+    /// for component in components.changed() {
+    ///     println!("{}: {:?}°C", component.label(), component.temperature());
+    /// }
+    /// ```
+    pub fn changed(&self) -> impl Iterator<Item = &Component> {
+        self.list()
+            .iter()
+            .zip(&self.changed)
+            .filter_map(|(component, changed)| changed.then_some(component))
+    }
 }
 
 /// Getting a component temperature information.
@@ -269,6 +338,27 @@ impl Component {
         self.inner.label()
     }
 
+    /// Returns an identifier of the component which, unlike its label, is unique and stays the
+    /// same across refreshes.
+    ///
+    /// On Linux, it is the path of the `hwmon` file the temperature is read from (like
+    /// `/sys/class/hwmon/hwmon2/temp1_input`). On FreeBSD, it is the name of the `sysctl` the
+    /// temperature is read from (like `dev.cpu.0.temperature`).
+    ///
+    /// ⚠️ On other systems, it is the same as [`Component::label`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}: {}", component.id(), component.label());
+    /// }
+    /// ```
+    pub fn id(&self) -> &str {
+        self.inner.id()
+    }
+
     /// Refreshes component.
     ///
     /// ```no_run
@@ -284,8 +374,23 @@ impl Component {
     }
 }
 
+// Returns, for each temperature of `components`, whether it is different from the one at the same
+// position in `previous` (or if the component is new). Components are matched by position since
+// their labels aren't unique: a refresh only adds the new components at the end of the list, the
+// ones which are gone being removed afterwards.
+fn changed_temperatures(
+    previous: &[Option<f32>],
+    components: impl Iterator<Item = Option<f32>>,
+) -> Vec<bool> {
+    components
+        .enumerate()
+        .map(|(pos, temperature)| previous.get(pos) != Some(&temperature))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::changed_temperatures;
     use crate::*;
 
     #[test]
@@ -294,4 +399,42 @@ mod tests {
         components.refresh(false);
         components.refresh(false);
     }
+
+    #[test]
+    fn test_components_changed() {
+        let mut components = Components::new();
+        assert_eq!(components.changed().count(), 0);
+        components.refresh(false);
+        // All the components are new.
+        assert_eq!(components.changed().count(), components.len());
+        components.refresh(true);
+        assert!(components.changed().count() <= components.len());
+
+        let components = Components::from(Vec::from(components));
+        assert_eq!(components.changed().count(), 0);
+    }
+
+    #[test]
+    fn test_changed_temperatures() {
+        // Two NVMe drives, both labelled "Composite", then a CPU and a sensor without temperature.
+        let previous = [Some(40.), Some(45.), Some(50.), None];
+        // The second "Composite" changed and a new sensor was added.
+        let current = [Some(40.), Some(46.), Some(50.), None, Some(25.)];
+        assert_eq!(
+            changed_temperatures(&previous, current.into_iter()),
+            [false, true, false, false, true]
+        );
+        assert!(changed_temperatures(&[], current.into_iter())
+            .into_iter()
+            .all(|changed| changed));
+    }
+
+    #[test]
+    fn test_refresh_component() {
+        let mut components = Components::new_with_refreshed_list();
+        assert!(!components.refresh_component("sysinfo unknown component"));
+        if let Some(id) = components.first().map(|c| c.id().to_owned()) {
+            assert!(components.refresh_component(&id));
+        }
+    }
 }
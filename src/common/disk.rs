@@ -1,8 +1,10 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
 use std::fmt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use crate::common::impl_get_set::impl_get_set;
 use crate::DiskUsage;
@@ -78,6 +80,42 @@ impl Disk {
         self.inner.mount_point()
     }
 
+    /// Returns the line of `/proc/mounts` this disk was read from during the last refresh.
+    ///
+    /// It allows to get information that `sysinfo` doesn't provide (like the mount options)
+    /// without having to read the file again.
+    ///
+    /// The disks listed with [`Disks::from_process_namespace`] are read from
+    /// `/proc/<pid>/mountinfo` instead, so their line has the format of this file (which starts
+    /// with the mount ID and the parent ID, and has the optional fields and a `-` separator before
+    /// the filesystem type), described in
+    /// [`proc_pid_mountinfo(5)`](https://man7.org/linux/man-pages/man5/proc_pid_mountinfo.5.html).
+    ///
+    /// ⚠️ This method is only available with the `raw-access` feature and is only implemented
+    /// for Linux. It always returns `None` for all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("{:?}", disk.raw_mount_line());
+    /// }
+    /// ```
+    #[cfg(feature = "raw-access")]
+    pub fn raw_mount_line(&self) -> Option<&str> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.raw_mount_line()
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns the total disk size, in bytes.
     ///
     /// ```no_run
@@ -106,6 +144,73 @@ impl Disk {
         self.inner.available_space()
     }
 
+    /// Returns the space (in bytes) used by the data and metadata of the filesystem, counting
+    /// each of them once, or `None` if the filesystem doesn't report the space taken by the
+    /// copies it stores.
+    ///
+    /// It is refreshed with [`DiskRefreshKind::storage`]. Compare it with
+    /// [`Disk::raw_used_space`] to know how much space the copies required by the RAID profile
+    /// take (for example, data is stored twice with `RAID1`).
+    ///
+    /// This is the space used once compressed, so it doesn't tell how much space the files
+    /// would take uncompressed (which is what `du` shows).
+    ///
+    /// ⚠️ This method is only implemented for Btrfs on Linux. It always returns `None` for all
+    /// other filesystems (including ZFS) and systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if let (Some(data), Some(raw)) = (disk.data_used_space(), disk.raw_used_space()) {
+    ///         println!("[{:?}] {data}B stored using {raw}B", disk.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn data_used_space(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.data_used_space()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the space (in bytes) actually used on the underlying device(s) by the filesystem,
+    /// counting every copy of its data and metadata, or `None` if the filesystem doesn't report
+    /// it.
+    ///
+    /// Take a look at [`Disk::data_used_space`] for more information.
+    ///
+    /// ⚠️ This method is only implemented for Btrfs on Linux. It always returns `None` for all
+    /// other filesystems (including ZFS) and systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.raw_used_space());
+    /// }
+    /// ```
+    pub fn raw_used_space(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.raw_used_space()
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns `true` if the disk is removable.
     ///
     /// ```no_run
@@ -134,6 +239,153 @@ impl Disk {
         self.inner.is_read_only()
     }
 
+    /// Returns `true` if the device of the disk supports TRIM (also known as "discard"), or
+    /// `None` if it isn't a block device or if this information couldn't be retrieved.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] supports TRIM: {:?}", disk.name(), disk.supports_trim());
+    /// }
+    /// ```
+    pub fn supports_trim(&self) -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.supports_trim()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the active I/O scheduler of the device of the disk (like `mq-deadline`, `bfq` or
+    /// `none`), or `None` if it isn't a block device or if this information couldn't be
+    /// retrieved.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] I/O scheduler: {:?}", disk.name(), disk.io_scheduler());
+    /// }
+    /// ```
+    pub fn io_scheduler(&self) -> Option<String> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.io_scheduler()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the disk is a bind mount (of a folder of another mount), or `None` if
+    /// this information couldn't be retrieved.
+    ///
+    /// It is detected from the mounted folder of the file system, so the bind mount of the root
+    /// of a file system (like `mount --bind /mnt/disk /mnt/other`) can't be told apart from the
+    /// original mount and returns `false`.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] bind mount: {:?}", disk.mount_point(), disk.is_bind_mount());
+    /// }
+    /// ```
+    pub fn is_bind_mount(&self) -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.is_bind_mount()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the propagation type of the mount, or `None` if this information couldn't be
+    /// retrieved.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] propagation: {:?}", disk.mount_point(), disk.propagation());
+    /// }
+    /// ```
+    pub fn propagation(&self) -> Option<MountPropagation> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.propagation()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the disk is mounted with continuous TRIM enabled (the `discard` mount
+    /// option).
+    ///
+    /// Note that TRIM can also be done periodically (with `fstrim` for example) even if it
+    /// returns `false`.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] TRIM enabled: {:?}", disk.name(), disk.trim_enabled());
+    /// }
+    /// ```
+    pub fn trim_enabled(&self) -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                Some(self.inner.trim_enabled())
+            } else {
+                None
+            }
+        }
+    }
+
     /// Updates the disk' information with everything loaded.
     ///
     /// Equivalent to <code>[Disk::refresh_specifics]\([DiskRefreshKind::everything]\())</code>.
@@ -177,6 +429,68 @@ impl Disk {
     pub fn usage(&self) -> DiskUsage {
         self.inner.usage()
     }
+
+    /// Returns the average time it took to complete a read request between the last two
+    /// refreshes of the disk I/O usage, or `None` if no read request was completed in this
+    /// interval.
+    ///
+    /// It corresponds to the `r_await` column of `iostat`. After the first refresh, it is the
+    /// average since the system boot.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] read latency: {:?}", disk.name(), disk.average_read_latency());
+    /// }
+    /// ```
+    pub fn average_read_latency(&self) -> Option<Duration> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.average_read_latency()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the average time it took to complete a write request between the last two
+    /// refreshes of the disk I/O usage, or `None` if no write request was completed in this
+    /// interval.
+    ///
+    /// It corresponds to the `w_await` column of `iostat`. After the first refresh, it is the
+    /// average since the system boot.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] write latency: {:?}", disk.name(), disk.average_write_latency());
+    /// }
+    /// ```
+    pub fn average_write_latency(&self) -> Option<Duration> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.average_write_latency()
+            } else {
+                None
+            }
+        }
+    }
 }
 
 /// Disks interface.
@@ -293,6 +607,43 @@ impl Disks {
         disks
     }
 
+    /// Creates a new [`Disks`][crate::Disks] type with the disks seen from the mount namespace
+    /// of the process `pid`, which is useful to get the storage a containerized process
+    /// actually sees.
+    ///
+    /// The mount points are the ones of the namespace of the process. Later calls to
+    /// [`Disks::refresh`] keep using this mount namespace.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns an empty list for all
+    /// other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Disks, Pid};
+    ///
+    /// let disks = Disks::from_process_namespace(Pid::from(1337));
+    /// for disk in disks.list() {
+    ///     println!("{:?}: {:?}", disk.name(), disk.mount_point());
+    /// }
+    /// ```
+    #[cfg(feature = "system")]
+    pub fn from_process_namespace(pid: crate::Pid) -> Self {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                let mut disks = Self {
+                    inner: crate::DisksInner::from_process_namespace(pid),
+                };
+                disks.refresh_specifics(false, DiskRefreshKind::everything());
+                disks
+            } else {
+                let _pid = pid;
+                Self::new()
+            }
+        }
+    }
+
     /// Returns the disks list.
     ///
     /// ```no_run
@@ -322,6 +673,153 @@ impl Disks {
         self.inner.list_mut()
     }
 
+    /// Returns the disk on which the given `path` is located, meaning the disk whose mount point
+    /// is the longest prefix of `path`. Returns `None` if no disk matches.
+    ///
+    /// With nested mounts (for example `/` and `/home`), the most specific mount point wins.
+    ///
+    /// Note that `path` is compared as is: it isn't canonicalized, so symlinks are not resolved
+    /// and `..` components are not removed. Use [`std::fs::canonicalize`] first if needed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// if let Some(disk) = disks.disk_for_path("/var/lib/docker".as_ref()) {
+    ///     println!("{:?} is mounted on {:?}", disk.name(), disk.mount_point());
+    /// }
+    /// ```
+    pub fn disk_for_path(&self, path: &Path) -> Option<&Disk> {
+        self.list()
+            .iter()
+            .filter(|disk| path.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().components().count())
+    }
+
+    /// Returns the sum of the total space (in bytes) of the disks.
+    ///
+    /// The same device can be mounted at several places (with bind mounts or btrfs subvolumes
+    /// for example), so disks with the same (non-empty) [`name`](Disk::name) and the same
+    /// [`total_space`](Disk::total_space) are only counted once. Partitions of a same physical
+    /// disk have different names, so they are all counted.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// println!("{}/{} B available", disks.available_space(), disks.total_space());
+    /// ```
+    pub fn total_space(&self) -> u64 {
+        self.unique_disks().map(Disk::total_space).sum()
+    }
+
+    /// Returns the sum of the available space (in bytes) of the disks.
+    ///
+    /// Disks are deduplicated the same way as in [`Disks::total_space`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// println!("{}/{} B available", disks.available_space(), disks.total_space());
+    /// ```
+    pub fn available_space(&self) -> u64 {
+        self.unique_disks().map(Disk::available_space).sum()
+    }
+
+    /// Returns the disks sorted by used space percentage, from the fullest to the emptiest.
+    ///
+    /// Disks with a [`total_space`](Disk::total_space) of `0` are considered empty (and so come
+    /// last). Disks with the same percentage keep the order of [`Disks::list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.sorted_by_usage() {
+    ///     println!("{:?}: {} B available", disk.mount_point(), disk.available_space());
+    /// }
+    /// ```
+    pub fn sorted_by_usage(&self) -> Vec<&Disk> {
+        let mut disks = self.list().iter().collect::<Vec<_>>();
+        disks.sort_by(|a, b| used_ratio(b).total_cmp(&used_ratio(a)));
+        disks
+    }
+
+    /// Returns the disks sorted by [`available_space`](Disk::available_space), from the one
+    /// with the least free space to the one with the most.
+    ///
+    /// Disks with the same available space keep the order of [`Disks::list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.sorted_by_free_space() {
+    ///     println!("{:?}: {} B available", disk.mount_point(), disk.available_space());
+    /// }
+    /// ```
+    pub fn sorted_by_free_space(&self) -> Vec<&Disk> {
+        let mut disks = self.list().iter().collect::<Vec<_>>();
+        disks.sort_by_key(|disk| disk.available_space());
+        disks
+    }
+
+    /// Returns the I/O usage of each disk, indexed by mount point.
+    ///
+    /// It allows to save the I/O counters (for example with the `serde` feature) and to give
+    /// them back to [`Disks::restore_usages`] after a restart of your program, so the first
+    /// refresh computes the usage since the last refresh before the restart instead of since
+    /// the boot.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for (mount_point, usage) in disks.usages() {
+    ///     println!("{mount_point:?}: {} bytes read", usage.total_read_bytes);
+    /// }
+    /// ```
+    pub fn usages(&self) -> HashMap<PathBuf, DiskUsage> {
+        self.list()
+            .iter()
+            .map(|disk| (disk.mount_point().to_path_buf(), disk.usage()))
+            .collect()
+    }
+
+    /// Restores the I/O counters of the disks from `usages` (which was returned by
+    /// [`Disks::usages`]), so that the next refresh computes the usage since then.
+    ///
+    /// Only the total counters are used and the disks which aren't listed in `usages` are left
+    /// untouched. It needs to be called after the disks list was refreshed and before the next
+    /// refresh of the I/O usage. If the counters went back in the meantime (for example, if the
+    /// system was rebooted), the usage is `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// let usages = disks.usages();
+    /// // Restart of the program...
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// disks.restore_usages(&usages);
+    /// disks.refresh_specifics(true, DiskRefreshKind::nothing().with_io_usage());
+    /// ```
+    pub fn restore_usages(&mut self, usages: &HashMap<PathBuf, DiskUsage>) {
+        for disk in self.list_mut() {
+            if let Some(usage) = usages.get(disk.mount_point()) {
+                disk.inner.restore_usage(usage);
+            }
+        }
+    }
+
+    fn unique_disks(&self) -> impl Iterator<Item = &Disk> {
+        let mut seen = HashSet::new();
+        self.list().iter().filter(move |disk| {
+            disk.name().is_empty() || seen.insert((disk.name(), disk.total_space()))
+        })
+    }
+
     /// Refreshes the listed disks' information.
     ///
     /// Equivalent to <code>[Disks::refresh_specifics]\([DiskRefreshKind::everything]\())</code>.
@@ -392,6 +890,59 @@ impl fmt::Display for DiskKind {
     }
 }
 
+// Returns the used space of `disk`, between `0.` and `1.`.
+fn used_ratio(disk: &Disk) -> f64 {
+    let total = disk.total_space();
+    if total == 0 {
+        return 0.;
+    }
+    total.saturating_sub(disk.available_space()) as f64 / total as f64
+}
+
+/// Propagation type of a mount, which tells how mount and unmount events are shared with other
+/// mounts.
+///
+/// This type is returned by [`Disk::propagation`](`crate::Disk::propagation`). More information
+/// about it can be found in the [kernel documentation].
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     if let Some(propagation) = disk.propagation() {
+///         println!("{:?}: {propagation}", disk.mount_point());
+///     }
+/// }
+/// ```
+///
+/// [kernel documentation]: https://docs.kernel.org/filesystems/sharedsubtree.html
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum MountPropagation {
+    /// Events are propagated to and received from the other mounts of the same peer group.
+    ///
+    /// A mount which is both shared and a slave of another peer group is also `Shared`.
+    Shared,
+    /// Events are received from the master peer group but not propagated to it.
+    Slave,
+    /// Events are neither received nor propagated.
+    Private,
+    /// Like [`MountPropagation::Private`], but the mount can't be bind mounted either.
+    Unbindable,
+}
+
+impl fmt::Display for MountPropagation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Self::Shared => "shared",
+            Self::Slave => "slave",
+            Self::Private => "private",
+            Self::Unbindable => "unbindable",
+        })
+    }
+}
+
 /// Used to determine what you want to refresh specifically on the [`Disk`] type.
 ///
 /// * `kind` is about refreshing the [`Disk::kind`] information.
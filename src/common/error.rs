@@ -0,0 +1,92 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::fmt;
+use std::io;
+
+/// Error returned by the fallible methods of `sysinfo`.
+///
+/// It allows to know why an information couldn't be retrieved, for example to ask the user
+/// to run the program with elevated privileges instead of just skipping it.
+///
+/// ```no_run
+/// use sysinfo::SysinfoError;
+///
+/// fn handle(error: SysinfoError) {
+///     match error {
+///         SysinfoError::PermissionDenied => println!("try again as root"),
+///         SysinfoError::NotSupported => println!("not available on this system"),
+///         SysinfoError::NotFound => println!("it doesn't exist (anymore?)"),
+///         SysinfoError::Io(error) => println!("failed: {error}"),
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub enum SysinfoError {
+    /// The current user isn't allowed to retrieve this information.
+    PermissionDenied,
+    /// This information isn't available on this system.
+    NotSupported,
+    /// The requested item (process, file, etc) doesn't exist.
+    NotFound,
+    /// Any other I/O error.
+    Io(io::Error),
+}
+
+impl fmt::Display for SysinfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PermissionDenied => f.write_str("permission denied"),
+            Self::NotSupported => f.write_str("not supported on this system"),
+            Self::NotFound => f.write_str("not found"),
+            Self::Io(error) => write!(f, "I/O error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SysinfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SysinfoError {
+    fn from(error: io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied,
+            io::ErrorKind::NotFound => Self::NotFound,
+            io::ErrorKind::Unsupported => Self::NotSupported,
+            _ => Self::Io(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SysinfoError;
+    use std::io;
+
+    #[test]
+    fn check_from_io_error() {
+        let from = |kind| SysinfoError::from(io::Error::from(kind));
+
+        assert!(matches!(
+            from(io::ErrorKind::PermissionDenied),
+            SysinfoError::PermissionDenied
+        ));
+        assert!(matches!(
+            from(io::ErrorKind::NotFound),
+            SysinfoError::NotFound
+        ));
+        assert!(matches!(
+            from(io::ErrorKind::Unsupported),
+            SysinfoError::NotSupported
+        ));
+        assert!(matches!(
+            from(io::ErrorKind::InvalidData),
+            SysinfoError::Io(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,249 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{GpuInner, GpusInner};
+
+/// Interacting with GPUs.
+///
+/// ```no_run
+/// use sysinfo::Gpus;
+///
+/// let gpus = Gpus::new_with_refreshed_list();
+/// for gpu in &gpus {
+///     println!("{gpu:?}");
+/// }
+/// ```
+pub struct Gpus {
+    pub(crate) inner: GpusInner,
+}
+
+impl Default for Gpus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Gpus> for Vec<Gpu> {
+    fn from(gpus: Gpus) -> Self {
+        gpus.inner.into_vec()
+    }
+}
+
+impl From<Vec<Gpu>> for Gpus {
+    fn from(gpus: Vec<Gpu>) -> Self {
+        Self {
+            inner: GpusInner::from_vec(gpus),
+        }
+    }
+}
+
+impl std::ops::Deref for Gpus {
+    type Target = [Gpu];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Gpus {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Gpus {
+    type Item = &'a Gpu;
+    type IntoIter = std::slice::Iter<'a, Gpu>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Gpus {
+    type Item = &'a mut Gpu;
+    type IntoIter = std::slice::IterMut<'a, Gpu>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Gpus {
+    /// Creates a new empty [`Gpus`][crate::Gpus] type.
+    ///
+    /// If you want it to be filled directly, take a look at [`Gpus::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let mut gpus = Gpus::new();
+    /// gpus.refresh(false);
+    /// for gpu in &gpus {
+    ///     println!("{gpu:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: GpusInner::new(),
+        }
+    }
+
+    /// Creates a new [`Gpus`][crate::Gpus] type with the GPUs list loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{gpu:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut gpus = Self::new();
+        gpus.refresh(false);
+        gpus
+    }
+
+    /// Returns the GPUs list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{gpu:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Gpu] {
+        self.inner.list()
+    }
+
+    /// Returns the GPUs list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let mut gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list_mut() {
+    ///     gpu.refresh();
+    ///     println!("{gpu:?}");
+    /// }
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Gpu] {
+        self.inner.list_mut()
+    }
+
+    /// Refreshes the GPUs list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let mut gpus = Gpus::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// gpus.refresh(false);
+    /// ```
+    pub fn refresh(&mut self, remove_not_listed_gpus: bool) {
+        self.inner.refresh();
+        if remove_not_listed_gpus {
+            // Remove GPUs which are gone.
+            self.inner.gpus.retain_mut(|gpu| {
+                if !gpu.inner.updated {
+                    return false;
+                }
+                gpu.inner.updated = false;
+                true
+            });
+        }
+    }
+}
+
+/// Getting GPU memory and utilization information.
+///
+/// ⚠️ This information is only retrieved on Linux, from the `sysfs` files of the `amdgpu`
+/// driver. On other systems, the GPUs list is always empty.
+///
+/// ```no_run
+/// use sysinfo::Gpus;
+///
+/// let gpus = Gpus::new_with_refreshed_list();
+/// for gpu in &gpus {
+///     println!("{}: {:?}%", gpu.name(), gpu.utilization());
+/// }
+/// ```
+pub struct Gpu {
+    pub(crate) inner: GpuInner,
+}
+
+impl Gpu {
+    /// Returns the name of the GPU (like `card0`).
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in &gpus {
+    ///     println!("{}", gpu.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    /// Returns the total amount of video memory (in bytes), or `None` if the driver doesn't
+    /// provide it.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in &gpus {
+    ///     println!("{}: {:?} bytes", gpu.name(), gpu.vram_total());
+    /// }
+    /// ```
+    pub fn vram_total(&self) -> Option<u64> {
+        self.inner.vram_total()
+    }
+
+    /// Returns the amount of used video memory (in bytes), or `None` if the driver doesn't
+    /// provide it.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in &gpus {
+    ///     println!("{}: {:?} bytes", gpu.name(), gpu.vram_used());
+    /// }
+    /// ```
+    pub fn vram_used(&self) -> Option<u64> {
+        self.inner.vram_used()
+    }
+
+    /// Returns how busy the GPU is (in percentage), or `None` if the driver doesn't provide it.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in &gpus {
+    ///     println!("{}: {:?}%", gpu.name(), gpu.utilization());
+    /// }
+    /// ```
+    pub fn utilization(&self) -> Option<f32> {
+        self.inner.utilization()
+    }
+
+    /// Refreshes the GPU information.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let mut gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.iter_mut() {
+    ///     gpu.refresh();
+    /// }
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh()
+    }
+}
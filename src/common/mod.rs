@@ -4,12 +4,26 @@
 pub(crate) mod component;
 #[cfg(feature = "disk")]
 pub(crate) mod disk;
+pub(crate) mod error;
+#[cfg(feature = "gpu")]
+pub(crate) mod gpu;
 #[cfg(any(feature = "system", feature = "disk"))]
 pub(crate) mod impl_get_set;
 #[cfg(feature = "network")]
 pub(crate) mod network;
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    feature = "linux-proc-events",
+    not(feature = "unknown-ci")
+))]
+pub(crate) mod process_events;
+#[cfg(feature = "system")]
+pub(crate) mod process_watcher;
+#[cfg(all(windows, feature = "windows-services", not(feature = "unknown-ci")))]
+pub(crate) mod service;
 #[cfg(feature = "system")]
 pub(crate) mod system;
+pub(crate) mod threshold;
 #[cfg(feature = "user")]
 pub(crate) mod user;
 
@@ -43,6 +57,7 @@ pub(crate) mod user;
 /// ```
 #[cfg(any(feature = "disk", feature = "system"))]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct DiskUsage {
     /// Total number of written bytes.
     pub total_written_bytes: u64,
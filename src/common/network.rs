@@ -5,6 +5,7 @@ use std::fmt;
 use std::net::{AddrParseError, IpAddr};
 use std::num::ParseIntError;
 use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime};
 
 use crate::{NetworkDataInner, NetworksInner};
 
@@ -100,6 +101,273 @@ impl Networks {
     pub fn refresh(&mut self, remove_not_listed_interfaces: bool) {
         self.inner.refresh(remove_not_listed_interfaces)
     }
+
+    /// Returns the name of the network interface which has the `ip` address (in its
+    /// [`NetworkData::ip_networks`]), or `None` if no interface has it.
+    ///
+    /// If more than one interface has this address, any of them can be returned.
+    ///
+    /// ```no_run
+    /// use std::net::{IpAddr, Ipv4Addr};
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 5));
+    /// println!("{ip} is on {:?}", networks.interface_for_ip(ip));
+    /// ```
+    pub fn interface_for_ip(&self, ip: IpAddr) -> Option<&str> {
+        self.list()
+            .iter()
+            .find(|(_, network)| network.ip_networks().iter().any(|net| net.addr == ip))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the number of established TCP connections of each network interface.
+    ///
+    /// Connections are matched to the interface which has their local address (see
+    /// [`Networks::interface_for_ip`]), so you need to refresh the interfaces first. The
+    /// connections whose local address doesn't belong to any interface aren't counted.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns an empty map for all
+    /// other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, count) in networks.established_connections() {
+    ///     println!("[{interface_name}]: {count} established connections");
+    /// }
+    /// ```
+    pub fn established_connections(&self) -> HashMap<&str, usize> {
+        let mut connections: HashMap<&str, usize> =
+            self.list().keys().map(|name| (name.as_str(), 0)).collect();
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                for addr in self.inner.established_connections_local_addresses() {
+                    if let Some(name) = self.interface_for_ip(addr) {
+                        *connections.entry(name).or_default() += 1;
+                    }
+                }
+            } else {
+                connections.clear();
+            }
+        }
+        connections
+    }
+
+    /// Returns the counters of each network interface.
+    ///
+    /// It allows to save the counters (for example with the `serde` feature) and to give them
+    /// back to [`Networks::restore_counters`] after a restart of your program, so the first
+    /// refresh computes the usage since the last refresh before the restart. The time of the
+    /// last refresh is saved as well, so the first [`NetworkData::received_per_second`] and
+    /// [`NetworkData::transmitted_per_second`] are computed over the whole interval.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, counters) in networks.counters() {
+    ///     println!("{interface_name}: {} bytes received", counters.received);
+    /// }
+    /// ```
+    pub fn counters(&self) -> HashMap<String, NetworkCounters> {
+        self.list()
+            .iter()
+            .map(|(name, network)| {
+                (
+                    name.clone(),
+                    NetworkCounters {
+                        received: network.total_received(),
+                        transmitted: network.total_transmitted(),
+                        packets_received: network.total_packets_received(),
+                        packets_transmitted: network.total_packets_transmitted(),
+                        errors_on_received: network.total_errors_on_received(),
+                        errors_on_transmitted: network.total_errors_on_transmitted(),
+                        packets_dropped_on_received: network.total_packets_dropped_on_received(),
+                        packets_dropped_on_transmitted: network
+                            .total_packets_dropped_on_transmitted(),
+                        last_refresh: network.inner.last_refresh(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Restores the counters of the network interfaces from `counters` (which was returned by
+    /// [`Networks::counters`]), so that the next refresh computes the usage since then.
+    ///
+    /// The interfaces which aren't listed in `counters` are left untouched. It needs to be
+    /// called after the interfaces list was refreshed and before the next refresh. If the
+    /// counters went back in the meantime (for example, if the system was rebooted), the usage
+    /// is `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// let counters = networks.counters();
+    /// // Restart of the program...
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// networks.restore_counters(&counters);
+    /// networks.refresh(true);
+    /// ```
+    pub fn restore_counters(&mut self, counters: &HashMap<String, NetworkCounters>) {
+        for (name, network) in self.inner.interfaces.iter_mut() {
+            if let Some(counters) = counters.get(name) {
+                network.inner.restore_counters(counters);
+            }
+        }
+    }
+
+    /// Returns the number of incoming errors since the last refresh on all network interfaces.
+    ///
+    /// Loopback interfaces (the ones which only have loopback addresses) aren't included.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    /// println!("in: {}", networks.errors_on_received());
+    /// ```
+    pub fn errors_on_received(&self) -> u64 {
+        self.sum_non_loopback(NetworkData::errors_on_received)
+    }
+
+    /// Returns the total number of incoming errors on all network interfaces.
+    ///
+    /// Loopback interfaces (the ones which only have loopback addresses) aren't included.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// println!("in: {}", networks.total_errors_on_received());
+    /// ```
+    pub fn total_errors_on_received(&self) -> u64 {
+        self.sum_non_loopback(NetworkData::total_errors_on_received)
+    }
+
+    /// Returns the number of outcoming errors since the last refresh on all network
+    /// interfaces.
+    ///
+    /// Loopback interfaces (the ones which only have loopback addresses) aren't included.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    /// println!("out: {}", networks.errors_on_transmitted());
+    /// ```
+    pub fn errors_on_transmitted(&self) -> u64 {
+        self.sum_non_loopback(NetworkData::errors_on_transmitted)
+    }
+
+    /// Returns the total number of outcoming errors on all network interfaces.
+    ///
+    /// Loopback interfaces (the ones which only have loopback addresses) aren't included.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// println!("out: {}", networks.total_errors_on_transmitted());
+    /// ```
+    pub fn total_errors_on_transmitted(&self) -> u64 {
+        self.sum_non_loopback(NetworkData::total_errors_on_transmitted)
+    }
+
+    /// Returns the number of dropped incoming packets since the last refresh on all network
+    /// interfaces.
+    ///
+    /// Loopback interfaces (the ones which only have loopback addresses) aren't included.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    /// println!("in: {}", networks.packets_dropped_on_received());
+    /// ```
+    pub fn packets_dropped_on_received(&self) -> u64 {
+        self.sum_non_loopback(NetworkData::packets_dropped_on_received)
+    }
+
+    /// Returns the total number of dropped incoming packets on all network interfaces.
+    ///
+    /// Loopback interfaces (the ones which only have loopback addresses) aren't included.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// println!("in: {}", networks.total_packets_dropped_on_received());
+    /// ```
+    pub fn total_packets_dropped_on_received(&self) -> u64 {
+        self.sum_non_loopback(NetworkData::total_packets_dropped_on_received)
+    }
+
+    /// Returns the number of dropped outgoing packets since the last refresh on all network
+    /// interfaces.
+    ///
+    /// Loopback interfaces (the ones which only have loopback addresses) aren't included.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    /// println!("out: {}", networks.packets_dropped_on_transmitted());
+    /// ```
+    pub fn packets_dropped_on_transmitted(&self) -> u64 {
+        self.sum_non_loopback(NetworkData::packets_dropped_on_transmitted)
+    }
+
+    /// Returns the total number of dropped outgoing packets on all network interfaces.
+    ///
+    /// Loopback interfaces (the ones which only have loopback addresses) aren't included.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// println!("out: {}", networks.total_packets_dropped_on_transmitted());
+    /// ```
+    pub fn total_packets_dropped_on_transmitted(&self) -> u64 {
+        self.sum_non_loopback(NetworkData::total_packets_dropped_on_transmitted)
+    }
+
+    fn sum_non_loopback(&self, f: fn(&NetworkData) -> u64) -> u64 {
+        self.list()
+            .values()
+            .filter(|network| !network.is_loopback())
+            .map(f)
+            .fold(0, u64::saturating_add)
+    }
 }
 
 impl std::ops::Deref for Networks {
@@ -125,6 +393,12 @@ pub struct NetworkData {
 }
 
 impl NetworkData {
+    // Returns `true` if all the addresses of the interface are loopback addresses.
+    fn is_loopback(&self) -> bool {
+        let ip_networks = self.ip_networks();
+        !ip_networks.is_empty() && ip_networks.iter().all(|net| net.addr.is_loopback())
+    }
+
     /// Returns the number of received bytes since the last refresh.
     ///
     /// If you want the total number of bytes received, take a look at the
@@ -365,6 +639,136 @@ impl NetworkData {
         self.inner.total_errors_on_transmitted()
     }
 
+    /// Returns the number of incoming packets which were dropped (because of a lack of
+    /// resources for example) since the last refresh.
+    ///
+    /// If you want the total number of dropped incoming packets, take a look at the
+    /// [`total_packets_dropped_on_received`](NetworkData::total_packets_dropped_on_received)
+    /// method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.packets_dropped_on_received());
+    /// }
+    /// ```
+    pub fn packets_dropped_on_received(&self) -> u64 {
+        self.inner.packets_dropped_on_received()
+    }
+
+    /// Returns the total number of incoming packets which were dropped.
+    ///
+    /// If you want the number of dropped incoming packets since the last refresh, take a look
+    /// at the [`packets_dropped_on_received`](NetworkData::packets_dropped_on_received) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.total_packets_dropped_on_received());
+    /// }
+    /// ```
+    pub fn total_packets_dropped_on_received(&self) -> u64 {
+        self.inner.total_packets_dropped_on_received()
+    }
+
+    /// Returns the number of outgoing packets which were dropped since the last refresh.
+    ///
+    /// If you want the total number of dropped outgoing packets, take a look at the
+    /// [`total_packets_dropped_on_transmitted`](NetworkData::total_packets_dropped_on_transmitted)
+    /// method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.packets_dropped_on_transmitted());
+    /// }
+    /// ```
+    pub fn packets_dropped_on_transmitted(&self) -> u64 {
+        self.inner.packets_dropped_on_transmitted()
+    }
+
+    /// Returns the total number of outgoing packets which were dropped.
+    ///
+    /// If you want the number of dropped outgoing packets since the last refresh, take a look
+    /// at the [`packets_dropped_on_transmitted`](NetworkData::packets_dropped_on_transmitted)
+    /// method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.total_packets_dropped_on_transmitted());
+    /// }
+    /// ```
+    pub fn total_packets_dropped_on_transmitted(&self) -> u64 {
+        self.inner.total_packets_dropped_on_transmitted()
+    }
+
+    /// Returns the number of received bytes per second, computed from the number of bytes
+    /// received since the last refresh and the time elapsed since then.
+    ///
+    /// It returns `0.` until the interface has been refreshed at least twice.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(500));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {} B/s", network.received_per_second());
+    /// }
+    /// ```
+    pub fn received_per_second(&self) -> f64 {
+        per_second(self.received(), self.inner.elapsed_since_last_refresh())
+    }
+
+    /// Returns the number of transmitted bytes per second, computed from the number of bytes
+    /// transmitted since the last refresh and the time elapsed since then.
+    ///
+    /// It returns `0.` until the interface has been refreshed at least twice.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(500));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {} B/s", network.transmitted_per_second());
+    /// }
+    /// ```
+    pub fn transmitted_per_second(&self) -> f64 {
+        per_second(self.transmitted(), self.inner.elapsed_since_last_refresh())
+    }
+
     /// Returns the MAC address associated to current interface.
     ///
     /// ```no_run
@@ -393,6 +797,26 @@ impl NetworkData {
         self.inner.ip_networks()
     }
 
+    /// Returns `true` if the [`ip_networks`](NetworkData::ip_networks) of the interface changed
+    /// during the last refresh (like after a DHCP lease renewal or a VPN connection). It's also
+    /// `true` after the first refresh if the interface has addresses.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Wait some time...? Then refresh the data of each network.
+    /// networks.refresh(true);
+    /// for (interface_name, network) in &networks {
+    ///     if network.addresses_changed() {
+    ///         println!("[{interface_name}] new addresses: {:?}", network.ip_networks());
+    ///     }
+    /// }
+    /// ```
+    pub fn addresses_changed(&self) -> bool {
+        self.inner.addresses_changed()
+    }
+
     /// Returns the Maximum Transfer Unit (MTU) of the interface.
     ///
     /// ```no_run
@@ -406,6 +830,433 @@ impl NetworkData {
     pub fn mtu(&self) -> u64 {
         self.inner.mtu()
     }
+
+    /// Returns the maximum MTU supported by the interface (for example to know if it supports
+    /// jumbo frames), or `None` if the driver doesn't report it.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!(
+    ///         "{interface_name}: mtu {} (max: {:?})",
+    ///         network.mtu(),
+    ///         network.max_mtu(),
+    ///     );
+    /// }
+    /// ```
+    pub fn max_mtu(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.max_mtu()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of times the link of the interface went up or down (since the
+    /// interface was created), or `None` if this information isn't available.
+    ///
+    /// If it increases between two refreshes, the link is likely flapping (because of a faulty
+    /// cable or port for example).
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {:?} carrier changes", network.carrier_changes());
+    /// }
+    /// ```
+    pub fn carrier_changes(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.carrier_changes()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the duplex mode of the link of the interface, or `None` if it isn't known (for
+    /// example if the link is down or if it's a virtual interface).
+    ///
+    /// On Windows, it's the `MediaDuplexState` of the `MIB_IF_ROW2` of the interface.
+    ///
+    /// ⚠️ This method is only implemented for Linux and Windows. It always returns `None` for all
+    /// other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Duplex, Networks};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if network.duplex() == Some(Duplex::Half) {
+    ///         println!("{interface_name} is in half-duplex mode");
+    ///     }
+    /// }
+    /// ```
+    pub fn duplex(&self) -> Option<Duplex> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android", windows),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.duplex()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if autonegotiation of the link speed and duplex mode is enabled on the
+    /// interface, or `None` if the driver doesn't support it (like virtual interfaces).
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux (using the ethtool API). It always returns
+    /// `None` for all other systems (Windows doesn't provide it in `MIB_IF_ROW2`).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!(
+    ///         "{interface_name}: duplex {:?}, autonegotiation {:?}",
+    ///         network.duplex(),
+    ///         network.autonegotiation(),
+    ///     );
+    /// }
+    /// ```
+    pub fn autonegotiation(&self) -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.autonegotiation()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the VLAN ID of the interface if it's a VLAN sub-interface (like `eth0.100`).
+    ///
+    /// Take a look at [`NetworkData::parent_interface`] to get the interface on which this
+    /// VLAN is.
+    ///
+    /// ⚠️ This method is only implemented for Linux (using `/proc/net/vlan/config`). It always
+    /// returns `None` for all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(vlan_id) = network.vlan_id() {
+    ///         println!("{interface_name}: VLAN {vlan_id}");
+    ///     }
+    /// }
+    /// ```
+    pub fn vlan_id(&self) -> Option<u16> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.vlan_id()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the name of the interface on which this VLAN sub-interface is, or `None` if it's
+    /// not a VLAN sub-interface.
+    ///
+    /// ⚠️ This method is only implemented for Linux (using `/proc/net/vlan/config`). It always
+    /// returns `None` for all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let (Some(vlan_id), Some(parent)) = (network.vlan_id(), network.parent_interface()) {
+    ///         println!("{interface_name}: VLAN {vlan_id} on {parent}");
+    ///     }
+    /// }
+    /// ```
+    pub fn parent_interface(&self) -> Option<String> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.parent_interface()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the Wake-on-LAN modes supported by the interface and the ones currently enabled,
+    /// or `None` if the interface doesn't support Wake-on-LAN.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux (using the ethtool API). It always returns
+    /// `None` for all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(wol) = network.wake_on_lan() {
+    ///         println!("{interface_name}: {:?} (supported: {:?})", wol.enabled, wol.supported);
+    ///     }
+    /// }
+    /// ```
+    pub fn wake_on_lan(&self) -> Option<WolConfig> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.wake_on_lan()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the interface was listed during the last refresh.
+    ///
+    /// When refreshing with `remove_not_listed_interfaces` set to `false`, the interfaces which
+    /// are gone are kept with their last values, which don't change anymore. This method allows
+    /// to tell them apart from idle interfaces.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Interfaces which are gone are kept.
+    /// networks.refresh(false);
+    /// for (interface_name, network) in &networks {
+    ///     if !network.is_updated() {
+    ///         println!("{interface_name} is gone");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_updated(&self) -> bool {
+        self.inner.is_updated()
+    }
+}
+
+fn per_second(value: u64, elapsed: Option<Duration>) -> f64 {
+    match elapsed {
+        Some(elapsed) if !elapsed.is_zero() => value as f64 / elapsed.as_secs_f64(),
+        _ => 0.,
+    }
+}
+
+/// Keeps track of the time elapsed between the two last refreshes of a network interface.
+#[allow(dead_code)] // Not used on unsupported targets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RefreshTimer {
+    // `None` if the time of the last refresh is unknown (after a restore).
+    last_refresh: Option<Instant>,
+    elapsed: Option<Duration>,
+}
+
+#[allow(dead_code)] // Not used on unsupported targets.
+impl RefreshTimer {
+    pub(crate) fn new() -> Self {
+        Self {
+            last_refresh: Some(Instant::now()),
+            elapsed: None,
+        }
+    }
+
+    pub(crate) fn update(&mut self) {
+        let now = Instant::now();
+        self.elapsed = self
+            .last_refresh
+            .map(|last_refresh| now.duration_since(last_refresh));
+        self.last_refresh = Some(now);
+    }
+
+    pub(crate) fn elapsed(&self) -> Option<Duration> {
+        self.elapsed
+    }
+
+    // Returns the time of the last refresh, as a duration since the UNIX epoch, or `0` if it's
+    // unknown.
+    pub(crate) fn last_refresh(&self) -> Duration {
+        match (
+            self.last_refresh,
+            SystemTime::now().duration_since(SystemTime::UNIX_EPOCH),
+        ) {
+            (Some(last_refresh), Ok(now)) => now.saturating_sub(last_refresh.elapsed()),
+            _ => Duration::ZERO,
+        }
+    }
+
+    // Sets the time of the last refresh from a value returned by `last_refresh`, so the next
+    // update computes the time elapsed since then. If it's unknown or too old to be represented
+    // (for example, because the system was rebooted since then), no time elapsed is computed.
+    pub(crate) fn restore(&mut self, last_refresh: Duration) {
+        self.elapsed = None;
+        self.last_refresh = if last_refresh.is_zero() {
+            None
+        } else {
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .ok()
+                .and_then(|now| now.checked_sub(last_refresh))
+                .and_then(|since| Instant::now().checked_sub(since))
+        };
+    }
+}
+
+/// Duplex mode of a network link.
+///
+/// It is returned by [`NetworkData::duplex`][crate::NetworkData::duplex].
+///
+/// ```no_run
+/// use sysinfo::Networks;
+///
+/// let mut networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     if let Some(duplex) = network.duplex() {
+///         println!("{interface_name}: {duplex}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum Duplex {
+    /// Both ends can send data at the same time.
+    Full,
+    /// Only one end can send data at a time.
+    Half,
+}
+
+impl fmt::Display for Duplex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Full => "full",
+            Self::Half => "half",
+        })
+    }
+}
+
+/// Total counters of a network interface.
+///
+/// It is returned by [`Networks::counters`][crate::Networks::counters].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct NetworkCounters {
+    /// Total number of received bytes.
+    pub received: u64,
+    /// Total number of transmitted bytes.
+    pub transmitted: u64,
+    /// Total number of incoming packets.
+    pub packets_received: u64,
+    /// Total number of outgoing packets.
+    pub packets_transmitted: u64,
+    /// Total number of incoming errors.
+    pub errors_on_received: u64,
+    /// Total number of outgoing errors.
+    pub errors_on_transmitted: u64,
+    /// Total number of dropped incoming packets.
+    pub packets_dropped_on_received: u64,
+    /// Total number of dropped outgoing packets.
+    pub packets_dropped_on_transmitted: u64,
+    /// Time of the last refresh of the counters, as a duration since the UNIX epoch. If it is
+    /// `0`, the time elapsed since the last refresh is unknown and the first rates computed
+    /// after a restore are `0`.
+    pub last_refresh: Duration,
+}
+
+/// Wake-on-LAN configuration of a network interface.
+///
+/// It is returned by [`NetworkData::wake_on_lan`][crate::NetworkData::wake_on_lan].
+///
+/// ```no_run
+/// use sysinfo::{Networks, WolMode};
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     if let Some(wol) = network.wake_on_lan() {
+///         let magic = wol.enabled.contains(&WolMode::MagicPacket);
+///         println!("{interface_name}: wake on magic packet: {magic}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct WolConfig {
+    /// Modes supported by the interface.
+    pub supported: Vec<WolMode>,
+    /// Modes currently enabled (empty if Wake-on-LAN is disabled).
+    pub enabled: Vec<WolMode>,
+}
+
+/// Event which can wake up the system through a network interface.
+///
+/// It is used by [`WolConfig`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum WolMode {
+    /// Activity on the physical link.
+    Phy,
+    /// Unicast messages.
+    Unicast,
+    /// Multicast messages.
+    Multicast,
+    /// Broadcast messages.
+    Broadcast,
+    /// ARP requests.
+    Arp,
+    /// "Magic packets".
+    MagicPacket,
+    /// "Magic packets" with a SecureOn password.
+    SecureOn,
+    /// Packets matching the filters of the interface.
+    Filter,
+}
+
+impl fmt::Display for WolMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Phy => "phy",
+            Self::Unicast => "unicast",
+            Self::Multicast => "multicast",
+            Self::Broadcast => "broadcast",
+            Self::Arp => "arp",
+            Self::MagicPacket => "magic packet",
+            Self::SecureOn => "secureon",
+            Self::Filter => "filter",
+        })
+    }
 }
 
 /// MAC address for network interface.
@@ -538,6 +1389,7 @@ mod tests {
     use crate::*;
     use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
     use std::str::FromStr;
+    use std::time::Duration;
 
     // Ensure that the `Display` and `Debug` traits are implemented on the `MacAddr` struct
     #[test]
@@ -578,6 +1430,211 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_per_second() {
+        use super::per_second;
+
+        assert_eq!(per_second(100, None), 0.);
+        assert_eq!(per_second(100, Some(Duration::ZERO)), 0.);
+        assert_eq!(per_second(100, Some(Duration::from_millis(500))), 200.);
+        assert_eq!(per_second(0, Some(Duration::from_secs(2))), 0.);
+    }
+
+    #[test]
+    fn check_aggregated_errors() {
+        let networks = Networks::new_with_refreshed_list();
+        let total_received: u64 = networks
+            .values()
+            .map(NetworkData::total_errors_on_received)
+            .sum();
+        let total_transmitted: u64 = networks
+            .values()
+            .map(NetworkData::total_errors_on_transmitted)
+            .sum();
+        assert!(networks.total_errors_on_received() <= total_received);
+        assert!(networks.total_errors_on_transmitted() <= total_transmitted);
+        assert!(networks.errors_on_received() <= networks.total_errors_on_received());
+        assert!(networks.errors_on_transmitted() <= networks.total_errors_on_transmitted());
+    }
+
+    #[test]
+    fn check_aggregated_dropped_packets() {
+        let networks = Networks::new_with_refreshed_list();
+        let total_received: u64 = networks
+            .values()
+            .map(NetworkData::total_packets_dropped_on_received)
+            .sum();
+        let total_transmitted: u64 = networks
+            .values()
+            .map(NetworkData::total_packets_dropped_on_transmitted)
+            .sum();
+        assert!(networks.total_packets_dropped_on_received() <= total_received);
+        assert!(networks.total_packets_dropped_on_transmitted() <= total_transmitted);
+        assert!(
+            networks.packets_dropped_on_received() <= networks.total_packets_dropped_on_received()
+        );
+        assert!(
+            networks.packets_dropped_on_transmitted()
+                <= networks.total_packets_dropped_on_transmitted()
+        );
+    }
+
+    #[test]
+    fn check_addresses_changed() {
+        let mut networks = Networks::new_with_refreshed_list();
+        for network in networks.values() {
+            assert_eq!(
+                network.addresses_changed(),
+                !network.ip_networks().is_empty()
+            );
+        }
+        let addresses = networks
+            .iter()
+            .map(|(name, network)| (name.clone(), network.ip_networks().to_vec()))
+            .collect::<std::collections::HashMap<_, _>>();
+        networks.refresh(false);
+        for (name, network) in networks.iter() {
+            // The addresses might have changed in the meantime.
+            if network.addresses_changed() {
+                continue;
+            }
+            if let Some(previous) = addresses.get(name) {
+                assert_eq!(previous.len(), network.ip_networks().len());
+            }
+        }
+    }
+
+    #[test]
+    fn check_interface_for_ip() {
+        let networks = Networks::new_with_refreshed_list();
+        for network in networks.values() {
+            for ip_network in network.ip_networks() {
+                let interface = networks.interface_for_ip(ip_network.addr).unwrap();
+                // Another interface can have the same address.
+                assert!(networks[interface]
+                    .ip_networks()
+                    .iter()
+                    .any(|net| net.addr == ip_network.addr));
+            }
+        }
+        // This address is reserved for documentation.
+        assert_eq!(
+            networks.interface_for_ip(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 254))),
+            None
+        );
+    }
+
+    #[test]
+    fn check_restore_counters() {
+        let mut networks = Networks::new_with_refreshed_list();
+        let mut counters = networks.counters();
+        assert_eq!(counters.len(), networks.len());
+        for counters in counters.values_mut() {
+            counters.received += 1_000;
+            counters.packets_transmitted += 10;
+            counters.last_refresh -= Duration::from_secs(1);
+        }
+
+        networks.restore_counters(&counters);
+        for (name, network) in &networks {
+            assert_eq!(network.received(), 0);
+            assert_eq!(network.packets_transmitted(), 0);
+            if IS_SUPPORTED_SYSTEM {
+                assert_eq!(network.total_received(), counters[name].received);
+                assert_eq!(
+                    network.total_packets_transmitted(),
+                    counters[name].packets_transmitted
+                );
+            }
+        }
+        for (name, new_counters) in networks.counters() {
+            let old_counters = counters[&name];
+            // The time of the last refresh is converted to an `Instant`, which can be rounded.
+            let diff =
+                new_counters.last_refresh.as_secs_f64() - old_counters.last_refresh.as_secs_f64();
+            assert!(diff.abs() < 0.1);
+            assert_eq!(
+                NetworkCounters {
+                    last_refresh: old_counters.last_refresh,
+                    ..new_counters
+                },
+                old_counters
+            );
+        }
+    }
+
+    #[test]
+    fn check_refresh_timer_restore() {
+        use super::RefreshTimer;
+
+        let mut timer = RefreshTimer::new();
+        timer.restore(timer.last_refresh() - Duration::from_secs(10));
+        assert_eq!(timer.elapsed(), None);
+        timer.update();
+        assert!(timer
+            .elapsed()
+            .is_some_and(|elapsed| elapsed >= Duration::from_secs(9)));
+
+        // Unknown time of the last refresh.
+        timer.restore(Duration::ZERO);
+        assert_eq!(timer.last_refresh(), Duration::ZERO);
+        timer.update();
+        assert_eq!(timer.elapsed(), None);
+        timer.update();
+        assert!(timer.elapsed().is_some());
+    }
+
+    #[test]
+    fn check_wake_on_lan() {
+        let networks = Networks::new_with_refreshed_list();
+        for network in networks.values() {
+            if let Some(wol) = network.wake_on_lan() {
+                assert!(!wol.supported.is_empty());
+                assert!(wol.enabled.iter().all(|mode| wol.supported.contains(mode)));
+            }
+        }
+        // The loopback interface doesn't support it.
+        if let Some(lo) = networks.get("lo") {
+            assert_eq!(lo.wake_on_lan(), None);
+        }
+    }
+
+    #[test]
+    fn check_vlan() {
+        let networks = Networks::new_with_refreshed_list();
+        for network in networks.values() {
+            assert_eq!(
+                network.vlan_id().is_some(),
+                network.parent_interface().is_some()
+            );
+            if let Some(parent) = network.parent_interface() {
+                assert!(networks.contains_key(&parent));
+            }
+        }
+    }
+
+    #[test]
+    fn check_established_connections() {
+        let networks = Networks::new_with_refreshed_list();
+        let connections = networks.established_connections();
+        if IS_SUPPORTED_SYSTEM && cfg!(any(target_os = "linux", target_os = "android")) {
+            assert_eq!(connections.len(), networks.len());
+        } else {
+            assert!(connections.is_empty());
+        }
+
+        // Connect to ourselves through the loopback interface.
+        if let Some(interface) = networks.interface_for_ip(IpAddr::V4(Ipv4Addr::LOCALHOST)) {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let _client = std::net::TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+            let _server = listener.accept().unwrap();
+            if IS_SUPPORTED_SYSTEM && cfg!(any(target_os = "linux", target_os = "android")) {
+                // Both ends of the connection are counted.
+                assert!(networks.established_connections()[interface] >= 2);
+            }
+        }
+    }
+
     // Ensure that the `Display` and `Debug` traits are implemented on the `IpNetwork` struct
     #[test]
     fn check_display_impl_ip_network_ipv4() {
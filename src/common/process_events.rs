@@ -0,0 +1,112 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Pid, ProcessEventListenerInner, SysinfoError};
+
+/// Receives processes lifecycle events from the kernel as they happen.
+///
+/// Contrary to [`System::refresh_processes`][crate::System::refresh_processes], no process is
+/// missed, even the ones which started and exited between two refreshes. It uses the proc
+/// connector netlink socket, which requires the `CAP_NET_ADMIN` capability (so usually to run
+/// as root).
+///
+/// Events are returned by [`ProcessEventListener::next_event`] (or by iterating over the
+/// listener), which blocks until an event is received, so you'll likely want to use it from a
+/// dedicated thread.
+///
+/// ⚠️ This API is only available on Linux with the `linux-proc-events` feature enabled.
+///
+/// ```no_run
+/// use sysinfo::{ProcessEventListener, ProcessLifecycleEvent};
+///
+/// let listener = ProcessEventListener::new().expect("failed to listen to process events");
+/// for event in listener {
+///     match event {
+///         Ok(ProcessLifecycleEvent::Exec { pid }) => println!("{pid} executed a new program"),
+///         Ok(event) => println!("{event:?}"),
+///         Err(error) => {
+///             eprintln!("failed to receive event: {error}");
+///             break;
+///         }
+///     }
+/// }
+/// ```
+pub struct ProcessEventListener {
+    inner: ProcessEventListenerInner,
+}
+
+impl ProcessEventListener {
+    /// Starts listening to processes lifecycle events.
+    ///
+    /// It returns [`SysinfoError::PermissionDenied`] if the current process doesn't have the
+    /// `CAP_NET_ADMIN` capability.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessEventListener, SysinfoError};
+    ///
+    /// match ProcessEventListener::new() {
+    ///     Ok(_listener) => println!("listening"),
+    ///     Err(SysinfoError::PermissionDenied) => println!("you need to be root"),
+    ///     Err(error) => println!("failed to listen: {error}"),
+    /// }
+    /// ```
+    pub fn new() -> Result<Self, SysinfoError> {
+        Ok(Self {
+            inner: ProcessEventListenerInner::new()?,
+        })
+    }
+
+    /// Blocks until a process event is received and returns it.
+    ///
+    /// Threads creation and exit are not reported, only processes ones.
+    ///
+    /// ```no_run
+    /// use sysinfo::ProcessEventListener;
+    ///
+    /// let mut listener = ProcessEventListener::new().expect("failed to listen to process events");
+    /// println!("{:?}", listener.next_event());
+    /// ```
+    pub fn next_event(&mut self) -> Result<ProcessLifecycleEvent, SysinfoError> {
+        self.inner.next_event()
+    }
+}
+
+impl Iterator for ProcessEventListener {
+    type Item = Result<ProcessLifecycleEvent, SysinfoError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_event())
+    }
+}
+
+/// Process lifecycle event returned by [`ProcessEventListener`].
+///
+/// ```no_run
+/// use sysinfo::{ProcessEventListener, ProcessLifecycleEvent};
+///
+/// let mut listener = ProcessEventListener::new().expect("failed to listen to process events");
+/// if let Ok(ProcessLifecycleEvent::Fork { parent, child }) = listener.next_event() {
+///     println!("{parent} started {child}");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessLifecycleEvent {
+    /// A new process was created.
+    Fork {
+        /// The process which created the new one.
+        parent: Pid,
+        /// The new process.
+        child: Pid,
+    },
+    /// A process executed a new program.
+    Exec {
+        /// The process which executed a new program.
+        pid: Pid,
+    },
+    /// A process exited.
+    Exit {
+        /// The process which exited.
+        pid: Pid,
+        /// The exit status of the process, in the format returned by `waitpid`.
+        exit_code: u32,
+    },
+}
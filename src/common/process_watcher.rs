@@ -0,0 +1,244 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::{Pid, System};
+
+/// Watches a PID over time to detect when the process behind it is restarted.
+///
+/// A restart is detected when the [`start_time`][crate::Process::start_time] of the process
+/// with this PID changes between two updates, which happens when a supervisor keeps spawning
+/// a new process under the same PID (like in a container) or when the PID is reused. It allows
+/// to detect crash loops by counting the restarts which happened during the last `window`.
+///
+/// Since [`Process::start_time`][crate::Process::start_time] has a precision of one second, a
+/// process restarted twice during the same second is only counted once.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use sysinfo::{Pid, ProcessWatcher, ProcessesToUpdate, System};
+///
+/// let pid = Pid::from(1337);
+/// let mut s = System::new();
+/// let mut watcher = ProcessWatcher::new(pid, Duration::from_secs(60));
+///
+/// loop {
+///     s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+///     if watcher.update(&s) {
+///         println!("{pid} was restarted");
+///     }
+///     if watcher.restarts_in_window() >= 5 {
+///         println!("{pid} is crash looping");
+///     }
+///     std::thread::sleep(Duration::from_secs(1));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProcessWatcher {
+    pid: Pid,
+    window: Duration,
+    start_time: Option<u64>,
+    is_running: bool,
+    total_restarts: u64,
+    restarts: VecDeque<Instant>,
+}
+
+impl ProcessWatcher {
+    /// Creates a new [`ProcessWatcher`] for `pid`, keeping track of the restarts which
+    /// happened during the last `window`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use sysinfo::{Pid, ProcessWatcher};
+    ///
+    /// let watcher = ProcessWatcher::new(Pid::from(1337), Duration::from_secs(60));
+    /// assert_eq!(watcher.total_restarts(), 0);
+    /// ```
+    pub fn new(pid: Pid, window: Duration) -> Self {
+        Self {
+            pid,
+            window,
+            start_time: None,
+            is_running: false,
+            total_restarts: 0,
+            restarts: VecDeque::new(),
+        }
+    }
+
+    /// Returns the watched PID.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use sysinfo::{Pid, ProcessWatcher};
+    ///
+    /// let watcher = ProcessWatcher::new(Pid::from(1337), Duration::from_secs(60));
+    /// assert_eq!(watcher.pid(), Pid::from(1337));
+    /// ```
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Updates the watcher with the processes list of `system` and returns `true` if a restart
+    /// was detected.
+    ///
+    /// The first time the process is seen isn't considered as a restart. You need to refresh
+    /// the watched process (with [`System::refresh_processes`]) before calling this method.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::{Pid, ProcessWatcher, ProcessesToUpdate, System};
+    ///
+    /// let pid = Pid::from(1337);
+    /// let mut s = System::new();
+    /// let mut watcher = ProcessWatcher::new(pid, Duration::from_secs(60));
+    ///
+    /// s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    /// if watcher.update(&s) {
+    ///     println!("{pid} was restarted");
+    /// }
+    /// ```
+    pub fn update(&mut self, system: &System) -> bool {
+        let start_time = system.process(self.pid).map(|process| process.start_time());
+        self.update_with(start_time, Instant::now())
+    }
+
+    fn update_with(&mut self, start_time: Option<u64>, now: Instant) -> bool {
+        while self
+            .restarts
+            .front()
+            .is_some_and(|restart| now.duration_since(*restart) > self.window)
+        {
+            self.restarts.pop_front();
+        }
+        self.is_running = start_time.is_some();
+        let Some(start_time) = start_time else {
+            return false;
+        };
+        let restarted = self
+            .start_time
+            .is_some_and(|previous| previous != start_time);
+        self.start_time = Some(start_time);
+        if restarted {
+            self.total_restarts += 1;
+            self.restarts.push_back(now);
+        }
+        restarted
+    }
+
+    /// Returns `true` if the process was running during the last [`ProcessWatcher::update`].
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::{Pid, ProcessWatcher, ProcessesToUpdate, System};
+    ///
+    /// let pid = Pid::from(1337);
+    /// let mut s = System::new();
+    /// let mut watcher = ProcessWatcher::new(pid, Duration::from_secs(60));
+    ///
+    /// s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    /// watcher.update(&s);
+    /// println!("{pid} is running: {}", watcher.is_running());
+    /// ```
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+
+    /// Returns the last known start time (in seconds since epoch) of the process, or `None` if
+    /// it was never seen.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::{Pid, ProcessWatcher, ProcessesToUpdate, System};
+    ///
+    /// let pid = Pid::from(1337);
+    /// let mut s = System::new();
+    /// let mut watcher = ProcessWatcher::new(pid, Duration::from_secs(60));
+    ///
+    /// s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    /// watcher.update(&s);
+    /// println!("{pid} was started at {:?}", watcher.start_time());
+    /// ```
+    pub fn start_time(&self) -> Option<u64> {
+        self.start_time
+    }
+
+    /// Returns the number of restarts detected since the creation of the watcher.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use sysinfo::{Pid, ProcessWatcher};
+    ///
+    /// let watcher = ProcessWatcher::new(Pid::from(1337), Duration::from_secs(60));
+    /// assert_eq!(watcher.total_restarts(), 0);
+    /// ```
+    pub fn total_restarts(&self) -> u64 {
+        self.total_restarts
+    }
+
+    /// Returns the number of restarts detected during the window (as of the last
+    /// [`ProcessWatcher::update`]).
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use sysinfo::{Pid, ProcessWatcher};
+    ///
+    /// let watcher = ProcessWatcher::new(Pid::from(1337), Duration::from_secs(60));
+    /// assert_eq!(watcher.restarts_in_window(), 0);
+    /// ```
+    pub fn restarts_in_window(&self) -> usize {
+        self.restarts.len()
+    }
+
+    /// Returns the number of restarts per second during the window (as of the last
+    /// [`ProcessWatcher::update`]).
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use sysinfo::{Pid, ProcessWatcher};
+    ///
+    /// let watcher = ProcessWatcher::new(Pid::from(1337), Duration::from_secs(60));
+    /// assert_eq!(watcher.restart_frequency(), 0.);
+    /// ```
+    pub fn restart_frequency(&self) -> f64 {
+        if self.window.is_zero() {
+            return 0.;
+        }
+        self.restarts.len() as f64 / self.window.as_secs_f64()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ProcessWatcher;
+    use crate::Pid;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn check_process_watcher_restarts() {
+        let mut watcher = ProcessWatcher::new(Pid::from(1), Duration::from_secs(10));
+        let now = Instant::now();
+        let at = |secs| now + Duration::from_secs(secs);
+
+        // The first time the process is seen isn't a restart.
+        assert!(!watcher.update_with(Some(100), at(0)));
+        assert!(watcher.is_running());
+        assert!(!watcher.update_with(Some(100), at(1)));
+        assert!(watcher.update_with(Some(102), at(2)));
+        // The process is gone, then comes back with a new start time.
+        assert!(!watcher.update_with(None, at(3)));
+        assert!(!watcher.is_running());
+        assert_eq!(watcher.start_time(), Some(102));
+        assert!(watcher.update_with(Some(104), at(4)));
+        assert_eq!(watcher.total_restarts(), 2);
+        assert_eq!(watcher.restarts_in_window(), 2);
+        assert_eq!(watcher.restart_frequency(), 0.2);
+
+        // The first restart is out of the window.
+        assert!(!watcher.update_with(Some(104), at(13)));
+        assert_eq!(watcher.restarts_in_window(), 1);
+        assert!(!watcher.update_with(Some(104), at(20)));
+        assert_eq!(watcher.restarts_in_window(), 0);
+        assert_eq!(watcher.total_restarts(), 2);
+    }
+}
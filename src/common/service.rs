@@ -0,0 +1,339 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::fmt;
+
+use crate::{Pid, ServiceInner, ServicesInner};
+
+/// Interacting with Windows services.
+///
+/// ⚠️ This API is only available on Windows with the `windows-services` feature enabled.
+///
+/// ```no_run
+/// use sysinfo::Services;
+///
+/// let services = Services::new_with_refreshed_list();
+/// for service in &services {
+///     println!("{}: {:?}", service.name(), service.state());
+/// }
+/// ```
+pub struct Services {
+    inner: ServicesInner,
+}
+
+impl Default for Services {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Services> for Vec<Service> {
+    fn from(services: Services) -> Self {
+        services.inner.into_vec()
+    }
+}
+
+impl std::ops::Deref for Services {
+    type Target = [Service];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl<'a> IntoIterator for &'a Services {
+    type Item = &'a Service;
+    type IntoIter = std::slice::Iter<'a, Service>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl Services {
+    /// Creates a new empty [`Services`][crate::Services] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Services::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let mut services = Services::new();
+    /// services.refresh();
+    /// for service in &services {
+    ///     println!("{}", service.name());
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: ServicesInner::new(),
+        }
+    }
+
+    /// Creates a new [`Services`][crate::Services] type with the services list loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in services.list() {
+    ///     println!("{}", service.name());
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut services = Self::new();
+        services.refresh();
+        services
+    }
+
+    /// Returns the services list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in services.list() {
+    ///     println!("{}", service.name());
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Service] {
+        self.inner.list()
+    }
+
+    /// Reloads the services list from the service control manager.
+    ///
+    /// If the service control manager cannot be reached, the list is cleared.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let mut services = Services::new_with_refreshed_list();
+    /// // Wait some time...
+    /// services.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh();
+    }
+}
+
+/// Information about a Windows service.
+///
+/// It is returned by [`Services`][crate::Services].
+///
+/// ```no_run
+/// use sysinfo::Services;
+///
+/// let services = Services::new_with_refreshed_list();
+/// for service in &services {
+///     println!("{} ({})", service.display_name(), service.name());
+/// }
+/// ```
+pub struct Service {
+    pub(crate) inner: ServiceInner,
+}
+
+impl Service {
+    /// Returns the name of the service, as used by the service control manager.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in &services {
+    ///     println!("{}", service.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    /// Returns the display name of the service.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in &services {
+    ///     println!("{}", service.display_name());
+    /// }
+    /// ```
+    pub fn display_name(&self) -> &str {
+        self.inner.display_name()
+    }
+
+    /// Returns the current state of the service.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in &services {
+    ///     println!("{}: {}", service.name(), service.state());
+    /// }
+    /// ```
+    pub fn state(&self) -> ServiceState {
+        self.inner.state()
+    }
+
+    /// Returns how the service is started.
+    ///
+    /// It returns `None` if the configuration of the service couldn't be queried (for example
+    /// because of missing permissions).
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in &services {
+    ///     println!("{}: {:?}", service.name(), service.start_type());
+    /// }
+    /// ```
+    pub fn start_type(&self) -> Option<ServiceStartType> {
+        self.inner.start_type()
+    }
+
+    /// Returns the PID of the process running the service.
+    ///
+    /// It returns `None` if the service isn't running.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in &services {
+    ///     if let Some(pid) = service.pid() {
+    ///         println!("{} is running in process {pid}", service.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn pid(&self) -> Option<Pid> {
+        self.inner.pid()
+    }
+}
+
+impl fmt::Debug for Service {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Service")
+            .field("name", &self.name())
+            .field("display_name", &self.display_name())
+            .field("state", &self.state())
+            .field("start_type", &self.start_type())
+            .field("pid", &self.pid())
+            .finish()
+    }
+}
+
+/// State of a [`Service`].
+///
+/// ```no_run
+/// use sysinfo::{Services, ServiceState};
+///
+/// let services = Services::new_with_refreshed_list();
+/// for service in &services {
+///     if service.state() == ServiceState::Running {
+///         println!("{} is running", service.name());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ServiceState {
+    /// The service is not running.
+    Stopped,
+    /// The service is starting.
+    StartPending,
+    /// The service is stopping.
+    StopPending,
+    /// The service is running.
+    Running,
+    /// The service is resuming after being paused.
+    ContinuePending,
+    /// The service is being paused.
+    PausePending,
+    /// The service is paused.
+    Paused,
+    /// Unknown state.
+    Unknown(u32),
+}
+
+impl fmt::Display for ServiceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Self::Stopped => "Stopped",
+            Self::StartPending => "StartPending",
+            Self::StopPending => "StopPending",
+            Self::Running => "Running",
+            Self::ContinuePending => "ContinuePending",
+            Self::PausePending => "PausePending",
+            Self::Paused => "Paused",
+            Self::Unknown(_) => "Unknown",
+        })
+    }
+}
+
+/// How a [`Service`] is started.
+///
+/// ```no_run
+/// use sysinfo::{Services, ServiceStartType};
+///
+/// let services = Services::new_with_refreshed_list();
+/// for service in &services {
+///     if service.start_type() == Some(ServiceStartType::Disabled) {
+///         println!("{} is disabled", service.name());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ServiceStartType {
+    /// Device driver started by the system loader.
+    Boot,
+    /// Device driver started during kernel initialization.
+    System,
+    /// Started automatically by the service control manager during system startup.
+    Automatic,
+    /// Started on demand.
+    Manual,
+    /// Cannot be started.
+    Disabled,
+    /// Unknown start type.
+    Unknown(u32),
+}
+
+impl fmt::Display for ServiceStartType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Self::Boot => "Boot",
+            Self::System => "System",
+            Self::Automatic => "Automatic",
+            Self::Manual => "Manual",
+            Self::Disabled => "Disabled",
+            Self::Unknown(_) => "Unknown",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn check_services() {
+        let services = Services::new_with_refreshed_list();
+        assert!(!services.is_empty());
+        assert!(services.iter().all(|service| !service.name().is_empty()));
+        // Only running services have a process.
+        assert!(services
+            .iter()
+            .filter(|service| service.pid().is_some())
+            .all(|service| service.state() != ServiceState::Stopped));
+    }
+
+    #[test]
+    fn check_service_display() {
+        assert_eq!(ServiceState::Running.to_string(), "Running");
+        assert_eq!(ServiceState::Unknown(42).to_string(), "Unknown");
+        assert_eq!(ServiceStartType::Automatic.to_string(), "Automatic");
+        assert_eq!(ServiceStartType::Unknown(42).to_string(), "Unknown");
+    }
+}
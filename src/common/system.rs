@@ -1,11 +1,14 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use std::collections::{HashMap, HashSet};
+use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::path::Path;
 use std::process::ExitStatus;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime};
 
 use crate::common::impl_get_set::impl_get_set;
 use crate::common::DiskUsage;
@@ -24,6 +27,15 @@ use crate::{CpuInner, Gid, ProcessInner, SystemInner, Uid};
 /// ```
 pub struct System {
     pub(crate) inner: SystemInner,
+    // Processes which couldn't be refreshed anymore, kept in case they are listed again.
+    missing_processes: HashMap<Pid, MissingProcess>,
+    missing_process_grace_period: usize,
+}
+
+struct MissingProcess {
+    process: Process,
+    // Number of processes refreshes before it's dropped.
+    remaining_refreshes: usize,
 }
 
 impl Default for System {
@@ -84,6 +96,8 @@ impl System {
     pub fn new_with_specifics(refreshes: RefreshKind) -> Self {
         let mut s = Self {
             inner: SystemInner::new(),
+            missing_processes: HashMap::new(),
+            missing_process_grace_period: 0,
         };
         s.refresh_specifics(refreshes);
         s
@@ -166,6 +180,30 @@ impl System {
         self.inner.refresh_memory_specifics(refresh_kind)
     }
 
+    /// Refreshes RAM and SWAP usage, as well as the swap and page fault rates (returned by
+    /// [`System::swap_in_per_second`], [`System::swap_out_per_second`] and
+    /// [`System::major_page_faults_per_second`]).
+    ///
+    /// The rates are computed from the counters retrieved by the last call to this method and
+    /// the time elapsed since then, so you need to call it at least twice to get them.
+    ///
+    /// ⚠️ The rates are only computed on Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory_and_swap_rates();
+    /// // Wait some time...
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_memory_and_swap_rates();
+    /// println!("swap in: {} B/s", s.swap_in_per_second());
+    /// ```
+    pub fn refresh_memory_and_swap_rates(&mut self) {
+        self.refresh_memory();
+        self.inner.refresh_swap_rates();
+    }
+
     /// Refreshes CPUs usage.
     ///
     /// ⚠️ Please note that the result will very likely be inaccurate at the first call.
@@ -345,52 +383,188 @@ impl System {
         remove_dead_processes: bool,
         refresh_kind: ProcessRefreshKind,
     ) -> usize {
-        fn update_and_remove(pid: &Pid, processes: &mut HashMap<Pid, Process>) {
-            let updated = if let Some(proc) = processes.get_mut(pid) {
-                proc.inner.switch_updated()
-            } else {
-                return;
-            };
-            if !updated {
-                processes.remove(pid);
-            }
-        }
-        fn update(pid: &Pid, processes: &mut HashMap<Pid, Process>) {
-            if let Some(proc) = processes.get_mut(pid) {
-                if !proc.inner.switch_updated() {
-                    proc.inner.set_nonexistent();
-                }
+        self.refresh_processes_and_notify(
+            processes_to_update,
+            remove_dead_processes,
+            refresh_kind,
+            &mut |_| {},
+        )
+    }
+
+    // Refreshes the processes and calls `on_event` for each started, exited and updated process.
+    // The backends send the events of the processes they add to the list while the other ones
+    // are sent here, depending on whether the processes were updated.
+    fn refresh_processes_and_notify(
+        &mut self,
+        processes_to_update: ProcessesToUpdate<'_>,
+        remove_dead_processes: bool,
+        refresh_kind: ProcessRefreshKind,
+        on_event: &mut dyn FnMut(ProcessEvent),
+    ) -> usize {
+        let is_refreshed = |pid: &Pid| match processes_to_update {
+            ProcessesToUpdate::All => true,
+            ProcessesToUpdate::Some(pids) => pids.contains(pid),
+        };
+        // The missing processes are put back in the list so they are refreshed like the other
+        // processes (which also checks that they're still the same processes).
+        let mut restored = HashMap::new();
+        let processes = self.inner.processes_mut();
+        for (pid, missing) in std::mem::take(&mut self.missing_processes) {
+            if !is_refreshed(&pid) {
+                self.missing_processes.insert(pid, missing);
+            } else if let Entry::Vacant(entry) = processes.entry(pid) {
+                entry.insert(missing.process);
+                restored.insert(pid, missing.remaining_refreshes);
             }
+            // Otherwise, a new process already took its PID.
         }
 
-        let nb_updated = self
-            .inner
-            .refresh_processes_specifics(processes_to_update, refresh_kind);
+        let mut started = HashSet::new();
+        let nb_updated = self.inner.refresh_processes_specifics(
+            processes_to_update,
+            refresh_kind,
+            &mut |event| match event {
+                // The exit of a missing process was already sent when it went missing.
+                ProcessEvent::Exited(pid) if restored.contains_key(&pid) => {}
+                ProcessEvent::Started(pid) => {
+                    started.insert(pid);
+                    on_event(event);
+                }
+                _ => on_event(event),
+            },
+        );
         let processes = self.inner.processes_mut();
+        let mut not_updated = Vec::new();
+        let mut check_updated = |pid: Pid, proc: &mut Process| {
+            if !proc.inner.switch_updated() {
+                not_updated.push(pid);
+            } else if started.contains(&pid) {
+                // The backend already sent its event.
+            } else if restored.contains_key(&pid) {
+                // Its exit was sent when it went missing, so it's considered as started again.
+                on_event(ProcessEvent::Started(pid));
+            } else {
+                on_event(ProcessEvent::Updated(pid));
+            }
+        };
         match processes_to_update {
             ProcessesToUpdate::All => {
-                if remove_dead_processes {
-                    processes.retain(|_, v| v.inner.switch_updated());
-                } else {
-                    for proc in processes.values_mut() {
-                        proc.inner.switch_updated();
-                    }
+                for (pid, proc) in processes.iter_mut() {
+                    check_updated(*pid, proc);
                 }
             }
             ProcessesToUpdate::Some(pids) => {
-                let call = if remove_dead_processes {
-                    update_and_remove
-                } else {
-                    update
-                };
                 for pid in pids {
-                    call(pid, processes);
+                    if let Some(proc) = processes.get_mut(pid) {
+                        check_updated(*pid, proc);
+                    }
+                }
+            }
+        }
+        for pid in not_updated {
+            let restored_remaining_refreshes = restored.get(&pid).copied();
+            if remove_dead_processes || restored_remaining_refreshes.is_some() {
+                let Some(process) = processes.remove(&pid) else {
+                    continue;
+                };
+                if restored_remaining_refreshes.is_none() {
+                    on_event(ProcessEvent::Exited(pid));
+                }
+                let remaining_refreshes = match restored_remaining_refreshes {
+                    Some(remaining_refreshes) => remaining_refreshes - 1,
+                    None => self.missing_process_grace_period,
+                };
+                if remaining_refreshes > 0 {
+                    self.missing_processes.insert(
+                        pid,
+                        MissingProcess {
+                            process,
+                            remaining_refreshes,
+                        },
+                    );
+                }
+            } else if let ProcessesToUpdate::Some(_) = processes_to_update {
+                if let Some(proc) = processes.get_mut(&pid) {
+                    proc.inner.set_nonexistent();
                 }
             }
         }
         nb_updated
     }
 
+    /// Refreshes the processes like [`System::refresh_processes_specifics`] and calls `callback`
+    /// for each started, exited and updated process.
+    ///
+    /// It allows to keep an external state about processes up to date without having to compare
+    /// the processes list before and after each refresh: the events are sent while the processes
+    /// list is updated. If a PID was reused by a new process, you get an
+    /// [`ProcessEvent::Exited`] event followed by a [`ProcessEvent::Started`] event for it.
+    ///
+    /// Unlike with [`System::refresh_processes_specifics`], dead processes are always removed
+    /// (like if `remove_dead_processes` was `true`), so that each exited process generates only
+    /// one [`ProcessEvent::Exited`] event.
+    ///
+    /// Only the processes part of `processes_to_update` generate events. A process which
+    /// couldn't be refreshed is considered as exited, even if it's kept during the grace period
+    /// set with [`System::set_missing_process_grace_period`].
+    ///
+    /// Returns the number of updated processes.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessEvent, ProcessesToUpdate, ProcessRefreshKind, System};
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_processes_with_callback(
+    ///     ProcessesToUpdate::All,
+    ///     ProcessRefreshKind::everything(),
+    ///     |event| match event {
+    ///         ProcessEvent::Started(pid) => println!("{pid} started"),
+    ///         ProcessEvent::Exited(pid) => println!("{pid} exited"),
+    ///         ProcessEvent::Updated(_) => {}
+    ///     },
+    /// );
+    /// ```
+    pub fn refresh_processes_with_callback<F: FnMut(ProcessEvent)>(
+        &mut self,
+        processes_to_update: ProcessesToUpdate<'_>,
+        refresh_kind: ProcessRefreshKind,
+        mut callback: F,
+    ) -> usize {
+        self.refresh_processes_and_notify(processes_to_update, true, refresh_kind, &mut callback)
+    }
+
+    /// Sets the number of processes refreshes during which the information of a process which
+    /// couldn't be refreshed is kept, in case it is listed again. By default, it's `0`.
+    ///
+    /// When a process can't be refreshed (usually because it's dead, but it can also be
+    /// because its information couldn't be read temporarily), it is removed from the processes
+    /// list (if `remove_dead_processes` is `true`) and all its information is lost. So if it's
+    /// listed again later, it's handled as a new process and the values computed from the
+    /// difference between two refreshes (like [`Process::disk_usage`]) start from scratch.
+    ///
+    /// With a grace period, such a process is kept (outside of the processes list) during the
+    /// `refreshes` next processes refreshes. If it is listed again and has the same start time
+    /// (which ensures that it's not a new process which reused its PID), it's put back in the
+    /// processes list with its previous information.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// s.set_missing_process_grace_period(2);
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// ```
+    pub fn set_missing_process_grace_period(&mut self, refreshes: usize) {
+        self.missing_process_grace_period = refreshes;
+        if refreshes == 0 {
+            self.missing_processes.clear();
+        } else {
+            for missing in self.missing_processes.values_mut() {
+                missing.remaining_refreshes = missing.remaining_refreshes.min(refreshes);
+            }
+        }
+    }
+
     /// Returns the process list.
     ///
     /// ```no_run
@@ -476,1283 +650,3757 @@ impl System {
             .filter(move |val: &&Process| val.name() == name)
     }
 
-    /// Returns "global" CPUs usage (aka the addition of all the CPUs).
-    ///
-    /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
-    /// [`System::refresh_specifics`] with `cpu` enabled.
-    ///
-    /// ```no_run
-    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
-    ///
-    /// let mut s = System::new_with_specifics(
-    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
-    /// );
-    /// // Wait a bit because CPU usage is based on diff.
-    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    /// // Refresh CPUs again to get actual value.
-    /// s.refresh_cpu_usage();
-    /// println!("{}%", s.global_cpu_usage());
-    /// ```
-    pub fn global_cpu_usage(&self) -> f32 {
-        self.inner.global_cpu_usage()
-    }
-
-    /// Returns the list of the CPUs.
+    /// Returns an iterator of the processes started at or after `time`.
     ///
-    /// By default, the list of CPUs is empty until you call [`System::refresh_cpu_specifics`] or
-    /// [`System::refresh_specifics`] with `cpu` enabled.
+    /// The processes are not sorted. On Linux, the start times are compared with the precision
+    /// of a clock tick (see [`Process::start_time_ticks`]). On other systems, since
+    /// [`Process::start_time`] has a precision of one second, the processes started during the
+    /// same second as `time` are included.
     ///
     /// ```no_run
-    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
+    /// use std::time::{Duration, SystemTime};
+    /// use sysinfo::System;
     ///
-    /// let mut s = System::new_with_specifics(
-    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
-    /// );
-    /// // Wait a bit because CPU usage is based on diff.
-    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    /// // Refresh CPUs again to get actual value.
-    /// s.refresh_cpu_usage();
-    /// for cpu in s.cpus() {
-    ///     println!("{}%", cpu.cpu_usage());
+    /// let s = System::new_all();
+    /// let time = SystemTime::now() - Duration::from_secs(60);
+    /// let mut processes = s.processes_started_after(time).collect::<Vec<_>>();
+    /// // Most recently started first.
+    /// processes.sort_by_key(|process| std::cmp::Reverse(process.start_time()));
+    /// for process in processes {
+    ///     println!("{} {:?}", process.pid(), process.name());
     /// }
     /// ```
-    pub fn cpus(&self) -> &[Cpu] {
-        self.inner.cpus()
+    pub fn processes_started_after(&self, time: SystemTime) -> impl Iterator<Item = &Process> {
+        let time = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        let clock_ticks = {
+            cfg_if! {
+                if #[cfg(all(
+                    any(target_os = "linux", target_os = "android"),
+                    not(feature = "unknown-ci")
+                ))] {
+                    SystemInner::clock_ticks_since_boot(time)
+                } else {
+                    None
+                }
+            }
+        };
+        self.processes().values().filter(move |process| {
+            match (clock_ticks, process.start_time_ticks()) {
+                (Some(clock_ticks), Some(start_time_ticks)) => start_time_ticks >= clock_ticks,
+                _ => process.start_time() >= time.as_secs(),
+            }
+        })
     }
 
-    /// Returns the RAM size in bytes.
+    /// Returns the highest PID in the processes list, or `None` if it's empty.
+    ///
+    /// Compared to [`System::pid_max`], it allows to see how close the system is to run out of
+    /// PIDs. You need to refresh the processes first.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.total_memory());
+    /// println!("highest PID: {:?} (max: {:?})", s.max_pid_in_use(), System::pid_max());
     /// ```
-    ///
-    /// On Linux, if you want to see this information with the limit of your cgroup, take a look
-    /// at [`cgroup_limits`](System::cgroup_limits).
-    pub fn total_memory(&self) -> u64 {
-        self.inner.total_memory()
+    pub fn max_pid_in_use(&self) -> Option<Pid> {
+        self.processes().keys().max().copied()
     }
 
-    /// Returns the amount of free RAM in bytes.
+    /// Returns the maximum value of a PID, or `None` if it couldn't be retrieved.
     ///
-    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
-    /// memory that is available for (re)use.
+    /// Once this value is reached, the kernel starts again from the lowest PIDs, so it isn't
+    /// a limit of the number of processes. It is read from `/proc/sys/kernel/pid_max`.
     ///
-    /// Side note: Windows doesn't report "free" memory so this method returns the same value
-    /// as [`available_memory`](System::available_memory).
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// println!("{} bytes", s.free_memory());
+    /// println!("PID max: {:?}", System::pid_max());
     /// ```
-    pub fn free_memory(&self) -> u64 {
-        self.inner.free_memory()
+    pub fn pid_max() -> Option<Pid> {
+        SystemInner::pid_max()
     }
 
-    /// Returns the amount of available RAM in bytes.
+    /// Returns the counters of each process.
     ///
-    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
-    /// memory that is available for (re)use.
+    /// It allows to save the counters (for example with the `serde` feature) and to give them
+    /// back to [`System::restore_process_counters`] after a restart of your program, so the
+    /// first refresh computes the disk and CPU usage of the processes since the last refresh
+    /// before the restart.
     ///
-    /// ⚠️ Windows and FreeBSD don't report "available" memory so [`System::free_memory`]
-    /// returns the same value as this method.
+    /// On Linux, the CPU usage of the processes also depends on the CPU counters, so they need
+    /// to be saved too with [`System::cpu_counters`].
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.available_memory());
+    /// for (pid, counters) in s.process_counters() {
+    ///     println!("{pid}: {} bytes read", counters.disk_usage.total_read_bytes);
+    /// }
     /// ```
-    pub fn available_memory(&self) -> u64 {
-        self.inner.available_memory()
+    pub fn process_counters(&self) -> HashMap<Pid, ProcessCounters> {
+        self.processes()
+            .iter()
+            .map(|(pid, process)| {
+                (
+                    *pid,
+                    ProcessCounters {
+                        start_time: process.start_time(),
+                        disk_usage: process.disk_usage(),
+                        cpu: process.inner.cpu_counters(),
+                    },
+                )
+            })
+            .collect()
     }
 
-    /// Returns the amount of used RAM in bytes.
+    /// Restores the counters of the processes from `counters` (which was returned by
+    /// [`System::process_counters`]), so that the next refresh computes the disk and CPU usage
+    /// since then.
+    ///
+    /// Only the total counters are used. The processes which aren't listed in `counters`, or
+    /// whose start time is different (meaning that their PID was reused), are left untouched.
+    /// It needs to be called after the processes were refreshed and before the next refresh of
+    /// their disk and CPU usage.
+    ///
+    /// ⚠️ The CPU usage is only restored on Linux (together with [`System::restore_cpu_counters`])
+    /// and Windows. FreeBSD doesn't need it since the kernel computes it.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{ProcessesToUpdate, System};
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.used_memory());
+    /// let cpu_counters = s.cpu_counters();
+    /// let counters = s.process_counters();
+    /// // Restart of the program...
+    /// let mut s = System::new_all();
+    /// s.restore_cpu_counters(&cpu_counters);
+    /// s.restore_process_counters(&counters);
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
     /// ```
-    pub fn used_memory(&self) -> u64 {
-        self.inner.used_memory()
+    pub fn restore_process_counters(&mut self, counters: &HashMap<Pid, ProcessCounters>) {
+        for (pid, process) in self.inner.processes_mut().iter_mut() {
+            if let Some(counters) = counters
+                .get(pid)
+                .filter(|counters| counters.start_time == process.start_time())
+            {
+                process.inner.restore_disk_usage(&counters.disk_usage);
+                process.inner.restore_cpu_counters(&counters.cpu);
+            }
+        }
     }
 
-    /// Returns the SWAP size in bytes.
+    /// Returns the name of the init system (like `systemd`, `init` or `launchd`), or `None` if
+    /// the process with PID 1 isn't in the processes list.
+    ///
+    /// It is the file name of the executable of the process with PID 1 if it's available (since
+    /// `/sbin/init` is often a symlink to the actual init system) and its name otherwise. So the
+    /// processes list must have been refreshed before calling this method. In a container, it's
+    /// the name of the first process of the container.
+    ///
+    /// ⚠️ There is no process with PID 1 on Windows, so it always returns `None`.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.total_swap());
+    /// if s.init_system().as_deref() == Some("systemd") {
+    ///     println!("running with systemd");
+    /// }
     /// ```
-    pub fn total_swap(&self) -> u64 {
-        self.inner.total_swap()
+    pub fn init_system(&self) -> Option<String> {
+        let init = self.process(Pid::from(1))?;
+        let name = init.name_from_exe().unwrap_or_else(|| init.name());
+        Some(name.to_string_lossy().into_owned())
     }
 
-    /// Returns the amount of free SWAP in bytes.
+    /// Returns the ancestors of the process `pid`, starting from its parent and ending with the
+    /// first process of the chain (usually `init`, PID 1).
+    ///
+    /// The ancestors are found by following the [`Process::parent`] links of the processes
+    /// list, so the chain stops at the first ancestor which isn't in it (this ancestor is still
+    /// returned). It's empty if `pid` isn't in the processes list or has no parent. If a PID
+    /// appears twice in the chain (which can happen if PIDs were reused between two refreshes),
+    /// the walk stops before it.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.free_swap());
+    /// for ancestor in s.process_ancestors(Pid::from(1337)) {
+    ///     if let Some(process) = s.process(ancestor) {
+    ///         println!("{ancestor}: {:?}", process.name());
+    ///     }
+    /// }
     /// ```
-    pub fn free_swap(&self) -> u64 {
-        self.inner.free_swap()
+    pub fn process_ancestors(&self, pid: Pid) -> Vec<Pid> {
+        let mut ancestors = Vec::new();
+        let mut seen = HashSet::from([pid]);
+        let mut current = self.process(pid).and_then(Process::parent);
+
+        while let Some(parent) = current {
+            if !seen.insert(parent) {
+                break;
+            }
+            ancestors.push(parent);
+            current = self.process(parent).and_then(Process::parent);
+        }
+        ancestors
     }
 
-    /// Returns the amount of used SWAP in bytes.
+    /// Returns the current process if it's in the processes list.
+    ///
+    /// It's a shortcut for calling [`System::process`] with the result of
+    /// [`get_current_pid`]. So like any other process, it needs to be refreshed first.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{ProcessesToUpdate, System};
     ///
-    /// let s = System::new_all();
-    /// println!("{} bytes", s.used_swap());
+    /// let mut s = System::new();
+    /// let pid = sysinfo::get_current_pid().unwrap();
+    /// s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    /// if let Some(process) = s.current_process() {
+    ///     println!("I'm using {} bytes", process.memory());
+    /// }
     /// ```
-    pub fn used_swap(&self) -> u64 {
-        self.inner.used_swap()
+    pub fn current_process(&self) -> Option<&Process> {
+        self.process(get_current_pid().ok()?)
     }
 
-    /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
-    ///
-    /// This information is computed every time the method is called.
-    ///
-    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
-    /// calling this method.
+    /// Returns the (at most) `n` processes using the most CPU, sorted by decreasing
+    /// [`Process::cpu_usage`].
     ///
-    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
-    /// systems.
+    /// It's cheaper than sorting all processes since it only keeps the `n` best ones.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// println!("limits: {:?}", s.cgroup_limits());
+    /// let mut s = System::new_all();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_all();
+    /// for process in s.top_processes_by_cpu(5) {
+    ///     println!("{} {:?}: {}%", process.pid(), process.name(), process.cpu_usage());
+    /// }
     /// ```
-    pub fn cgroup_limits(&self) -> Option<CGroupLimits> {
-        self.inner.cgroup_limits()
+    pub fn top_processes_by_cpu(&self, n: usize) -> Vec<&Process> {
+        self.top_processes_by(n, |a, b| a.cpu_usage().total_cmp(&b.cpu_usage()))
     }
 
-    /// Returns system uptime (in seconds).
+    /// Returns the (at most) `n` processes using the most memory, sorted by decreasing
+    /// [`Process::memory`].
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// It's cheaper than sorting all processes since it only keeps the `n` best ones.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("System running since {} seconds", System::uptime());
+    /// let s = System::new_all();
+    /// for process in s.top_processes_by_memory(5) {
+    ///     println!("{} {:?}: {} B", process.pid(), process.name(), process.memory());
+    /// }
     /// ```
-    pub fn uptime() -> u64 {
-        SystemInner::uptime()
+    pub fn top_processes_by_memory(&self, n: usize) -> Vec<&Process> {
+        self.top_processes_by(n, |a, b| a.memory().cmp(&b.memory()))
     }
 
-    /// Returns the time (in seconds) when the system booted since UNIX epoch.
+    fn top_processes_by(
+        &self,
+        n: usize,
+        compare: fn(&Process, &Process) -> Ordering,
+    ) -> Vec<&Process> {
+        if n == 0 {
+            return Vec::new();
+        }
+        // It's a min-heap, so the "smallest" of the kept processes is the one replaced.
+        let mut heap = BinaryHeap::with_capacity(n.min(self.processes().len()));
+        for process in self.processes().values() {
+            if heap.len() < n {
+                heap.push(Reverse(TopProcess { process, compare }));
+            } else if let Some(mut smallest) = heap.peek_mut() {
+                if compare(process, smallest.0.process) == Ordering::Greater {
+                    smallest.0.process = process;
+                }
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(top)| top.process)
+            .collect()
+    }
+
+    /// Returns "global" CPUs usage (aka the addition of all the CPUs).
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
+    /// [`System::refresh_specifics`] with `cpu` enabled.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
     ///
-    /// println!("System booted at {} seconds", System::boot_time());
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again to get actual value.
+    /// s.refresh_cpu_usage();
+    /// println!("{}%", s.global_cpu_usage());
     /// ```
-    pub fn boot_time() -> u64 {
-        SystemInner::boot_time()
+    pub fn global_cpu_usage(&self) -> f32 {
+        self.inner.global_cpu_usage()
     }
 
-    /// Returns the system load average value.
+    /// Returns the average usage of the CPUs at the given indexes in [`System::cpus`].
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// It is useful to focus on the CPUs running a workload, for example to exclude isolated
+    /// cores (which are mostly idle) from the computation. Indexes which don't match any CPU are
+    /// ignored. If no index matches a CPU, it returns `0.`.
     ///
-    /// ⚠️ This is currently not working on **Windows**.
+    /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
+    /// [`System::refresh_specifics`] with `cpu` enabled.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
     ///
-    /// let load_avg = System::load_average();
-    /// println!(
-    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
-    ///     load_avg.one,
-    ///     load_avg.five,
-    ///     load_avg.fifteen,
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
     /// );
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again to get actual value.
+    /// s.refresh_cpu_usage();
+    /// // Usage of the first two CPUs.
+    /// println!("{}%", s.cpu_usage_for(&[0, 1]));
     /// ```
-    pub fn load_average() -> LoadAvg {
-        SystemInner::load_average()
+    pub fn cpu_usage_for(&self, cpus: &[usize]) -> f32 {
+        let all_cpus = self.cpus();
+        let (total, count) = cpus
+            .iter()
+            .filter_map(|index| all_cpus.get(*index))
+            .fold((0., 0), |(total, count), cpu| {
+                (total + cpu.cpu_usage(), count + 1)
+            });
+        if count == 0 {
+            return 0.;
+        }
+        total / count as f32
     }
 
-    /// Returns the system name.
+    /// Returns the "global" percentage of time stolen by the hypervisor from all the CPUs (aka
+    /// the time during which the virtual CPUs were waiting for a physical CPU), or `None` if this
+    /// information isn't available.
     ///
-    /// | example platform | value of `System::name()` |
-    /// |---|---|
-    /// | linux laptop | "Ubuntu" |
-    /// | android phone | "Pixel 9 Pro" |
-    /// | apple laptop | "Darwin" |
-    /// | windows server | "Windows" |
+    /// It's the main indicator of "noisy neighbours" on virtual machines. It isn't included in
+    /// [`System::global_cpu_usage`]. To have up-to-date information, you need to refresh the CPU
+    /// usage the same way as for [`System::global_cpu_usage`].
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
     ///
-    /// println!("OS: {:?}", System::name());
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// // Wait a bit because steal time is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu_usage();
+    /// println!("{:?}%", s.global_steal_time_percent());
     /// ```
-    pub fn name() -> Option<String> {
-        SystemInner::name()
+    pub fn global_steal_time_percent(&self) -> Option<f32> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                Some(self.inner.global_steal_time_percent())
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the system's kernel version.
+    /// Returns the power (in watts) drawn by the CPU packages between the last two energy
+    /// refreshes, or `None` if this information isn't available.
     ///
-    /// | example platform | value of `System::kernel_version()` |
-    /// |---|---|
-    /// | linux laptop | "6.8.0-48-generic" |
-    /// | android phone | "6.1.84-android14-11" |
-    /// | apple laptop | "24.1.0" |
-    /// | windows server | "20348" |
+    /// It's computed from the RAPL (Running Average Power Limit) energy counters, so you need to
+    /// refresh them at least twice (with [`CpuRefreshKind::with_energy`]) before getting a value.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// ⚠️ This method is only implemented for Linux, on Intel and AMD CPUs. Reading the energy
+    /// counters usually requires root privileges. It always returns `None` for all other systems.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{CpuRefreshKind, System};
     ///
-    /// println!("kernel version: {:?}", System::kernel_version());
+    /// let mut s = System::new();
+    /// s.refresh_cpu_specifics(CpuRefreshKind::nothing().with_energy());
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_cpu_specifics(CpuRefreshKind::nothing().with_energy());
+    /// if let Some(power) = s.package_power() {
+    ///     println!("{power:.2} W");
+    /// }
     /// ```
-    pub fn kernel_version() -> Option<String> {
-        SystemInner::kernel_version()
+    pub fn package_power(&self) -> Option<f64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.package_power()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the system version (e.g. for macOS this will return 15.1 rather than the kernel
-    /// version).
+    /// Measures the CPUs usage over `duration` and returns it.
     ///
-    /// | example platform | value of `System::os_version()` |
-    /// |---|---|
-    /// | linux laptop | "24.04" |
-    /// | android phone | "15" |
-    /// | apple laptop | "15.1.1" |
-    /// | windows server | "10 (20348)" |
+    /// It refreshes the CPUs usage, waits for `duration` and refreshes it again, so this
+    /// function blocks the current thread for `duration`. If `duration` is shorter than
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`], it waits for [`MINIMUM_CPU_UPDATE_INTERVAL`] instead.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// It's a convenient alternative to [`System::refresh_cpu_usage`] when you only need to
+    /// get the CPUs usage once.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("OS version: {:?}", System::os_version());
+    /// let report = System::measure_cpu_usage(std::time::Duration::from_secs(1));
+    /// println!("global: {}%", report.global);
+    /// for (nb, usage) in report.per_cpu.iter().enumerate() {
+    ///     println!("CPU {nb}: {usage}%");
+    /// }
     /// ```
-    pub fn os_version() -> Option<String> {
-        SystemInner::os_version()
+    ///
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+    pub fn measure_cpu_usage(duration: Duration) -> CpuUsageReport {
+        let mut s = Self::new();
+        s.refresh_cpu_usage();
+        std::thread::sleep(duration.max(crate::MINIMUM_CPU_UPDATE_INTERVAL));
+        s.refresh_cpu_usage();
+        CpuUsageReport {
+            global: s.global_cpu_usage(),
+            per_cpu: s.cpus().iter().map(Cpu::cpu_usage).collect(),
+        }
     }
 
-    /// Returns the system long os version.
-    ///
-    /// | example platform | value of `System::long_os_version()` |
-    /// |---|---|
-    /// | linux laptop | "Linux (Ubuntu 24.04)" |
-    /// | android phone | "Android 15 on Pixel 9 Pro" |
-    /// | apple laptop | "macOS 15.1.1 Sequoia" |
-    /// | windows server | "Windows Server 2022 Datacenter" |
+    /// Returns the list of the CPUs.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// By default, the list of CPUs is empty until you call [`System::refresh_cpu_specifics`] or
+    /// [`System::refresh_specifics`] with `cpu` enabled.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
     ///
-    /// println!("Long OS Version: {:?}", System::long_os_version());
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again to get actual value.
+    /// s.refresh_cpu_usage();
+    /// for cpu in s.cpus() {
+    ///     println!("{}%", cpu.cpu_usage());
+    /// }
     /// ```
-    pub fn long_os_version() -> Option<String> {
-        SystemInner::long_os_version()
+    pub fn cpus(&self) -> &[Cpu] {
+        self.inner.cpus()
     }
 
-    /// Returns the distribution id as defined by os-release,
-    /// or [`std::env::consts::OS`].
-    ///
-    /// See also
-    /// - <https://www.freedesktop.org/software/systemd/man/os-release.html#ID=>
-    /// - <https://doc.rust-lang.org/std/env/consts/constant.OS.html>
+    /// Returns the time counters of the global CPU and of each CPU.
     ///
-    /// | example platform | value of `System::distribution_id()` |
-    /// |---|---|
-    /// | linux laptop | "ubuntu" |
-    /// | android phone | "android" |
-    /// | apple laptop | "macos" |
-    /// | windows server | "windows" |
+    /// It allows to save the counters (for example with the `serde` feature) and to give them
+    /// back to [`System::restore_cpu_counters`] after a restart of your program, so the first
+    /// refresh computes the CPU usage since the last refresh before the restart.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// ⚠️ This function is only implemented for Linux and FreeBSD. It always returns empty
+    /// counters for all other systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("Distribution ID: {:?}", System::distribution_id());
+    /// let s = System::new_all();
+    /// let counters = s.cpu_counters();
     /// ```
-    pub fn distribution_id() -> String {
-        SystemInner::distribution_id()
+    pub fn cpu_counters(&self) -> CpuCounters {
+        self.inner.cpu_counters()
     }
 
-    /// Returns the distribution ids of operating systems that are closely
-    /// related to the local operating system in regards to packaging and
-    /// programming interfaces, for example listing one or more OS identifiers
-    /// the local OS is a derivative from.
+    /// Restores the time counters of the CPUs from `counters` (which was returned by
+    /// [`System::cpu_counters`]), so that the next refresh computes the CPU usage since then.
     ///
-    /// See also
-    /// - <https://www.freedesktop.org/software/systemd/man/latest/os-release.html#ID_LIKE=>
+    /// The CPU usage is the ratio between the counters of the time spent working and of the
+    /// total time of the CPUs, so it doesn't depend on when the counters were saved: the first
+    /// refresh returns the average CPU usage since then.
     ///
-    /// | example platform | value of `System::distribution_id_like()` |
-    /// |---|---|
-    /// | android phone | [] |
-    /// | archlinux laptop | [] |
-    /// | centos server | ["rhel", "fedora"] |
-    /// | ubuntu laptop | ["debian"] |
-    /// | windows laptop | [] |
+    /// On Linux, the CPU usage of the processes is computed against the global CPU time, so
+    /// this method needs to be called as well as [`System::restore_process_counters`] to get it.
+    /// It needs to be called after the CPUs were refreshed and before the next refresh of their
+    /// CPU usage.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// ⚠️ This function is only implemented for Linux and FreeBSD. It does nothing on other
+    /// systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("Distribution ID_LIKE: {:?}", System::distribution_id_like());
+    /// let s = System::new_all();
+    /// let counters = s.cpu_counters();
+    /// // Restart of the program...
+    /// let mut s = System::new_all();
+    /// s.restore_cpu_counters(&counters);
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu_usage();
     /// ```
-    pub fn distribution_id_like() -> Vec<String> {
-        SystemInner::distribution_id_like()
+    pub fn restore_cpu_counters(&mut self, counters: &CpuCounters) {
+        self.inner.restore_cpu_counters(counters);
     }
 
-    /// Provides kernel version following this string format:
+    /// Returns the RAM size in bytes.
     ///
-    /// | Platform | Result |
-    /// |-|-|
-    /// | Windows | Windows OS Build 20348.2227 |
-    /// | Linux | Linux 6.12.13-200.fc41.x86_64 |
-    /// | Android | Android 612.13-200 |
-    /// | MacOS | Darwin 21.6.0 |
-    /// | FreeBSD | FreeBSD 199506 |
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// If any of the information is not available, it will be replaced with "unknown".
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.total_memory());
+    /// ```
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// On Linux, if you want to see this information with the limit of your cgroup, take a look
+    /// at [`cgroup_limits`](System::cgroup_limits).
+    pub fn total_memory(&self) -> u64 {
+        self.inner.total_memory()
+    }
+
+    /// Returns the amount of free RAM in bytes.
+    ///
+    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
+    /// memory that is available for (re)use.
+    ///
+    /// Side note: Windows doesn't report "free" memory so this method returns the same value
+    /// as [`available_memory`](System::available_memory).
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("Kernel long version: {}", System::kernel_long_version());
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.free_memory());
     /// ```
-    ///
-    /// [distribution_id]: System::distribution_id
-    /// [kernel_version]: System::kernel_version
-    pub fn kernel_long_version() -> String {
-        let kernel_version = match System::kernel_version() {
-            None => "unknown".to_string(),
-            Some(s) => s,
-        };
-        let kernel_name = SystemInner::kernel_name().unwrap_or("Unknown");
-        if cfg!(windows) {
-            format!("{kernel_name} OS Build {kernel_version}")
-        } else {
-            format!("{kernel_name} {kernel_version}")
-        }
+    pub fn free_memory(&self) -> u64 {
+        self.inner.free_memory()
     }
 
-    /// Returns the system hostname based off DNS.
+    /// Returns the amount of available RAM in bytes.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
+    /// memory that is available for (re)use.
+    ///
+    /// ⚠️ Windows and FreeBSD don't report "available" memory so [`System::free_memory`]
+    /// returns the same value as this method.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("Hostname: {:?}", System::host_name());
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.available_memory());
     /// ```
-    pub fn host_name() -> Option<String> {
-        SystemInner::host_name()
+    pub fn available_memory(&self) -> u64 {
+        self.inner.available_memory()
     }
 
-    /// Returns the CPU architecture (eg. x86, amd64, aarch64, ...).
-    ///
-    /// **Important**: this information is computed every time this function is called.
+    /// Returns the amount of used RAM in bytes.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("CPU Architecture: {:?}", System::cpu_arch());
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.used_memory());
     /// ```
-    pub fn cpu_arch() -> String {
-        SystemInner::cpu_arch().unwrap_or_else(|| std::env::consts::ARCH.to_owned())
+    pub fn used_memory(&self) -> u64 {
+        self.inner.used_memory()
     }
 
-    /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
+    /// Returns the SWAP size in bytes.
     ///
-    /// In case there are multiple CPUs, it will combine the physical core count of all the CPUs.
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.total_swap());
+    /// ```
+    pub fn total_swap(&self) -> u64 {
+        self.inner.total_swap()
+    }
+
+    /// Returns the amount of free SWAP in bytes.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new();
-    /// println!("{:?}", System::physical_core_count());
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.free_swap());
     /// ```
-    pub fn physical_core_count() -> Option<usize> {
-        SystemInner::physical_core_count()
+    pub fn free_swap(&self) -> u64 {
+        self.inner.free_swap()
     }
-}
 
-/// A struct representing system load average value.
-///
-/// It is returned by [`System::load_average`][crate::System::load_average].
-///
-/// ```no_run
-/// use sysinfo::System;
-///
-/// let load_avg = System::load_average();
-/// println!(
-///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
-///     load_avg.one,
-///     load_avg.five,
-///     load_avg.fifteen,
-/// );
-/// ```
-#[repr(C)]
-#[derive(Default, Debug, Clone)]
-pub struct LoadAvg {
-    /// Average load within one minute.
-    pub one: f64,
-    /// Average load within five minutes.
-    pub five: f64,
-    /// Average load within fifteen minutes.
-    pub fifteen: f64,
-}
+    /// Returns the amount of used SWAP in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.used_swap());
+    /// ```
+    pub fn used_swap(&self) -> u64 {
+        self.inner.used_swap()
+    }
 
-/// An enum representing signals on UNIX-like systems.
-///
-/// On non-unix systems, this enum is mostly useless and is only there to keep coherency between
-/// the different OSes.
-///
-/// If you want the list of the supported signals on the current system, use
-/// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
-pub enum Signal {
-    /// Hangup detected on controlling terminal or death of controlling process.
-    Hangup,
-    /// Interrupt from keyboard.
-    Interrupt,
-    /// Quit from keyboard.
-    Quit,
-    /// Illegal instruction.
-    Illegal,
-    /// Trace/breakpoint trap.
-    Trap,
-    /// Abort signal from C abort function.
-    Abort,
-    /// IOT trap. A synonym for SIGABRT.
-    IOT,
-    /// Bus error (bad memory access).
-    Bus,
-    /// Floating point exception.
-    FloatingPointException,
-    /// Kill signal.
-    Kill,
-    /// User-defined signal 1.
-    User1,
-    /// Invalid memory reference.
-    Segv,
-    /// User-defined signal 2.
-    User2,
-    /// Broken pipe: write to pipe with no readers.
-    Pipe,
-    /// Timer signal from C alarm function.
-    Alarm,
-    /// Termination signal.
-    Term,
-    /// Child stopped or terminated.
-    Child,
-    /// Continue if stopped.
-    Continue,
-    /// Stop process.
-    Stop,
-    /// Stop typed at terminal.
-    TSTP,
-    /// Terminal input for background process.
-    TTIN,
-    /// Terminal output for background process.
-    TTOU,
-    /// Urgent condition on socket.
-    Urgent,
-    /// CPU time limit exceeded.
-    XCPU,
-    /// File size limit exceeded.
-    XFSZ,
-    /// Virtual alarm clock.
-    VirtualAlarm,
-    /// Profiling time expired.
-    Profiling,
-    /// Windows resize signal.
-    Winch,
-    /// I/O now possible.
-    IO,
-    /// Pollable event (Sys V). Synonym for IO
-    Poll,
-    /// Power failure (System V).
+    /// Returns the number of bytes read from the swap per second, computed during the last call
+    /// to [`System::refresh_memory_and_swap_rates`].
     ///
-    /// Doesn't exist on apple systems so will be ignored.
-    Power,
-    /// Bad argument to routine (SVr4).
-    Sys,
-}
+    /// It returns `0.` until [`System::refresh_memory_and_swap_rates`] was called at least
+    /// twice.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `0.` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory_and_swap_rates();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_memory_and_swap_rates();
+    /// println!("swap in: {} B/s", s.swap_in_per_second());
+    /// ```
+    pub fn swap_in_per_second(&self) -> f64 {
+        self.inner.swap_in_per_second()
+    }
 
-impl std::fmt::Display for Signal {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match *self {
-            Self::Hangup => "Hangup",
-            Self::Interrupt => "Interrupt",
-            Self::Quit => "Quit",
-            Self::Illegal => "Illegal",
-            Self::Trap => "Trap",
-            Self::Abort => "Abort",
-            Self::IOT => "IOT",
-            Self::Bus => "Bus",
-            Self::FloatingPointException => "FloatingPointException",
-            Self::Kill => "Kill",
-            Self::User1 => "User1",
-            Self::Segv => "Segv",
-            Self::User2 => "User2",
-            Self::Pipe => "Pipe",
-            Self::Alarm => "Alarm",
-            Self::Term => "Term",
-            Self::Child => "Child",
-            Self::Continue => "Continue",
-            Self::Stop => "Stop",
-            Self::TSTP => "TSTP",
-            Self::TTIN => "TTIN",
-            Self::TTOU => "TTOU",
-            Self::Urgent => "Urgent",
-            Self::XCPU => "XCPU",
-            Self::XFSZ => "XFSZ",
-            Self::VirtualAlarm => "VirtualAlarm",
-            Self::Profiling => "Profiling",
-            Self::Winch => "Winch",
-            Self::IO => "IO",
-            Self::Poll => "Poll",
-            Self::Power => "Power",
-            Self::Sys => "Sys",
-        };
-        f.write_str(s)
+    /// Returns the number of bytes written to the swap per second, computed during the last
+    /// call to [`System::refresh_memory_and_swap_rates`].
+    ///
+    /// It returns `0.` until [`System::refresh_memory_and_swap_rates`] was called at least
+    /// twice.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `0.` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory_and_swap_rates();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_memory_and_swap_rates();
+    /// println!("swap out: {} B/s", s.swap_out_per_second());
+    /// ```
+    pub fn swap_out_per_second(&self) -> f64 {
+        self.inner.swap_out_per_second()
     }
-}
 
-/// Contains memory limits for the current process.
-#[derive(Default, Debug, Clone)]
-pub struct CGroupLimits {
-    /// Total memory (in bytes) for the current cgroup.
-    pub total_memory: u64,
-    /// Free memory (in bytes) for the current cgroup.
-    pub free_memory: u64,
-    /// Free swap (in bytes) for the current cgroup.
-    pub free_swap: u64,
-    /// Resident Set Size (RSS) (in bytes) for the current cgroup.
-    pub rss: u64,
-}
+    /// Returns the number of major page faults (the ones which required reading from the disk)
+    /// per second, computed during the last call to [`System::refresh_memory_and_swap_rates`].
+    ///
+    /// It returns `0.` until [`System::refresh_memory_and_swap_rates`] was called at least
+    /// twice.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `0.` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory_and_swap_rates();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_memory_and_swap_rates();
+    /// println!("{} major page faults/s", s.major_page_faults_per_second());
+    /// ```
+    pub fn major_page_faults_per_second(&self) -> f64 {
+        self.inner.major_page_faults_per_second()
+    }
 
-/// Enum describing the different status of a process.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
-pub enum ProcessStatus {
-    /// ## Linux
+    /// Returns `true` if the system is currently under memory pressure.
     ///
-    /// Idle kernel thread.
+    /// It uses the default thresholds of [`System::is_under_memory_pressure_with`]: the system
+    /// is under memory pressure if tasks were stalled waiting for memory more than 10% of the
+    /// time during the last 10 seconds or, if this information isn't available, if less than
+    /// 10% of the memory is available.
     ///
-    /// ## macOs/FreeBSD
+    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
+    /// calling this method.
     ///
-    /// Process being created by fork.
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// ## Other OS
+    /// let s = System::new_all();
+    /// if s.is_under_memory_pressure() {
+    ///     println!("the system is running out of memory!");
+    /// }
+    /// ```
+    pub fn is_under_memory_pressure(&self) -> bool {
+        self.is_under_memory_pressure_with(10., 0.1)
+    }
+
+    /// Returns `true` if the system is currently under memory pressure, using the given
+    /// thresholds.
     ///
-    /// Not available.
-    Idle,
-    /// Running.
-    Run,
-    /// ## Linux
+    /// On Linux, it uses the pressure stall information (PSI) of the memory when available:
+    /// the system is under memory pressure if the percentage of time during the last 10
+    /// seconds where at least one task was stalled waiting for memory (the `some avg10` value of
+    /// `/proc/pressure/memory`) is above `stall_percent`.
     ///
-    /// Sleeping in an interruptible waiting.
+    /// Otherwise (on other systems, or if the kernel doesn't support PSI), it falls back to an
+    /// heuristic: the system is under memory pressure if the ratio between
+    /// [`System::available_memory`] and [`System::total_memory`] is below
+    /// `min_available_ratio` (between `0.` and `1.`). This heuristic is less accurate since a
+    /// system can work fine with little available memory, or on the contrary be thrashing
+    /// because of its cgroup limits.
     ///
-    /// ## macOS/FreeBSD
+    /// **Important**: the PSI information is computed every time this method is called.
     ///
-    /// Sleeping on an address.
+    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
+    /// calling this method, otherwise the fallback always returns `false`.
     ///
-    /// ## Other OS
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// Not available.
-    Sleep,
-    /// ## Linux
+    /// let s = System::new_all();
+    /// // Stalled more than 20% of the time, or less than 5% of available memory.
+    /// if s.is_under_memory_pressure_with(20., 0.05) {
+    ///     println!("the system is running out of memory!");
+    /// }
+    /// ```
+    pub fn is_under_memory_pressure_with(
+        &self,
+        stall_percent: f32,
+        min_available_ratio: f64,
+    ) -> bool {
+        if let Some(avg10) = SystemInner::memory_pressure_avg10() {
+            return avg10 > stall_percent;
+        }
+        let total_memory = self.total_memory();
+        if total_memory == 0 {
+            return false;
+        }
+        (self.available_memory() as f64 / total_memory as f64) < min_available_ratio
+    }
+
+    /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
     ///
-    /// Stopped (on a signal) or (before Linux 2.6.33) trace stopped.
+    /// This information is computed every time the method is called.
     ///
-    /// ## macOS/FreeBSD
+    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
+    /// calling this method.
     ///
-    /// Process debugging or suspension.
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
     ///
-    /// ## Other OS
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// Not available.
-    Stop,
-    /// ## Linux/FreeBSD/macOS
+    /// let s = System::new_all();
+    /// println!("limits: {:?}", s.cgroup_limits());
+    /// ```
+    pub fn cgroup_limits(&self) -> Option<CGroupLimits> {
+        self.inner.cgroup_limits()
+    }
+
+    /// Returns system uptime (in seconds).
     ///
-    /// Zombie process. Terminated but not reaped by its parent.
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("System running since {} seconds", System::uptime());
+    /// ```
+    pub fn uptime() -> u64 {
+        SystemInner::uptime()
+    }
+
+    /// Returns the time (in seconds) when the system booted since UNIX epoch.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("System booted at {} seconds", System::boot_time());
+    /// ```
+    pub fn boot_time() -> u64 {
+        SystemInner::boot_time()
+    }
+
+    /// Returns the system load average value.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This is currently not working on **Windows**.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let load_avg = System::load_average();
+    /// println!(
+    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
+    ///     load_avg.one,
+    ///     load_avg.five,
+    ///     load_avg.fifteen,
+    /// );
+    /// ```
+    pub fn load_average() -> LoadAvg {
+        SystemInner::load_average()
+    }
+
+    /// Returns the system name.
+    ///
+    /// | example platform | value of `System::name()` |
+    /// |---|---|
+    /// | linux laptop | "Ubuntu" |
+    /// | android phone | "Pixel 9 Pro" |
+    /// | apple laptop | "Darwin" |
+    /// | windows server | "Windows" |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("OS: {:?}", System::name());
+    /// ```
+    pub fn name() -> Option<String> {
+        SystemInner::name()
+    }
+
+    /// Returns the system's kernel version.
+    ///
+    /// | example platform | value of `System::kernel_version()` |
+    /// |---|---|
+    /// | linux laptop | "6.8.0-48-generic" |
+    /// | android phone | "6.1.84-android14-11" |
+    /// | apple laptop | "24.1.0" |
+    /// | windows server | "20348" |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("kernel version: {:?}", System::kernel_version());
+    /// ```
+    pub fn kernel_version() -> Option<String> {
+        SystemInner::kernel_version()
+    }
+
+    /// Returns the system version (e.g. for macOS this will return 15.1 rather than the kernel
+    /// version).
+    ///
+    /// | example platform | value of `System::os_version()` |
+    /// |---|---|
+    /// | linux laptop | "24.04" |
+    /// | android phone | "15" |
+    /// | apple laptop | "15.1.1" |
+    /// | windows server | "10 (20348)" |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("OS version: {:?}", System::os_version());
+    /// ```
+    pub fn os_version() -> Option<String> {
+        SystemInner::os_version()
+    }
+
+    /// Returns the system long os version.
+    ///
+    /// | example platform | value of `System::long_os_version()` |
+    /// |---|---|
+    /// | linux laptop | "Linux (Ubuntu 24.04)" |
+    /// | android phone | "Android 15 on Pixel 9 Pro" |
+    /// | apple laptop | "macOS 15.1.1 Sequoia" |
+    /// | windows server | "Windows Server 2022 Datacenter" |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Long OS Version: {:?}", System::long_os_version());
+    /// ```
+    pub fn long_os_version() -> Option<String> {
+        SystemInner::long_os_version()
+    }
+
+    /// Returns when the operating system was installed, or `None` if it couldn't be retrieved.
+    ///
+    /// * On Linux, there is no record of it so it uses the creation time (birth time) of the
+    ///   root filesystem (the one of `/lost+found`, or of `/` if it doesn't exist). It is only
+    ///   an approximation: the root filesystem might have been created or restored after the
+    ///   installation (like in containers or with images). It returns `None` if the filesystem
+    ///   doesn't support birth times.
+    /// * On Windows, it uses the `InstallDate` value of the registry, which is updated by
+    ///   feature updates.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux and Windows. It always returns `None`
+    /// for all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("OS installed at: {:?}", System::os_install_time());
+    /// ```
+    pub fn os_install_time() -> Option<SystemTime> {
+        SystemInner::os_install_time()
+    }
+
+    /// Returns the distribution id as defined by os-release,
+    /// or [`std::env::consts::OS`].
+    ///
+    /// See also
+    /// - <https://www.freedesktop.org/software/systemd/man/os-release.html#ID=>
+    /// - <https://doc.rust-lang.org/std/env/consts/constant.OS.html>
+    ///
+    /// | example platform | value of `System::distribution_id()` |
+    /// |---|---|
+    /// | linux laptop | "ubuntu" |
+    /// | android phone | "android" |
+    /// | apple laptop | "macos" |
+    /// | windows server | "windows" |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Distribution ID: {:?}", System::distribution_id());
+    /// ```
+    pub fn distribution_id() -> String {
+        SystemInner::distribution_id()
+    }
+
+    /// Returns the distribution ids of operating systems that are closely
+    /// related to the local operating system in regards to packaging and
+    /// programming interfaces, for example listing one or more OS identifiers
+    /// the local OS is a derivative from.
+    ///
+    /// See also
+    /// - <https://www.freedesktop.org/software/systemd/man/latest/os-release.html#ID_LIKE=>
+    ///
+    /// | example platform | value of `System::distribution_id_like()` |
+    /// |---|---|
+    /// | android phone | [] |
+    /// | archlinux laptop | [] |
+    /// | centos server | ["rhel", "fedora"] |
+    /// | ubuntu laptop | ["debian"] |
+    /// | windows laptop | [] |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Distribution ID_LIKE: {:?}", System::distribution_id_like());
+    /// ```
+    pub fn distribution_id_like() -> Vec<String> {
+        SystemInner::distribution_id_like()
+    }
+
+    /// Provides kernel version following this string format:
+    ///
+    /// | Platform | Result |
+    /// |-|-|
+    /// | Windows | Windows OS Build 20348.2227 |
+    /// | Linux | Linux 6.12.13-200.fc41.x86_64 |
+    /// | Android | Android 612.13-200 |
+    /// | MacOS | Darwin 21.6.0 |
+    /// | FreeBSD | FreeBSD 199506 |
+    ///
+    /// If any of the information is not available, it will be replaced with "unknown".
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Kernel long version: {}", System::kernel_long_version());
+    /// ```
+    ///
+    /// [distribution_id]: System::distribution_id
+    /// [kernel_version]: System::kernel_version
+    pub fn kernel_long_version() -> String {
+        let kernel_version = match System::kernel_version() {
+            None => "unknown".to_string(),
+            Some(s) => s,
+        };
+        let kernel_name = SystemInner::kernel_name().unwrap_or("Unknown");
+        if cfg!(windows) {
+            format!("{kernel_name} OS Build {kernel_version}")
+        } else {
+            format!("{kernel_name} {kernel_version}")
+        }
+    }
+
+    /// Returns the system hostname based off DNS.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Hostname: {:?}", System::host_name());
+    /// ```
+    pub fn host_name() -> Option<String> {
+        SystemInner::host_name()
+    }
+
+    /// Returns the CPU architecture (eg. x86, amd64, aarch64, ...).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("CPU Architecture: {:?}", System::cpu_arch());
+    /// ```
+    pub fn cpu_arch() -> String {
+        SystemInner::cpu_arch().unwrap_or_else(|| std::env::consts::ARCH.to_owned())
+    }
+
+    /// Returns the configured timezone of the system (eg. "America/New_York").
+    ///
+    /// On Unix systems, the `TZ` environment variable is used if set, otherwise it is read from
+    /// the `/etc/localtime` link. On Windows, it returns the Windows name of the timezone (eg.
+    /// "Eastern Standard Time").
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Timezone: {:?}", System::timezone());
+    /// ```
+    pub fn timezone() -> Option<String> {
+        SystemInner::timezone()
+    }
+
+    /// Returns the configured locale of the system (eg. "en_US.UTF-8" on Unix systems or
+    /// "en-US" on Windows).
+    ///
+    /// On Unix systems, the `LC_ALL` and `LANG` environment variables are used.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Locale: {:?}", System::locale());
+    /// ```
+    pub fn locale() -> Option<String> {
+        SystemInner::locale()
+    }
+
+    /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
+    ///
+    /// In case there are multiple CPUs, it will combine the physical core count of all the CPUs.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// println!("{:?}", System::physical_core_count());
+    /// ```
+    pub fn physical_core_count() -> Option<usize> {
+        SystemInner::physical_core_count()
+    }
+
+    /// Returns the number of physical CPU sockets (also called packages) or `None` if it couldn't
+    /// get it.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux and Windows. It always returns `None` for
+    /// all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{:?}", System::physical_socket_count());
+    /// ```
+    pub fn physical_socket_count() -> Option<usize> {
+        SystemInner::physical_socket_count()
+    }
+
+    /// Returns `true` if the current process runs with elevated privileges.
+    ///
+    /// On Unix systems, it means running as root (effective user ID is 0). On Windows, it means
+    /// running with an elevated token ("Run as administrator").
+    ///
+    /// A lot of information (like the environment or the command line of processes owned by
+    /// other users) is only available with elevated privileges, so it can be used to warn users
+    /// that some data might be missing.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if !System::is_elevated() {
+    ///     println!("run as root/administrator to get all information");
+    /// }
+    /// ```
+    pub fn is_elevated() -> bool {
+        SystemInner::is_elevated()
+    }
+
+    /// Returns the size of a memory page (in bytes).
+    ///
+    /// It's usually 4 KiB, but can be bigger (16 KiB on Apple Silicon, up to 64 KiB on some ARM
+    /// systems), so it shouldn't be hardcoded when converting page counts to bytes.
+    ///
+    /// It returns `0` on unsupported systems or if it couldn't be retrieved.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("page size: {} bytes", System::page_size());
+    /// ```
+    pub fn page_size() -> u64 {
+        SystemInner::page_size()
+    }
+
+    /// Returns the amount of memory (in bytes) currently taken back by the hypervisor through
+    /// memory ballooning, or `None` if this information isn't available.
+    ///
+    /// In virtual machines, the balloon driver gives memory back to the hypervisor by allocating
+    /// it in the guest, so this memory is still counted in [`System::total_memory`] but can't be
+    /// used. It is read from `/proc/vmstat`, so it returns `Some(0)` on physical machines if the
+    /// kernel supports ballooning and `None` if it doesn't.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("balloon memory: {:?} bytes", System::balloon_memory());
+    /// ```
+    pub fn balloon_memory() -> Option<u64> {
+        SystemInner::balloon_memory()
+    }
+
+    /// Returns the number of entries currently in the connection tracking (conntrack) table of
+    /// netfilter, or `None` if conntrack isn't loaded.
+    ///
+    /// When this table is full (see [`System::conntrack_max`]), new connections going through
+    /// the firewall or NAT are silently dropped.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let (Some(count), Some(max)) = (System::conntrack_count(), System::conntrack_max()) {
+    ///     println!("conntrack table: {count}/{max}");
+    /// }
+    /// ```
+    pub fn conntrack_count() -> Option<u64> {
+        SystemInner::conntrack_count()
+    }
+
+    /// Returns the maximum number of entries of the connection tracking (conntrack) table of
+    /// netfilter, or `None` if conntrack isn't loaded.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("conntrack table limit: {:?}", System::conntrack_max());
+    /// ```
+    pub fn conntrack_max() -> Option<u64> {
+        SystemInner::conntrack_max()
+    }
+
+    /// Returns statistics about the sockets and the memory used by TCP, or `None` if they
+    /// couldn't be retrieved.
+    ///
+    /// When the memory used by TCP goes over [`TcpMemoryStats::memory_pressure`], the kernel
+    /// starts reducing the socket buffers, and when it reaches [`TcpMemoryStats::memory_max`],
+    /// it starts dropping packets and pruning connections.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(tcp) = System::tcp_memory() {
+    ///     println!("TCP memory: {}/{} bytes", tcp.memory, tcp.memory_max);
+    ///     println!("{} orphaned TCP sockets", tcp.orphans);
+    /// }
+    /// ```
+    pub fn tcp_memory() -> Option<TcpMemoryStats> {
+        SystemInner::tcp_memory()
+    }
+
+    /// Returns the value of the `name` virtual memory setting (like `"swappiness"`,
+    /// `"dirty_ratio"` or `"overcommit_memory"`), or `None` if it doesn't exist or if its
+    /// value isn't a single integer.
+    ///
+    /// On Linux, the settings are read from `/proc/sys/vm/` (they are the `vm.*` sysctls).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("swappiness: {:?}", System::vm_setting("swappiness"));
+    /// ```
+    pub fn vm_setting(name: &str) -> Option<i64> {
+        SystemInner::vm_setting(name)
+    }
+
+    /// Returns `true` if `feature` is available on the current system.
+    ///
+    /// It allows to know if the value returned by the corresponding methods is meaningful or
+    /// only a default value (like `0` or `None`). It always returns `false` if the platform isn't
+    /// supported (see [`IS_SUPPORTED_SYSTEM`][crate::IS_SUPPORTED_SYSTEM]).
+    ///
+    /// On Linux, the features depending on the kernel configuration or on the permissions of the
+    /// current process (like [`Feature::CGroupLimits`] or [`Feature::ProcessDelays`]) are
+    /// checked every time this function is called, so you might want to cache its result.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Feature, System};
+    ///
+    /// if System::supports(Feature::LoadAverage) {
+    ///     println!("{:?}", System::load_average());
+    /// }
+    /// ```
+    pub fn supports(feature: Feature) -> bool {
+        crate::IS_SUPPORTED_SYSTEM && SystemInner::supports(feature)
+    }
+
+    /// Returns the number of bits of entropy currently available in the kernel entropy pool or
+    /// `None` if it couldn't get it.
+    ///
+    /// Services generating cryptographic keys (for example at boot) might stall if this value is
+    /// too low.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Available entropy: {:?}", System::available_entropy());
+    /// ```
+    pub fn available_entropy() -> Option<u32> {
+        SystemInner::available_entropy()
+    }
+
+    /// Returns the mode of transparent huge pages (THP) or `None` if it couldn't get it.
+    ///
+    /// Some workloads (like databases or JVMs) suffer from latency spikes when THP is always
+    /// enabled, so their documentation usually recommends to set it to `madvise` or `never`.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, ThpMode};
+    ///
+    /// if System::transparent_huge_pages() == Some(ThpMode::Always) {
+    ///     println!("transparent huge pages are always enabled");
+    /// }
+    /// ```
+    pub fn transparent_huge_pages() -> Option<ThpMode> {
+        SystemInner::transparent_huge_pages()
+    }
+
+    /// Returns `true` if UEFI Secure Boot is enabled, `false` if it is disabled and `None` if the
+    /// system didn't boot with UEFI or if the information couldn't be read.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux and Windows. It always returns `None` for all
+    /// other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Secure Boot enabled: {:?}", System::secure_boot_enabled());
+    /// ```
+    pub fn secure_boot_enabled() -> Option<bool> {
+        SystemInner::secure_boot_enabled()
+    }
+
+    /// Returns `true` if simultaneous multithreading (SMT, also known as "hyperthreading") is
+    /// enabled, or `None` if this information couldn't be retrieved.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("SMT enabled: {:?}", System::smt_enabled());
+    /// ```
+    pub fn smt_enabled() -> Option<bool> {
+        SystemInner::smt_enabled()
+    }
+
+    /// Returns the number of hardware threads (logical CPUs) per physical core, or `None` if this
+    /// information couldn't be retrieved.
+    ///
+    /// If the cores don't all have the same number of threads (like on CPUs mixing performance
+    /// and efficiency cores), the highest value is returned.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("threads per core: {:?}", System::threads_per_core());
+    /// ```
+    pub fn threads_per_core() -> Option<usize> {
+        SystemInner::threads_per_core()
+    }
+
+    /// Returns the number of CPUs which could ever be available on the system (including the
+    /// ones which could be hotplugged later), or `None` if this information couldn't be
+    /// retrieved.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("possible CPUs: {:?}", System::cpus_possible());
+    /// ```
+    pub fn cpus_possible() -> Option<usize> {
+        SystemInner::cpus_possible()
+    }
+
+    /// Returns the number of CPUs physically present on the system (whether they're online or
+    /// not), or `None` if this information couldn't be retrieved.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("present CPUs: {:?}", System::cpus_present());
+    /// ```
+    pub fn cpus_present() -> Option<usize> {
+        SystemInner::cpus_present()
+    }
+
+    /// Returns the number of CPUs currently online (the ones the scheduler can use), or `None`
+    /// if this information couldn't be retrieved.
+    ///
+    /// On systems with CPU hotplug or with CPUs disabled at boot, it can be lower than
+    /// [`System::cpus_present`], which can itself be lower than [`System::cpus_possible`].
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("online CPUs: {:?}", System::cpus_online());
+    /// ```
+    pub fn cpus_online() -> Option<usize> {
+        SystemInner::cpus_online()
+    }
+
+    /// Returns the list of the loaded kernel modules (the loaded drivers on Windows).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux and Windows. It always returns an empty list
+    /// for all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for module in System::kernel_modules() {
+    ///     println!("{} ({:?} B, used by {:?})", module.name, module.size, module.use_count);
+    /// }
+    /// ```
+    pub fn kernel_modules() -> Vec<KernelModule> {
+        SystemInner::kernel_modules()
+    }
+
+    /// Returns the number of ECC (Error-Correcting Code) memory errors detected by each memory
+    /// controller, or `None` if this information isn't available (for example if the memory
+    /// doesn't support ECC or if the EDAC kernel modules aren't loaded).
+    ///
+    /// A rising number of corrected errors often means that a memory module is about to fail.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(errors) = System::memory_errors() {
+    ///     for controller in &errors.controllers {
+    ///         println!(
+    ///             "{}: {} corrected, {} uncorrected",
+    ///             controller.name, controller.corrected, controller.uncorrected,
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub fn memory_errors() -> Option<MemoryErrors> {
+        SystemInner::memory_errors()
+    }
+
+    /// Returns the number of interrupts handled by each CPU, for each interrupt source.
+    ///
+    /// It is useful to find out if interrupts are evenly distributed between CPUs or if they
+    /// all end up on the same CPU.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(stats) = System::interrupts_per_cpu() {
+    ///     for (cpu, total) in stats.cpus.iter().zip(stats.total_per_cpu()) {
+    ///         println!("CPU{cpu}: {total} interrupts");
+    ///     }
+    /// }
+    /// ```
+    pub fn interrupts_per_cpu() -> Option<InterruptStats> {
+        SystemInner::interrupts_per_cpu()
+    }
+
+    /// Returns the PID of the process referred to by the `fd` pidfd (a file descriptor
+    /// returned by `pidfd_open` or by `clone` with `CLONE_PIDFD`).
+    ///
+    /// It returns `None` if `fd` isn't a pidfd, if the process already exited or if it isn't
+    /// part of the PID namespace of the current process.
+    ///
+    /// Unlike a PID, a pidfd cannot be reused by another process, so it allows to make sure
+    /// the [`Process`] you get from the returned PID is the expected one (as long as its PID
+    /// is checked again with this method after getting it).
+    ///
+    /// ⚠️ This method is only available on Unix systems and is only implemented for Linux. It
+    /// always returns `None` for all other Unix systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// # let pidfd = 3;
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = System::process_from_pidfd(pidfd).and_then(|pid| s.process(pid)) {
+    ///     println!("{:?}", process.name());
+    /// }
+    /// ```
+    #[cfg(unix)]
+    pub fn process_from_pidfd(fd: std::os::unix::io::RawFd) -> Option<Pid> {
+        SystemInner::process_from_pidfd(fd)
+    }
+}
+
+// Used by `System::top_processes_by` to order processes in a `BinaryHeap`.
+struct TopProcess<'a> {
+    process: &'a Process,
+    compare: fn(&Process, &Process) -> Ordering,
+}
+
+impl PartialEq for TopProcess<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TopProcess<'_> {}
+
+impl PartialOrd for TopProcess<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TopProcess<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(self.process, other.process)
+    }
+}
+
+/// A struct representing system load average value.
+///
+/// It is returned by [`System::load_average`][crate::System::load_average].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let load_avg = System::load_average();
+/// println!(
+///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
+///     load_avg.one,
+///     load_avg.five,
+///     load_avg.fifteen,
+/// );
+/// ```
+#[repr(C)]
+#[derive(Default, Debug, Clone)]
+pub struct LoadAvg {
+    /// Average load within one minute.
+    pub one: f64,
+    /// Average load within five minutes.
+    pub five: f64,
+    /// Average load within fifteen minutes.
+    pub fifteen: f64,
+}
+
+/// An enum representing signals on UNIX-like systems.
+///
+/// On non-unix systems, this enum is mostly useless and is only there to keep coherency between
+/// the different OSes.
+///
+/// If you want the list of the supported signals on the current system, use
+/// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum Signal {
+    /// Hangup detected on controlling terminal or death of controlling process.
+    Hangup,
+    /// Interrupt from keyboard.
+    Interrupt,
+    /// Quit from keyboard.
+    Quit,
+    /// Illegal instruction.
+    Illegal,
+    /// Trace/breakpoint trap.
+    Trap,
+    /// Abort signal from C abort function.
+    Abort,
+    /// IOT trap. A synonym for SIGABRT.
+    IOT,
+    /// Bus error (bad memory access).
+    Bus,
+    /// Floating point exception.
+    FloatingPointException,
+    /// Kill signal.
+    Kill,
+    /// User-defined signal 1.
+    User1,
+    /// Invalid memory reference.
+    Segv,
+    /// User-defined signal 2.
+    User2,
+    /// Broken pipe: write to pipe with no readers.
+    Pipe,
+    /// Timer signal from C alarm function.
+    Alarm,
+    /// Termination signal.
+    Term,
+    /// Child stopped or terminated.
+    Child,
+    /// Continue if stopped.
+    Continue,
+    /// Stop process.
+    Stop,
+    /// Stop typed at terminal.
+    TSTP,
+    /// Terminal input for background process.
+    TTIN,
+    /// Terminal output for background process.
+    TTOU,
+    /// Urgent condition on socket.
+    Urgent,
+    /// CPU time limit exceeded.
+    XCPU,
+    /// File size limit exceeded.
+    XFSZ,
+    /// Virtual alarm clock.
+    VirtualAlarm,
+    /// Profiling time expired.
+    Profiling,
+    /// Windows resize signal.
+    Winch,
+    /// I/O now possible.
+    IO,
+    /// Pollable event (Sys V). Synonym for IO
+    Poll,
+    /// Power failure (System V).
+    ///
+    /// Doesn't exist on apple systems so will be ignored.
+    Power,
+    /// Bad argument to routine (SVr4).
+    Sys,
+}
+
+impl std::fmt::Display for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            Self::Hangup => "Hangup",
+            Self::Interrupt => "Interrupt",
+            Self::Quit => "Quit",
+            Self::Illegal => "Illegal",
+            Self::Trap => "Trap",
+            Self::Abort => "Abort",
+            Self::IOT => "IOT",
+            Self::Bus => "Bus",
+            Self::FloatingPointException => "FloatingPointException",
+            Self::Kill => "Kill",
+            Self::User1 => "User1",
+            Self::Segv => "Segv",
+            Self::User2 => "User2",
+            Self::Pipe => "Pipe",
+            Self::Alarm => "Alarm",
+            Self::Term => "Term",
+            Self::Child => "Child",
+            Self::Continue => "Continue",
+            Self::Stop => "Stop",
+            Self::TSTP => "TSTP",
+            Self::TTIN => "TTIN",
+            Self::TTOU => "TTOU",
+            Self::Urgent => "Urgent",
+            Self::XCPU => "XCPU",
+            Self::XFSZ => "XFSZ",
+            Self::VirtualAlarm => "VirtualAlarm",
+            Self::Profiling => "Profiling",
+            Self::Winch => "Winch",
+            Self::IO => "IO",
+            Self::Poll => "Poll",
+            Self::Power => "Power",
+            Self::Sys => "Sys",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Contains memory limits for the current process.
+#[derive(Default, Debug, Clone)]
+pub struct CGroupLimits {
+    /// Total memory (in bytes) for the current cgroup.
+    pub total_memory: u64,
+    /// Free memory (in bytes) for the current cgroup.
+    pub free_memory: u64,
+    /// Free swap (in bytes) for the current cgroup.
+    pub free_swap: u64,
+    /// Resident Set Size (RSS) (in bytes) for the current cgroup.
+    pub rss: u64,
+}
+
+/// Information about a loaded kernel module (or driver on Windows).
+///
+/// It is returned by [`System::kernel_modules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelModule {
+    /// Name of the module.
+    pub name: String,
+    /// Memory size (in bytes) of the module. Not available on Windows.
+    pub size: Option<u64>,
+    /// Number of users of the module (other modules, open devices, etc). Not available on
+    /// Windows.
+    pub use_count: Option<u32>,
+}
+
+/// CPUs usage measured over a period of time.
+///
+/// It is returned by [`System::measure_cpu_usage`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpuUsageReport {
+    /// "Global" CPUs usage (in percent), like [`System::global_cpu_usage`].
+    pub global: f32,
+    /// Usage (in percent) of each CPU, in the same order as [`System::cpus`].
+    pub per_cpu: Vec<f32>,
+}
+
+/// ECC memory errors of each memory controller.
+///
+/// It is returned by [`System::memory_errors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryErrors {
+    /// List of the memory controllers.
+    pub controllers: Vec<MemoryControllerErrors>,
+}
+
+impl MemoryErrors {
+    /// Returns the number of corrected errors of all the memory controllers.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(errors) = System::memory_errors() {
+    ///     println!("{} corrected errors", errors.total_corrected());
+    /// }
+    /// ```
+    pub fn total_corrected(&self) -> u64 {
+        self.controllers
+            .iter()
+            .fold(0, |total, c| total.saturating_add(c.corrected))
+    }
+
+    /// Returns the number of uncorrected errors of all the memory controllers.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(errors) = System::memory_errors() {
+    ///     println!("{} uncorrected errors", errors.total_uncorrected());
+    /// }
+    /// ```
+    pub fn total_uncorrected(&self) -> u64 {
+        self.controllers
+            .iter()
+            .fold(0, |total, c| total.saturating_add(c.uncorrected))
+    }
+}
+
+/// ECC memory errors of a memory controller.
+///
+/// It is part of [`MemoryErrors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryControllerErrors {
+    /// Name of the memory controller (like `"mc0"`).
+    pub name: String,
+    /// Number of errors which were corrected.
+    pub corrected: u64,
+    /// Number of errors which couldn't be corrected.
+    pub uncorrected: u64,
+}
+
+/// Per-CPU interrupt counts.
+///
+/// It is returned by [`System::interrupts_per_cpu`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterruptStats {
+    /// Numbers of the CPUs the counts refer to, in the same order as [`Interrupt::counts`].
+    /// Offline CPUs are not listed.
+    pub cpus: Vec<usize>,
+    /// List of the interrupt sources.
+    pub interrupts: Vec<Interrupt>,
+}
+
+impl InterruptStats {
+    /// Returns the total number of interrupts handled by each CPU, in the same order as
+    /// [`InterruptStats::cpus`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(stats) = System::interrupts_per_cpu() {
+    ///     println!("{:?}", stats.total_per_cpu());
+    /// }
+    /// ```
+    pub fn total_per_cpu(&self) -> Vec<u64> {
+        let mut totals = vec![0u64; self.cpus.len()];
+        for interrupt in &self.interrupts {
+            for (total, count) in totals.iter_mut().zip(&interrupt.counts) {
+                *total = total.saturating_add(*count);
+            }
+        }
+        totals
+    }
+}
+
+/// Interrupt counts of an interrupt source.
+///
+/// It is part of [`InterruptStats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interrupt {
+    /// Identifier of the interrupt: either an IRQ number (like `"24"`) or a name for
+    /// architecture-specific interrupts (like `"NMI"` or `"LOC"`).
+    pub irq: String,
+    /// Number of times this interrupt was handled by each CPU, in the same order as
+    /// [`InterruptStats::cpus`].
+    ///
+    /// Some entries (like `"ERR"`) only have a single system-wide count.
+    pub counts: Vec<u64>,
+    /// Description of the interrupt (controller, trigger type, device name...). It can be
+    /// empty.
+    pub description: String,
+}
+
+/// Type containing received and transmitted bytes.
+///
+/// It is returned by [`Process::network_usage`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd)]
+pub struct NetworkUsage {
+    /// Total number of received bytes.
+    pub total_received_bytes: u64,
+    /// Number of received bytes since the last refresh.
+    pub received_bytes: u64,
+    /// Total number of transmitted bytes.
+    pub total_transmitted_bytes: u64,
+    /// Number of transmitted bytes since the last refresh.
+    pub transmitted_bytes: u64,
+}
+
+/// Number of open file descriptors of a process, grouped by kind.
+///
+/// It is returned by [`Process::fd_breakdown`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FdBreakdown {
+    /// Number of file descriptors referring to a path in the filesystem (regular files,
+    /// directories, devices, etc).
+    pub files: usize,
+    /// Number of sockets.
+    pub sockets: usize,
+    /// Number of pipes.
+    pub pipes: usize,
+    /// Number of file descriptors without an inode (like `epoll`, `eventfd`, `timerfd`, etc).
+    pub anon_inodes: usize,
+    /// Number of file descriptors which don't fit in the other categories.
+    pub others: usize,
+}
+
+impl FdBreakdown {
+    /// Returns the total number of open file descriptors.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(fds) = process.fd_breakdown() {
+    ///         println!("{} file descriptors", fds.total());
+    ///     }
+    /// }
+    /// ```
+    pub fn total(&self) -> usize {
+        self.files + self.sockets + self.pipes + self.anon_inodes + self.others
+    }
+}
+
+/// Time spent by a process waiting for resources.
+///
+/// It is returned by [`Process::delays`]. The values which aren't supported by the kernel are
+/// `0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TaskDelays {
+    /// Time spent waiting for a CPU while being runnable.
+    pub cpu: Duration,
+    /// Time spent waiting for block I/O to complete.
+    pub block_io: Duration,
+    /// Time spent waiting for pages to be swapped in.
+    pub swap_in: Duration,
+    /// Time spent waiting for memory to be reclaimed.
+    pub memory_reclaim: Duration,
+    /// Time spent waiting for pages which were recently evicted (thrashing).
+    pub thrashing: Duration,
+    /// Time spent waiting for memory to be compacted.
+    pub compaction: Duration,
+}
+
+/// Signal masks of a process.
+///
+/// It is returned by [`Process::signal_info`]. Each mask has the bit `n - 1` set for the signal
+/// number `n`.
+///
+/// On Linux, the masks are read from `/proc/<pid>/status`, which describes the thread-group
+/// leader (the main thread) of the process.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SignalInfo {
+    /// Signals blocked by the main thread of the process. The other threads can block other
+    /// signals.
+    pub blocked: u64,
+    /// Signals pending for the whole process or for its main thread. The signals pending for
+    /// the other threads aren't included.
+    pub pending: u64,
+    /// Signals ignored by the process (it's the same for all its threads).
+    pub ignored: u64,
+    /// Signals for which the process installed a handler (it's the same for all its threads).
+    pub caught: u64,
+}
+
+impl SignalInfo {
+    /// Returns `true` if `signal` is blocked by the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, Signal, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(info) = s.process(Pid::from(1337)).and_then(|p| p.signal_info()) {
+    ///     println!("SIGTERM is blocked: {}", info.is_blocking(Signal::Term));
+    /// }
+    /// ```
+    pub fn is_blocking(&self, signal: Signal) -> bool {
+        Self::is_set(self.blocked, signal)
+    }
+
+    /// Returns `true` if `signal` is pending for the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, Signal, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(info) = s.process(Pid::from(1337)).and_then(|p| p.signal_info()) {
+    ///     println!("SIGTERM is pending: {}", info.is_pending(Signal::Term));
+    /// }
+    /// ```
+    pub fn is_pending(&self, signal: Signal) -> bool {
+        Self::is_set(self.pending, signal)
+    }
+
+    /// Returns `true` if `signal` is ignored by the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, Signal, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(info) = s.process(Pid::from(1337)).and_then(|p| p.signal_info()) {
+    ///     println!("SIGTERM is ignored: {}", info.is_ignoring(Signal::Term));
+    /// }
+    /// ```
+    pub fn is_ignoring(&self, signal: Signal) -> bool {
+        Self::is_set(self.ignored, signal)
+    }
+
+    /// Returns `true` if the process installed a handler for `signal`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, Signal, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(info) = s.process(Pid::from(1337)).and_then(|p| p.signal_info()) {
+    ///     println!("SIGTERM is caught: {}", info.is_catching(Signal::Term));
+    /// }
+    /// ```
+    pub fn is_catching(&self, signal: Signal) -> bool {
+        Self::is_set(self.caught, signal)
+    }
+
+    fn is_set(mask: u64, signal: Signal) -> bool {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                crate::sys::system::convert_signal(signal)
+                    .and_then(|signal| u32::try_from(signal - 1).ok())
+                    .and_then(|bit| mask.checked_shr(bit))
+                    .is_some_and(|mask| mask & 1 != 0)
+            } else {
+                let _ = (mask, signal);
+                false
+            }
+        }
+    }
+}
+
+/// GPU usage of a process.
+///
+/// It is returned by [`Process::gpu_usage`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GpuUsage {
+    /// Video memory (in bytes) used by the process.
+    pub memory_vram: u64,
+    /// Time spent by each GPU engine (like `"gfx"` or `"render"`) running work submitted by the
+    /// process, sorted by engine name.
+    pub engines: Vec<(String, Duration)>,
+}
+
+impl GpuUsage {
+    /// Returns the time spent by all GPU engines running work submitted by the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(gpu) = process.gpu_usage() {
+    ///         println!("GPU time: {:?}", gpu.total_engine_time());
+    ///     }
+    /// }
+    /// ```
+    pub fn total_engine_time(&self) -> Duration {
+        self.engines.iter().map(|(_, time)| *time).sum()
+    }
+}
+
+/// Enum describing the different status of a process.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ProcessStatus {
+    /// ## Linux
+    ///
+    /// Idle kernel thread.
+    ///
+    /// ## macOs/FreeBSD
+    ///
+    /// Process being created by fork.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Idle,
+    /// Running.
+    Run,
+    /// ## Linux
+    ///
+    /// Sleeping in an interruptible waiting.
+    ///
+    /// ## macOS/FreeBSD
+    ///
+    /// Sleeping on an address.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Sleep,
+    /// ## Linux
+    ///
+    /// Stopped (on a signal) or (before Linux 2.6.33) trace stopped.
+    ///
+    /// ## macOS/FreeBSD
+    ///
+    /// Process debugging or suspension.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Stop,
+    /// ## Linux/FreeBSD/macOS
+    ///
+    /// Zombie process. Terminated but not reaped by its parent.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Zombie,
+    /// ## Linux
+    ///
+    /// Tracing stop (Linux 2.6.33 onward). Stopped by debugger during the tracing.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Tracing,
+    /// ## Linux
+    ///
+    /// Dead/uninterruptible sleep (usually IO).
+    ///
+    /// ## FreeBSD
+    ///
+    /// A process should never end up in this state.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Dead,
+    /// ## Linux
+    ///
+    /// Wakekill (Linux 2.6.33 to 3.13 only).
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Wakekill,
+    /// ## Linux
+    ///
+    /// Waking (Linux 2.6.33 to 3.13 only).
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Waking,
+    /// ## Linux
+    ///
+    /// Parked (Linux 3.9 to 3.13 only).
+    ///
+    /// ## macOS
+    ///
+    /// Halted at a clean point.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Parked,
+    /// ## FreeBSD
+    ///
+    /// Blocked on a lock.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    LockBlocked,
+    /// ## Linux
+    ///
+    /// Waiting in uninterruptible disk sleep.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    UninterruptibleDiskSleep,
+    /// Unknown.
+    Unknown(u32),
+}
+
+/// Enum describing the different kind of threads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ThreadKind {
+    /// Kernel thread.
+    Kernel,
+    /// User thread.
+    Userland,
+}
+
+/// Windows integrity level of a process.
+///
+/// It is returned by [`Process::integrity_level`].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(level) = s.process(Pid::from(1337)).and_then(|p| p.integrity_level()) {
+///     println!("integrity level: {level}");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum IntegrityLevel {
+    /// Untrusted integrity level (used by anonymous processes).
+    Untrusted,
+    /// Low integrity level (used by sandboxed processes).
+    Low,
+    /// Medium integrity level (used by the processes of standard users).
+    Medium,
+    /// High integrity level (used by elevated processes).
+    High,
+    /// System integrity level (used by services).
+    System,
+    /// Protected integrity level (used by protected processes).
+    Protected,
+}
+
+impl fmt::Display for IntegrityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Untrusted => "Untrusted",
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+            Self::System => "System",
+            Self::Protected => "Protected",
+        })
+    }
+}
+
+/// Struct containing information of a process.
+///
+/// ## iOS
+///
+/// This information cannot be retrieved on iOS due to sandboxing.
+///
+/// ## Apple app store
+///
+/// If you are building a macOS Apple app store, it won't be able
+/// to retrieve this information.
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     println!("{:?}", process.name());
+/// }
+/// ```
+pub struct Process {
+    pub(crate) inner: ProcessInner,
+}
+
+impl Process {
+    /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
+    /// platforms by this crate).
+    ///
+    /// Returns `true` if the signal was sent successfully. If you want to wait for this process
+    /// to end, you can use [`Process::wait`].
+    ///
+    /// ⚠️ Even if this function returns `true`, it doesn't necessarily mean that the process will
+    /// be killed. It just means that the signal was sent successfully.
+    ///
+    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
+    /// levels than the current process for example.
+    ///
+    /// If you want to use another signal, take a look at [`Process::kill_with`].
+    ///
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     process.kill();
+    /// }
+    /// ```
+    pub fn kill(&self) -> bool {
+        self.kill_with(Signal::Kill).unwrap_or(false)
+    }
+
+    /// Sends the given `signal` to the process. If the signal doesn't exist on this platform,
+    /// it'll do nothing and will return `None`. Otherwise it'll return `Some(bool)`. The boolean
+    /// value will depend on whether or not the signal was sent successfully.
+    ///
+    /// If you just want to kill the process, use [`Process::kill`] directly. If you want to wait
+    /// for this process to end, you can use [`Process::wait`].
+    ///
+    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
+    /// levels than the current process for example.
+    ///
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, Signal, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if process.kill_with(Signal::Kill).is_none() {
+    ///         println!("This signal isn't supported on this platform");
+    ///     }
+    /// }
+    /// ```
+    pub fn kill_with(&self, signal: Signal) -> Option<bool> {
+        self.inner.kill_with(signal)
+    }
+
+    /// Wait for process termination and returns its [`ExitStatus`] if it could be retrieved,
+    /// returns `None` otherwise.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Waiting for pid 1337");
+    ///     let exit_status = process.wait();
+    ///     println!("Pid 1337 exited with: {exit_status:?}");
+    /// }
+    /// ```
+    pub fn wait(&self) -> Option<ExitStatus> {
+        self.inner.wait()
+    }
+
+    /// Returns the name of the process.
+    ///
+    /// **⚠️ Important ⚠️**
+    ///
+    /// On **Linux**, there are two things to know about processes' name:
+    ///  1. It is limited to 15 characters.
+    ///  2. It is not always the exe name.
+    ///
+    /// If you are looking for a specific process, unless you know what you are
+    /// doing, in most cases it's better to use [`Process::exe`] instead (which
+    /// can be empty sometimes!).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &OsStr {
+        self.inner.name()
+    }
+
+    /// Returns the `comm` name of the process (limited to 15 characters), which can be changed
+    /// by the process itself.
+    ///
+    /// On Linux, it's what [`Process::name`] returns.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux, where
+    /// [`Process::name`] comes from other sources (like the executable name on Windows).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.name_from_comm());
+    /// }
+    /// ```
+    pub fn name_from_comm(&self) -> Option<&OsStr> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                Some(self.name())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the file name of the executable of the process (from [`Process::exe`]), or
+    /// `None` if the executable path isn't available.
+    ///
+    /// The executable path needs to be refreshed (with [`ProcessRefreshKind::with_exe`]) for
+    /// this method to return something.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.name_from_exe());
+    /// }
+    /// ```
+    pub fn name_from_exe(&self) -> Option<&OsStr> {
+        self.exe()?.file_name()
+    }
+
+    /// Returns the file name of the first argument of the command line (`argv[0]`) of the
+    /// process, or `None` if the command line is empty.
+    ///
+    /// The command line needs to be refreshed (with [`ProcessRefreshKind::with_cmd`]) for this
+    /// method to return something. Keep in mind that a process can set its `argv[0]` to any
+    /// value.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.name_from_cmdline());
+    /// }
+    /// ```
+    pub fn name_from_cmdline(&self) -> Option<&OsStr> {
+        Path::new(self.cmd().first()?).file_name()
+    }
+
+    /// Returns the command line.
+    ///
+    ///  **⚠️ Important ⚠️**
+    ///
+    /// On **Windows**, you might need to use `administrator` privileges when running your program
+    /// to have access to this information.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cmd());
+    /// }
+    /// ```
+    pub fn cmd(&self) -> &[OsString] {
+        self.inner.cmd()
+    }
+
+    /// Returns the path to the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.exe());
+    /// }
+    /// ```
+    ///
+    /// ### Implementation notes
+    ///
+    /// On Linux, this method will return an empty path if there
+    /// was an error trying to read `/proc/<pid>/exe`. This can
+    /// happen, for example, if the permission levels or UID namespaces
+    /// between the caller and target processes are different.
+    ///
+    /// It is also the case that `cmd[0]` is _not_ usually a correct
+    /// replacement for this.
+    /// A process [may change its `cmd[0]` value](https://man7.org/linux/man-pages/man5/proc.5.html)
+    /// freely, making this an untrustworthy source of information.
+    pub fn exe(&self) -> Option<&Path> {
+        self.inner.exe()
+    }
+
+    /// Returns the size (in bytes) of the executable file of the process on disk, or `None` if
+    /// it couldn't be retrieved (the file doesn't exist anymore, missing permissions, etc).
+    ///
+    /// The file is the one returned by [`Process::exe`].
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.exe_size());
+    /// }
+    /// ```
+    pub fn exe_size(&self) -> Option<u64> {
+        std::fs::metadata(self.exe()?)
+            .ok()
+            .map(|metadata| metadata.len())
+    }
+
+    /// Returns the device and inode numbers (in this order) of the executable file of the
+    /// process, or `None` if they couldn't be retrieved.
+    ///
+    /// Unlike the path returned by [`Process::exe`], they allow to know if two processes run
+    /// the same binary even if it was started from different paths (bind mounts, containers,
+    /// etc).
+    ///
+    /// On Linux, the file is the one the process was actually started from, even if it isn't
+    /// accessible from the current mount namespace. On other Unix systems, it is the one
+    /// returned by [`Process::exe`].
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some((dev, inode)) = process.exe_identity() {
+    ///         println!("device: {dev}, inode: {inode}");
+    ///     }
+    /// }
+    /// ```
+    pub fn exe_identity(&self) -> Option<(u64, u64)> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.exe_identity()
+            } else if #[cfg(unix)] {
+                use std::os::unix::fs::MetadataExt;
+
+                let metadata = std::fs::metadata(self.exe()?).ok()?;
+                Some((metadata.dev(), metadata.ino()))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the PID of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.pid());
+    /// }
+    /// ```
+    pub fn pid(&self) -> Pid {
+        self.inner.pid()
+    }
+
+    /// Returns the environment variables of the process.
+    ///
+    /// They are retrieved when the process is refreshed with [`ProcessRefreshKind::environ`]
+    /// enabled. On Linux, it is the environment the process was started with (changes done by
+    /// the process itself afterwards, with `setenv` for example, aren't visible).
+    ///
+    /// It is empty for processes without environment (like kernel threads or zombie processes)
+    /// but also when it couldn't be retrieved. Use [`Process::try_environ`] to tell the two
+    /// apart.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.environ());
+    /// }
+    /// ```
+    pub fn environ(&self) -> &[OsString] {
+        self.inner.environ()
+    }
+
+    /// Retrieves the environment variables of the process.
+    ///
+    /// Unlike [`Process::environ`], it returns why they couldn't be retrieved, which is mostly
+    /// useful to know if you need elevated privileges ([`SysinfoError::PermissionDenied`]) or if
+    /// the process is gone ([`SysinfoError::NotFound`]). Processes without environment (like
+    /// kernel threads or zombie processes) return an empty list.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns
+    /// [`SysinfoError::NotSupported`] for all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, SysinfoError, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     match process.try_environ() {
+    ///         Ok(environ) => println!("{environ:?}"),
+    ///         Err(SysinfoError::PermissionDenied) => println!("you need to be root"),
+    ///         Err(e) => println!("failed to get environment: {e}"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`SysinfoError::PermissionDenied`]: crate::SysinfoError::PermissionDenied
+    /// [`SysinfoError::NotFound`]: crate::SysinfoError::NotFound
+    /// [`SysinfoError::NotSupported`]: crate::SysinfoError::NotSupported
+    pub fn try_environ(&self) -> Result<Vec<OsString>, crate::SysinfoError> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.try_environ()
+            } else {
+                Err(crate::SysinfoError::NotSupported)
+            }
+        }
+    }
+
+    /// Returns the current working directory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cwd());
+    /// }
+    /// ```
+    pub fn cwd(&self) -> Option<&Path> {
+        self.inner.cwd()
+    }
+
+    /// Returns the path of the root directory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.root());
+    /// }
+    /// ```
+    pub fn root(&self) -> Option<&Path> {
+        self.inner.root()
+    }
+
+    /// Returns the content of the `/proc/<pid>/stat` file read during the last refresh.
+    ///
+    /// It allows to get information that `sysinfo` doesn't provide (like the number of page
+    /// faults) without having to read the file again. The fields are described in the
+    /// [proc(5)](https://man7.org/linux/man-pages/man5/proc_pid_stat.5.html) man page.
+    ///
+    /// ⚠️ This method is only available with the `raw-access` feature and is only implemented
+    /// for Linux. It always returns `None` for all other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.raw_stat());
+    /// }
+    /// ```
+    #[cfg(feature = "raw-access")]
+    pub fn raw_stat(&self) -> Option<&str> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.raw_stat()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the memory usage (in bytes).
+    ///
+    /// This method returns the [size of the resident set], that is, the amount of memory that the
+    /// process allocated and which is currently mapped in physical RAM. It does not include memory
+    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
+    ///
+    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
+    /// present time, but it might not be a good indicator of the total memory that the process will
+    /// be using over its lifetime. For that purpose, you can try and use
+    /// [`virtual_memory`](Process::virtual_memory).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.memory());
+    /// }
+    /// ```
+    ///
+    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
+    pub fn memory(&self) -> u64 {
+        self.inner.memory()
+    }
+
+    /// Returns the percentage of the total memory of the system used by this process, computed
+    /// from [`Process::memory`] and [`System::total_memory`].
+    ///
+    /// It returns `0.` if the total memory of `system` isn't known (for example if
+    /// [`System::refresh_memory`] was never called).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:.1}%", process.memory_percent(&s));
+    /// }
+    /// ```
+    pub fn memory_percent(&self, system: &System) -> f32 {
+        match system.total_memory() {
+            0 => 0.,
+            total_memory => (self.memory() as f64 / total_memory as f64 * 100.) as f32,
+        }
+    }
+
+    /// Returns the virtual memory usage (in bytes).
+    ///
+    /// This method returns the [size of virtual memory], that is, the amount of memory that the
+    /// process can access, whether it is currently mapped in physical RAM or not. It includes
+    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
+    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    ///
+    /// This value has limitations though. Depending on the operating system and type of process,
+    /// this value might be a good indicator of the total memory that the process will be using over
+    /// its lifetime. However, for example, in the version 14 of macOS this value is in the order of
+    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
+    /// process maps into memory a very large file, this value will increase accordingly, even if
+    /// the process is not actively using the memory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.virtual_memory());
+    /// }
+    /// ```
+    ///
+    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
+    pub fn virtual_memory(&self) -> u64 {
+        self.inner.virtual_memory()
+    }
+
+    /// Returns the parent PID.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.parent());
+    /// }
+    /// ```
+    pub fn parent(&self) -> Option<Pid> {
+        self.inner.parent()
+    }
+
+    /// Returns the status of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.status());
+    /// }
+    /// ```
+    pub fn status(&self) -> ProcessStatus {
+        self.inner.status()
+    }
+
+    /// Returns the time where the process was started (in seconds) from epoch.
     ///
-    /// ## Other OS
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// Not available.
-    Zombie,
-    /// ## Linux
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Started at {} seconds", process.start_time());
+    /// }
+    /// ```
+    pub fn start_time(&self) -> u64 {
+        self.inner.start_time()
+    }
+
+    /// Returns the time where the process was started, in clock ticks since boot.
     ///
-    /// Tracing stop (Linux 2.6.33 onward). Stopped by debugger during the tracing.
+    /// Contrary to [`Process::start_time`], which has a precision of one second, it allows to
+    /// order processes started during the same second. The number of clock ticks per second
+    /// is usually 100 (returned by `sysconf(_SC_CLK_TCK)`).
     ///
-    /// ## Other OS
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
-    /// Not available.
-    Tracing,
-    /// ## Linux
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// Dead/uninterruptible sleep (usually IO).
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Started at {:?} ticks", process.start_time_ticks());
+    /// }
+    /// ```
+    pub fn start_time_ticks(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.start_time_ticks()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns for how much time the process has been running (in seconds).
     ///
-    /// ## FreeBSD
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// A process should never end up in this state.
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Running since {} seconds", process.run_time());
+    /// }
+    /// ```
+    pub fn run_time(&self) -> u64 {
+        self.inner.run_time()
+    }
+
+    /// Returns the total CPU usage (in %). Notice that it might be bigger than
+    /// 100 if run on a multi-core machine.
     ///
-    /// ## Other OS
+    /// It is the sum of the usage of each CPU core used by the process: a process fully using
+    /// 2 cores is at 200%. If you want a value between 0% and 100% of the whole machine, use
+    /// [`Process::cpu_usage_normalized`].
     ///
-    /// Not available.
-    Dead,
-    /// ## Linux
+    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
+    /// **twice** because CPU usage computation is based on time diff (process
+    /// time on a given time period divided by total system time on the same
+    /// time period).
     ///
-    /// Wakekill (Linux 2.6.33 to 3.13 only).
+    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
+    /// between two calls of this method (take a look at
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
+    /// more information).
     ///
-    /// ## Other OS
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
     ///
-    /// Not available.
-    Wakekill,
-    /// ## Linux
+    /// let mut s = System::new_all();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPU usage to get actual value.
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_cpu()
+    /// );
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}%", process.cpu_usage());
+    /// }
+    /// ```
+    pub fn cpu_usage(&self) -> f32 {
+        self.inner.cpu_usage()
+    }
+
+    /// Returns the CPU usage of the process relative to the whole machine (between 0% and
+    /// 100%).
     ///
-    /// Waking (Linux 2.6.33 to 3.13 only).
+    /// It is [`Process::cpu_usage`] divided by the number of logical CPUs currently online: on
+    /// a machine with 4 logical CPUs, a process fully using 2 cores is at 50%. It returns `0.`
+    /// if the number of CPUs couldn't be retrieved.
     ///
-    /// ## Other OS
+    /// The same warnings as for [`Process::cpu_usage`] apply.
     ///
-    /// Not available.
-    Waking,
-    /// ## Linux
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
     ///
-    /// Parked (Linux 3.9 to 3.13 only).
+    /// let mut s = System::new_all();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPU usage to get actual value.
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_cpu()
+    /// );
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}% of the machine", process.cpu_usage_normalized());
+    /// }
+    /// ```
+    pub fn cpu_usage_normalized(&self) -> f32 {
+        match SystemInner::logical_cpu_count() {
+            0 => 0.,
+            count => self.cpu_usage() / count as f32,
+        }
+    }
+
+    /// Returns the total accumulated CPU usage (in CPU-milliseconds). Note
+    /// that it might be bigger than the total clock run time of a process if
+    /// run on a multi-core machine.
     ///
-    /// ## macOS
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// Halted at a clean point.
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.accumulated_cpu_time());
+    /// }
+    /// ```
+    pub fn accumulated_cpu_time(&self) -> u64 {
+        self.inner.accumulated_cpu_time()
+    }
+
+    /// Returns number of bytes read and written to disk.
     ///
-    /// ## Other OS
+    /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
+    /// written bytes.
     ///
-    /// Not available.
-    Parked,
-    /// ## FreeBSD
+    /// ⚠️ Files might be cached in memory by your OS, meaning that reading/writing them might not
+    /// increase the `read_bytes`/`written_bytes` values. You can find more information about it
+    /// in the `proc_pid_io` manual (`man proc_pid_io` on unix platforms).
     ///
-    /// Blocked on a lock.
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// ## Other OS
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     let disk_usage = process.disk_usage();
+    ///     println!("read bytes   : new/total => {}/{}",
+    ///         disk_usage.read_bytes,
+    ///         disk_usage.total_read_bytes,
+    ///     );
+    ///     println!("written bytes: new/total => {}/{}",
+    ///         disk_usage.written_bytes,
+    ///         disk_usage.total_written_bytes,
+    ///     );
+    /// }
+    /// ```
+    pub fn disk_usage(&self) -> DiskUsage {
+        self.inner.disk_usage()
+    }
+
+    /// Returns the total number of read and write system calls (in this order) made by this
+    /// process, or `None` if this information couldn't be retrieved.
     ///
-    /// Not available.
-    LockBlocked,
-    /// ## Linux
+    /// Like [`Process::disk_usage`], it is refreshed when [`ProcessRefreshKind::disk_usage`] is
+    /// set. Take a look at [`Process::syscall_counts_delta`] to get the number of calls since the
+    /// last refresh.
     ///
-    /// Waiting in uninterruptible disk sleep.
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
-    /// ## Other OS
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// Not available.
-    UninterruptibleDiskSleep,
-    /// Unknown.
-    Unknown(u32),
-}
-
-/// Enum describing the different kind of threads.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
-pub enum ThreadKind {
-    /// Kernel thread.
-    Kernel,
-    /// User thread.
-    Userland,
-}
-
-/// Struct containing information of a process.
-///
-/// ## iOS
-///
-/// This information cannot be retrieved on iOS due to sandboxing.
-///
-/// ## Apple app store
-///
-/// If you are building a macOS Apple app store, it won't be able
-/// to retrieve this information.
-///
-/// ```no_run
-/// use sysinfo::{Pid, System};
-///
-/// let s = System::new_all();
-/// if let Some(process) = s.process(Pid::from(1337)) {
-///     println!("{:?}", process.name());
-/// }
-/// ```
-pub struct Process {
-    pub(crate) inner: ProcessInner,
-}
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some((reads, writes)) = process.syscall_counts() {
+    ///         println!("read calls: {reads}, write calls: {writes}");
+    ///     }
+    /// }
+    /// ```
+    pub fn syscall_counts(&self) -> Option<(u64, u64)> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.syscall_counts()
+            } else {
+                None
+            }
+        }
+    }
 
-impl Process {
-    /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
-    /// platforms by this crate).
+    /// Returns the number of read and write system calls (in this order) made by this process
+    /// since the last refresh, or `None` if this information couldn't be retrieved.
     ///
-    /// Returns `true` if the signal was sent successfully. If you want to wait for this process
-    /// to end, you can use [`Process::wait`].
+    /// It needs two refreshes: it returns `None` until the system calls of the process were
+    /// counted twice.
     ///
-    /// ⚠️ Even if this function returns `true`, it doesn't necessarily mean that the process will
-    /// be killed. It just means that the signal was sent successfully.
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
-    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
-    /// levels than the current process for example.
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// If you want to use another signal, take a look at [`Process::kill_with`].
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some((reads, writes)) = process.syscall_counts_delta() {
+    ///         println!("new read calls: {reads}, new write calls: {writes}");
+    ///     }
+    /// }
+    /// ```
+    pub fn syscall_counts_delta(&self) -> Option<(u64, u64)> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.syscall_counts_delta()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the total time this process spent waiting on a run queue for a CPU (its
+    /// scheduling latency), or `None` if this information couldn't be retrieved.
     ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    /// A high scheduling latency means that the process is affected by CPU contention, which
+    /// isn't visible from [`Process::cpu_usage`]. It is only refreshed if
+    /// [`ProcessRefreshKind::scheduling_latency`] is enabled. Take a look at
+    /// [`Process::scheduling_latency_delta`] to get the time spent waiting since the last
+    /// refresh.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_scheduling_latency(),
+    /// );
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     process.kill();
+    ///     println!("waited for a CPU: {:?}", process.scheduling_latency());
     /// }
     /// ```
-    pub fn kill(&self) -> bool {
-        self.kill_with(Signal::Kill).unwrap_or(false)
+    pub fn scheduling_latency(&self) -> Option<Duration> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.scheduling_latency()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Sends the given `signal` to the process. If the signal doesn't exist on this platform,
-    /// it'll do nothing and will return `None`. Otherwise it'll return `Some(bool)`. The boolean
-    /// value will depend on whether or not the signal was sent successfully.
-    ///
-    /// If you just want to kill the process, use [`Process::kill`] directly. If you want to wait
-    /// for this process to end, you can use [`Process::wait`].
+    /// Returns the time this process spent waiting on a run queue for a CPU since the last
+    /// refresh, or `None` if this information couldn't be retrieved.
     ///
-    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
-    /// levels than the current process for example.
+    /// It needs two refreshes: it returns `None` until the scheduling latency of the process was
+    /// retrieved twice.
     ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, Signal, System};
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new();
+    /// let refresh_kind = ProcessRefreshKind::nothing().with_scheduling_latency();
+    /// s.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+    /// // Wait a bit...
+    /// s.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if process.kill_with(Signal::Kill).is_none() {
-    ///         println!("This signal isn't supported on this platform");
-    ///     }
+    ///     println!("waited for a CPU: {:?}", process.scheduling_latency_delta());
     /// }
     /// ```
-    pub fn kill_with(&self, signal: Signal) -> Option<bool> {
-        self.inner.kill_with(signal)
+    pub fn scheduling_latency_delta(&self) -> Option<Duration> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.scheduling_latency_delta()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Wait for process termination and returns its [`ExitStatus`] if it could be retrieved,
-    /// returns `None` otherwise.
+    /// Returns how fast the memory (RSS) of this process grew (in bytes per second) over its
+    /// recent memory samples, or `None` if there aren't enough samples yet.
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// A sample is recorded every time the memory of this process is refreshed with
+    /// [`ProcessRefreshKind::memory_history`] enabled (which also refreshes
+    /// [`Process::memory`]), and only the last samples are kept. A negative value means that
+    /// the memory usage is going down. If another process takes the PID of this process, the
+    /// history starts again from scratch.
     ///
-    /// let mut s = System::new_all();
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
     ///
+    /// let mut s = System::new();
+    /// let refresh_kind = ProcessRefreshKind::nothing().with_memory_history();
+    /// for _ in 0..10 {
+    ///     s.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+    ///     std::thread::sleep(std::time::Duration::from_secs(1));
+    /// }
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Waiting for pid 1337");
-    ///     let exit_status = process.wait();
-    ///     println!("Pid 1337 exited with: {exit_status:?}");
+    ///     println!("memory growth: {:?} B/s", process.memory_growth_rate());
     /// }
     /// ```
-    pub fn wait(&self) -> Option<ExitStatus> {
-        self.inner.wait()
+    pub fn memory_growth_rate(&self) -> Option<f64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.memory_growth_rate()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the name of the process.
+    /// Returns an approximation of the number of bytes received and transmitted over the network
+    /// by this process or `None` if this information couldn't be retrieved.
     ///
-    /// **⚠️ Important ⚠️**
+    /// It is only refreshed if [`ProcessRefreshKind::network_usage`] is enabled.
     ///
-    /// On **Linux**, there are two things to know about processes' name:
-    ///  1. It is limited to 15 characters.
-    ///  2. It is not always the exe name.
+    /// ⚠️ This is **not** a per-process accounting: on Linux, the values come from
+    /// `/proc/<pid>/net/dev`, which contains the counters of all the interfaces (except the
+    /// loopback one) of the network namespace of the process. So all the processes sharing a
+    /// network namespace (usually all the processes of the host, unless they are running in a
+    /// container) will return the same values. Accurate per-process accounting requires packet
+    /// capture (with `CAP_NET_ADMIN`) or eBPF, which sysinfo doesn't do.
     ///
-    /// If you are looking for a specific process, unless you know what you are
-    /// doing, in most cases it's better to use [`Process::exe`] instead (which
-    /// can be empty sometimes!).
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_network_usage(),
+    /// );
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.name());
+    ///     if let Some(network_usage) = process.network_usage() {
+    ///         println!("received bytes   : new/total => {}/{}",
+    ///             network_usage.received_bytes,
+    ///             network_usage.total_received_bytes,
+    ///         );
+    ///         println!("transmitted bytes: new/total => {}/{}",
+    ///             network_usage.transmitted_bytes,
+    ///             network_usage.total_transmitted_bytes,
+    ///         );
+    ///     }
     /// }
     /// ```
-    pub fn name(&self) -> &OsStr {
-        self.inner.name()
+    pub fn network_usage(&self) -> Option<NetworkUsage> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.network_usage()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the command line.
-    ///
-    ///  **⚠️ Important ⚠️**
+    /// Returns the ID of the owner user of this process or `None` if this
+    /// information couldn't be retrieved. If you want to get the [`User`] from
+    /// it, take a look at [`Users::get_user_by_id`].
     ///
-    /// On **Windows**, you might need to use `administrator` privileges when running your program
-    /// to have access to this information.
+    /// [`User`]: crate::User
+    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.cmd());
+    ///     println!("User id for process 1337: {:?}", process.user_id());
     /// }
     /// ```
-    pub fn cmd(&self) -> &[OsString] {
-        self.inner.cmd()
+    pub fn user_id(&self) -> Option<&Uid> {
+        self.inner.user_id()
     }
 
-    /// Returns the path to the process.
+    /// Returns the user ID of the effective owner of this process or `None` if
+    /// this information couldn't be retrieved. If you want to get the [`User`]
+    /// from it, take a look at [`Users::get_user_by_id`].
+    ///
+    /// If you run something with `sudo`, the real user ID of the launched
+    /// process will be the ID of the user you are logged in as but effective
+    /// user ID will be `0` (i-e root).
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// [`User`]: crate::User
+    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.exe());
+    ///     println!("User id for process 1337: {:?}", process.effective_user_id());
     /// }
     /// ```
-    ///
-    /// ### Implementation notes
-    ///
-    /// On Linux, this method will return an empty path if there
-    /// was an error trying to read `/proc/<pid>/exe`. This can
-    /// happen, for example, if the permission levels or UID namespaces
-    /// between the caller and target processes are different.
-    ///
-    /// It is also the case that `cmd[0]` is _not_ usually a correct
-    /// replacement for this.
-    /// A process [may change its `cmd[0]` value](https://man7.org/linux/man-pages/man5/proc.5.html)
-    /// freely, making this an untrustworthy source of information.
-    pub fn exe(&self) -> Option<&Path> {
-        self.inner.exe()
+    pub fn effective_user_id(&self) -> Option<&Uid> {
+        self.inner.effective_user_id()
     }
 
-    /// Returns the PID of the process.
+    /// Returns the process group ID of the process.
+    ///
+    /// ⚠️ It always returns `None` on Windows.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.pid());
+    ///     println!("Group ID for process 1337: {:?}", process.group_id());
     /// }
     /// ```
-    pub fn pid(&self) -> Pid {
-        self.inner.pid()
+    pub fn group_id(&self) -> Option<Gid> {
+        self.inner.group_id()
     }
 
-    /// Returns the environment variables of the process.
+    /// Returns the effective group ID of the process.
+    ///
+    /// If you run something with `sudo`, the real group ID of the launched
+    /// process will be the primary group ID you are logged in as but effective
+    /// group ID will be `0` (i-e root).
+    ///
+    /// ⚠️ It always returns `None` on Windows.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.environ());
+    ///     println!("User id for process 1337: {:?}", process.effective_group_id());
     /// }
     /// ```
-    pub fn environ(&self) -> &[OsString] {
-        self.inner.environ()
+    pub fn effective_group_id(&self) -> Option<Gid> {
+        self.inner.effective_group_id()
     }
 
-    /// Returns the current working directory.
+    /// Returns the session ID for the current process or `None` if it couldn't
+    /// be retrieved.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.cwd());
+    ///     println!("Session ID for process 1337: {:?}", process.session_id());
     /// }
     /// ```
-    pub fn cwd(&self) -> Option<&Path> {
-        self.inner.cwd()
+    pub fn session_id(&self) -> Option<Pid> {
+        self.inner.session_id()
     }
 
-    /// Returns the path of the root directory.
+    /// Returns `true` if the process is in the foreground process group of its controlling
+    /// terminal, `false` if it's in the background or `None` if it has no controlling terminal
+    /// (or if this information couldn't be retrieved).
+    ///
+    /// It's what `ps` displays with a `+` in its `STAT` column.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.root());
+    ///     println!("in the foreground: {:?}", process.is_foreground());
     /// }
     /// ```
-    pub fn root(&self) -> Option<&Path> {
-        self.inner.root()
+    pub fn is_foreground(&self) -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.is_foreground()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the memory usage (in bytes).
+    /// Returns the integrity level of the process (from the mandatory label of its token), or
+    /// `None` if it couldn't be retrieved.
     ///
-    /// This method returns the [size of the resident set], that is, the amount of memory that the
-    /// process allocated and which is currently mapped in physical RAM. It does not include memory
-    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
+    /// It tells which sandbox or privilege boundary the process is in: a browser renderer
+    /// usually runs at [`IntegrityLevel::Low`] while an elevated process runs at
+    /// [`IntegrityLevel::High`].
     ///
-    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
-    /// present time, but it might not be a good indicator of the total memory that the process will
-    /// be using over its lifetime. For that purpose, you can try and use
-    /// [`virtual_memory`](Process::virtual_memory).
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Windows. It always returns `None` for all other
+    /// systems.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.memory());
+    ///     println!("integrity level: {:?}", process.integrity_level());
     /// }
     /// ```
-    ///
-    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
-    pub fn memory(&self) -> u64 {
-        self.inner.memory()
+    pub fn integrity_level(&self) -> Option<IntegrityLevel> {
+        cfg_if! {
+            if #[cfg(all(windows, not(feature = "unknown-ci")))] {
+                self.inner.integrity_level()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the virtual memory usage (in bytes).
-    ///
-    /// This method returns the [size of virtual memory], that is, the amount of memory that the
-    /// process can access, whether it is currently mapped in physical RAM or not. It includes
-    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
-    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    /// Tasks run by this process. If there are none, returns `None`.
     ///
-    /// This value has limitations though. Depending on the operating system and type of process,
-    /// this value might be a good indicator of the total memory that the process will be using over
-    /// its lifetime. However, for example, in the version 14 of macOS this value is in the order of
-    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
-    /// process maps into memory a very large file, this value will increase accordingly, even if
-    /// the process is not actively using the memory.
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.virtual_memory());
+    ///     if let Some(tasks) = process.tasks() {
+    ///         println!("Listing tasks for process {:?}", process.pid());
+    ///         for task_pid in tasks {
+    ///             if let Some(task) = s.process(*task_pid) {
+    ///                 println!("Task {:?}: {:?}", task.pid(), task.name());
+    ///             }
+    ///         }
+    ///     }
     /// }
     /// ```
-    ///
-    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
-    pub fn virtual_memory(&self) -> u64 {
-        self.inner.virtual_memory()
+    pub fn tasks(&self) -> Option<&HashSet<Pid>> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.tasks.as_ref()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the parent PID.
+    /// If the process is a thread, it'll return `Some` with the kind of thread it is. Returns
+    /// `None` otherwise.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.parent());
+    ///
+    /// for (_, process) in s.processes() {
+    ///     if let Some(thread_kind) = process.thread_kind() {
+    ///         println!("Process {:?} is a {thread_kind:?} thread", process.pid());
+    ///     }
     /// }
     /// ```
-    pub fn parent(&self) -> Option<Pid> {
-        self.inner.parent()
+    pub fn thread_kind(&self) -> Option<ThreadKind> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.thread_kind()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the status of the process.
+    /// Returns the index of the CPU the process last ran on (as of the last refresh). It matches
+    /// the position of the CPU in [`System::cpus`].
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.status());
+    ///     println!("last ran on CPU {:?}", process.last_cpu());
     /// }
     /// ```
-    pub fn status(&self) -> ProcessStatus {
-        self.inner.status()
+    pub fn last_cpu(&self) -> Option<usize> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.last_cpu()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the time where the process was started (in seconds) from epoch.
+    /// Returns the number of distinct CPUs the threads of this process last ran on, or `None`
+    /// if this information couldn't be retrieved.
+    ///
+    /// It allows to know if a multi-threaded process is actually running on several CPUs. Only
+    /// the last CPU of each thread is taken into account (see [`Process::last_cpu`]).
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Started at {} seconds", process.start_time());
+    ///     println!("threads last ran on {:?} CPUs", process.cpus_used_recently());
     /// }
     /// ```
-    pub fn start_time(&self) -> u64 {
-        self.inner.start_time()
+    pub fn cpus_used_recently(&self) -> Option<usize> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.cpus_used_recently()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns for how much time the process has been running (in seconds).
+    /// Returns the name of the kernel function in which the process is currently sleeping
+    /// (for example `futex_wait` or `do_select`). Returns `None` if the process is running or if
+    /// the information isn't available.
+    ///
+    /// It's particularly useful to understand why a process is stuck in an uninterruptible sleep.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Running since {} seconds", process.run_time());
+    ///     println!("waiting in: {:?}", process.wait_channel());
     /// }
     /// ```
-    pub fn run_time(&self) -> u64 {
-        self.inner.run_time()
+    pub fn wait_channel(&self) -> Option<String> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.wait_channel()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the total CPU usage (in %). Notice that it might be bigger than
-    /// 100 if run on a multi-core machine.
+    /// Returns the number of open TCP and UDP sockets (in this order) of this process, or `None`
+    /// if this information couldn't be retrieved (you usually need to have the same owner as the
+    /// process or elevated privileges).
     ///
-    /// If you want a value between 0% and 100%, divide the returned value by
-    /// the number of CPUs.
+    /// It's a much cheaper way than listing all the connections to detect connection leaks.
+    /// Both IPv4 and IPv6 sockets are counted.
     ///
-    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
-    /// **twice** because CPU usage computation is based on time diff (process
-    /// time on a given time period divided by total system time on the same
-    /// time period).
+    /// **Important**: this information is computed every time this method is called.
     ///
-    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
-    /// between two calls of this method (take a look at
-    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
-    /// more information).
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
     ///
-    /// let mut s = System::new_all();
-    /// // Wait a bit because CPU usage is based on diff.
-    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    /// // Refresh CPU usage to get actual value.
-    /// s.refresh_processes_specifics(
-    ///     ProcessesToUpdate::All,
-    ///     true,
-    ///     ProcessRefreshKind::nothing().with_cpu()
-    /// );
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}%", process.cpu_usage());
+    ///     if let Some((tcp, udp)) = process.socket_count() {
+    ///         println!("{tcp} TCP sockets and {udp} UDP sockets");
+    ///     }
     /// }
     /// ```
-    pub fn cpu_usage(&self) -> f32 {
-        self.inner.cpu_usage()
+    pub fn socket_count(&self) -> Option<(usize, usize)> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.socket_count()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the total accumulated CPU usage (in CPU-milliseconds). Note
-    /// that it might be bigger than the total clock run time of a process if
-    /// run on a multi-core machine.
+    /// Returns the number of open file descriptors of this process, grouped by what they
+    /// refer to, or `None` if this information couldn't be retrieved (you usually need to have
+    /// the same owner as the process or elevated privileges).
+    ///
+    /// It helps to find out what kind of file descriptors is leaking.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.accumulated_cpu_time());
+    ///     if let Some(fds) = process.fd_breakdown() {
+    ///         println!("{} file descriptors, {} sockets", fds.total(), fds.sockets);
+    ///     }
     /// }
     /// ```
-    pub fn accumulated_cpu_time(&self) -> u64 {
-        self.inner.accumulated_cpu_time()
+    pub fn fd_breakdown(&self) -> Option<FdBreakdown> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.fd_breakdown()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns number of bytes read and written to disk.
+    /// Returns the GPU usage of this process, or `None` if it doesn't use the GPU or if this
+    /// information couldn't be retrieved.
     ///
-    /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
-    /// written bytes.
+    /// It is computed from the `fdinfo` of the DRM file descriptors opened by the process,
+    /// so the GPU driver needs to support it (like `amdgpu`, `i915`, `xe` or `msm`). The
+    /// usage of file descriptors shared between processes is attributed to all of them.
     ///
-    /// ⚠️ Files might be cached in memory by your OS, meaning that reading/writing them might not
-    /// increase the `read_bytes`/`written_bytes` values. You can find more information about it
-    /// in the `proc_pid_io` manual (`man proc_pid_io` on unix platforms).
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     let disk_usage = process.disk_usage();
-    ///     println!("read bytes   : new/total => {}/{}",
-    ///         disk_usage.read_bytes,
-    ///         disk_usage.total_read_bytes,
-    ///     );
-    ///     println!("written bytes: new/total => {}/{}",
-    ///         disk_usage.written_bytes,
-    ///         disk_usage.total_written_bytes,
-    ///     );
+    ///     if let Some(gpu) = process.gpu_usage() {
+    ///         println!("{} bytes of VRAM, engines: {:?}", gpu.memory_vram, gpu.engines);
+    ///     }
     /// }
     /// ```
-    pub fn disk_usage(&self) -> DiskUsage {
-        self.inner.disk_usage()
+    pub fn gpu_usage(&self) -> Option<GpuUsage> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.gpu_usage()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the ID of the owner user of this process or `None` if this
-    /// information couldn't be retrieved. If you want to get the [`User`] from
-    /// it, take a look at [`Users::get_user_by_id`].
+    /// Returns the memory (in bytes) used by the kernel for the page tables of this process, or
+    /// `None` if this information couldn't be retrieved.
     ///
-    /// [`User`]: crate::User
-    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
+    /// This memory isn't part of [`Process::memory`], but it can become significant for
+    /// processes with a large address space, or when a lot of processes are running.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.user_id());
+    ///     println!("page tables: {:?} bytes", process.page_table_memory());
     /// }
     /// ```
-    pub fn user_id(&self) -> Option<&Uid> {
-        self.inner.user_id()
+    pub fn page_table_memory(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.page_table_memory()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the user ID of the effective owner of this process or `None` if
-    /// this information couldn't be retrieved. If you want to get the [`User`]
-    /// from it, take a look at [`Users::get_user_by_id`].
-    ///
-    /// If you run something with `sudo`, the real user ID of the launched
-    /// process will be the ID of the user you are logged in as but effective
-    /// user ID will be `0` (i-e root).
+    /// Returns the size (in bytes) of the stack of the main thread of this process, or `None`
+    /// if this information couldn't be retrieved.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// **Important**: this information is computed every time this method is called.
     ///
-    /// [`User`]: crate::User
-    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.effective_user_id());
+    ///     println!("stack: {:?} bytes", process.stack_memory());
     /// }
     /// ```
-    pub fn effective_user_id(&self) -> Option<&Uid> {
-        self.inner.effective_user_id()
+    pub fn stack_memory(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.stack_memory()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the process group ID of the process.
+    /// Returns the amount of memory (in bytes) locked by this process (with `mlock` for
+    /// example), or `None` if this information couldn't be retrieved.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// Locked memory can't be swapped out nor reclaimed by the kernel.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Group ID for process 1337: {:?}", process.group_id());
+    ///     println!("locked: {:?} bytes", process.locked_memory());
     /// }
     /// ```
-    pub fn group_id(&self) -> Option<Gid> {
-        self.inner.group_id()
+    pub fn locked_memory(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.locked_memory()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the effective group ID of the process.
+    /// Returns how long this process (all its threads) waited for resources, or `None` if this
+    /// information couldn't be retrieved.
     ///
-    /// If you run something with `sudo`, the real group ID of the launched
-    /// process will be the primary group ID you are logged in as but effective
-    /// group ID will be `0` (i-e root).
+    /// On Linux, it uses the delay accounting of the taskstats netlink interface, which
+    /// requires the `CAP_NET_ADMIN` capability and a kernel built with
+    /// `CONFIG_TASK_DELAY_ACCT` (and booted with `delayacct` since Linux 5.14).
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.effective_group_id());
+    /// if let Some(delays) = s.process(Pid::from(1337)).and_then(|p| p.delays()) {
+    ///     println!("waited {:?} for block I/O", delays.block_io);
     /// }
     /// ```
-    pub fn effective_group_id(&self) -> Option<Gid> {
-        self.inner.effective_group_id()
+    pub fn delays(&self) -> Option<TaskDelays> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.delays()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the session ID for the current process or `None` if it couldn't
-    /// be retrieved.
+    /// Returns the file mode creation mask (umask) of this process, or `None` if this
+    /// information couldn't be retrieved.
     ///
-    /// ⚠️ This information is computed every time this method is called.
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux. It's only available
+    /// since Linux 4.7.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Session ID for process 1337: {:?}", process.session_id());
+    /// if let Some(umask) = s.process(Pid::from(1337)).and_then(|p| p.umask()) {
+    ///     println!("umask: {umask:04o}");
     /// }
     /// ```
-    pub fn session_id(&self) -> Option<Pid> {
-        self.inner.session_id()
+    pub fn umask(&self) -> Option<u32> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.umask()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Tasks run by this process. If there are none, returns `None`.
+    /// Returns the signals blocked, pending, ignored and caught by this process, or `None` if
+    /// this information couldn't be retrieved.
+    ///
+    /// It helps understanding why a process doesn't react to a signal (for example because it's
+    /// blocking [`Signal::Term`]).
+    ///
+    /// **Important**: this information is computed every time this method is called.
     ///
     /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{Pid, Signal, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if let Some(tasks) = process.tasks() {
-    ///         println!("Listing tasks for process {:?}", process.pid());
-    ///         for task_pid in tasks {
-    ///             if let Some(task) = s.process(*task_pid) {
-    ///                 println!("Task {:?}: {:?}", task.pid(), task.name());
-    ///             }
-    ///         }
+    /// if let Some(info) = s.process(Pid::from(1337)).and_then(|p| p.signal_info()) {
+    ///     if info.is_blocking(Signal::Term) || info.is_ignoring(Signal::Term) {
+    ///         println!("SIGTERM won't terminate this process");
     ///     }
     /// }
     /// ```
-    pub fn tasks(&self) -> Option<&HashSet<Pid>> {
+    pub fn signal_info(&self) -> Option<SignalInfo> {
         cfg_if! {
             if #[cfg(all(
                 any(target_os = "linux", target_os = "android"),
                 not(feature = "unknown-ci")
             ))] {
-                self.inner.tasks.as_ref()
+                self.inner.signal_info()
             } else {
                 None
             }
         }
     }
 
-    /// If the process is a thread, it'll return `Some` with the kind of thread it is. Returns
-    /// `None` otherwise.
+    /// Returns the systemd unit this process belongs to (like `nginx.service`), or `None` if it
+    /// doesn't belong to any or if this information couldn't be retrieved.
+    ///
+    /// It's extracted from the cgroup of the process.
+    ///
+    /// **Important**: this information is computed every time this method is called.
     ///
     /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     ///
-    /// for (_, process) in s.processes() {
-    ///     if let Some(thread_kind) = process.thread_kind() {
-    ///         println!("Process {:?} is a {thread_kind:?} thread", process.pid());
-    ///     }
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("unit: {:?}", process.systemd_unit());
     /// }
     /// ```
-    pub fn thread_kind(&self) -> Option<ThreadKind> {
+    pub fn systemd_unit(&self) -> Option<String> {
         cfg_if! {
             if #[cfg(all(
                 any(target_os = "linux", target_os = "android"),
                 not(feature = "unknown-ci")
             ))] {
-                self.inner.thread_kind()
+                self.inner.systemd_unit()
             } else {
                 None
             }
@@ -1922,6 +4570,34 @@ pub enum ProcessesToUpdate<'a> {
     Some(&'a [Pid]),
 }
 
+/// Event sent by [`System::refresh_processes_with_callback`].
+///
+/// ```no_run
+/// use sysinfo::{ProcessEvent, ProcessesToUpdate, ProcessRefreshKind, System};
+///
+/// let mut s = System::new();
+/// let mut started = Vec::new();
+/// s.refresh_processes_with_callback(
+///     ProcessesToUpdate::All,
+///     ProcessRefreshKind::nothing(),
+///     |event| {
+///         if let ProcessEvent::Started(pid) = event {
+///             started.push(pid);
+///         }
+///     },
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessEvent {
+    /// The process wasn't in the processes list before the refresh.
+    Started(Pid),
+    /// The process was in the processes list before the refresh and is now dead (or couldn't
+    /// be refreshed).
+    Exited(Pid),
+    /// The process was in the processes list before the refresh and was refreshed.
+    Updated(Pid),
+}
+
 /// Used to determine what you want to refresh specifically on the [`Process`] type.
 ///
 /// When all refresh are ruled out, a [`Process`] will still retrieve the following information:
@@ -1974,6 +4650,9 @@ pub struct ProcessRefreshKind {
     cmd: UpdateKind,
     exe: UpdateKind,
     tasks: bool,
+    network_usage: bool,
+    scheduling_latency: bool,
+    memory_history: bool,
 }
 
 /// Creates a new `ProcessRefreshKind` with every refresh set to `false`, except for `tasks`.
@@ -1993,6 +4672,9 @@ impl Default for ProcessRefreshKind {
             cmd: UpdateKind::default(),
             exe: UpdateKind::default(),
             tasks: true, // Process by default includes all tasks.
+            network_usage: false,
+            scheduling_latency: false,
+            memory_history: false,
         }
     }
 }
@@ -2037,6 +4719,9 @@ impl ProcessRefreshKind {
             cmd: UpdateKind::OnlyIfNotSet,
             exe: UpdateKind::OnlyIfNotSet,
             tasks: true,
+            network_usage: true,
+            scheduling_latency: true,
+            memory_history: true,
         }
     }
 
@@ -2087,6 +4772,30 @@ It will retrieve the following information:
     impl_get_set!(ProcessRefreshKind, cmd, with_cmd, without_cmd, UpdateKind);
     impl_get_set!(ProcessRefreshKind, exe, with_exe, without_exe, UpdateKind);
     impl_get_set!(ProcessRefreshKind, tasks, with_tasks, without_tasks);
+    impl_get_set!(
+        ProcessRefreshKind,
+        network_usage,
+        with_network_usage,
+        without_network_usage,
+        "\
+It is only used on Linux. See [`Process::network_usage`] for more information."
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        scheduling_latency,
+        with_scheduling_latency,
+        without_scheduling_latency,
+        "\
+It is only used on Linux. See [`Process::scheduling_latency`] for more information."
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        memory_history,
+        with_memory_history,
+        without_memory_history,
+        "\
+It is only used on Linux. See [`Process::memory_growth_rate`] for more information."
+    );
 }
 
 /// Used to determine what you want to refresh specifically on the [`Cpu`] type.
@@ -2113,6 +4822,7 @@ It will retrieve the following information:
 pub struct CpuRefreshKind {
     cpu_usage: bool,
     frequency: bool,
+    energy: bool,
 }
 
 impl CpuRefreshKind {
@@ -2125,6 +4835,7 @@ impl CpuRefreshKind {
     ///
     /// assert_eq!(r.frequency(), false);
     /// assert_eq!(r.cpu_usage(), false);
+    /// assert_eq!(r.energy(), false);
     /// ```
     pub fn nothing() -> Self {
         Self::default()
@@ -2139,16 +4850,26 @@ impl CpuRefreshKind {
     ///
     /// assert_eq!(r.frequency(), true);
     /// assert_eq!(r.cpu_usage(), true);
+    /// assert_eq!(r.energy(), true);
     /// ```
     pub fn everything() -> Self {
         Self {
             cpu_usage: true,
             frequency: true,
+            energy: true,
         }
     }
 
     impl_get_set!(CpuRefreshKind, cpu_usage, with_cpu_usage, without_cpu_usage);
     impl_get_set!(CpuRefreshKind, frequency, with_frequency, without_frequency);
+    impl_get_set!(
+        CpuRefreshKind,
+        energy,
+        with_energy,
+        without_energy,
+        "\
+It is only used on Linux. See [`System::package_power`] for more information."
+    );
 }
 
 /// Used to determine which memory you want to refresh specifically.
@@ -2334,6 +5055,28 @@ pub fn get_current_pid() -> Result<Pid, &'static str> {
     inner()
 }
 
+/// Returns the physical memory (in bytes) used by the current process, or `None` if this
+/// information couldn't be retrieved.
+///
+/// It's the same value as [`Process::memory`], but it doesn't require to refresh the
+/// processes (nor to create a [`System`]), making it a very cheap way to monitor the memory used
+/// by your own program.
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ⚠️ It always returns `None` on FreeBSD.
+///
+/// ```no_run
+/// use sysinfo::current_memory_usage;
+///
+/// if let Some(memory) = current_memory_usage() {
+///     println!("I'm using {memory} bytes");
+/// }
+/// ```
+pub fn current_memory_usage() -> Option<u64> {
+    SystemInner::current_memory_usage()
+}
+
 /// Contains all the methods of the [`Cpu`][crate::Cpu] struct.
 ///
 /// ```no_run
@@ -2382,6 +5125,44 @@ impl Cpu {
         self.inner.cpu_usage()
     }
 
+    /// Returns the percentage of time stolen by the hypervisor from this CPU (aka the time
+    /// during which this virtual CPU was waiting for a physical CPU), or `None` if this
+    /// information isn't available.
+    ///
+    /// It isn't included in [`Cpu::cpu_usage`]. Just like it, you'll need to refresh it at least
+    /// twice to have a non-zero value.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    ///
+    /// // Wait a bit because steal time is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu_all();
+    ///
+    /// for cpu in s.cpus() {
+    ///     println!("{:?}%", cpu.steal_time_percent());
+    /// }
+    /// ```
+    pub fn steal_time_percent(&self) -> Option<f32> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                Some(self.inner.steal_time_percent())
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns this CPU's name.
     ///
     /// ```no_run
@@ -2445,12 +5226,293 @@ impl Cpu {
     pub fn frequency(&self) -> u64 {
         self.inner.frequency()
     }
+
+    /// Returns the idle states (also known as C-states) of this CPU, from the shallowest to the
+    /// deepest, or `None` if this information isn't available.
+    ///
+    /// The deeper the state, the less power the CPU uses but the longer it takes to wake up.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states().unwrap_or_default() {
+    ///         println!("{} {}: {:?}", cpu.name(), state.name, state.residency);
+    ///     }
+    /// }
+    /// ```
+    pub fn idle_states(&self) -> Option<Vec<IdleState>> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.idle_states()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the temperature (in degrees Celsius) of the core of this CPU, or `None` if this
+    /// information isn't available.
+    ///
+    /// Logical CPUs sharing the same physical core return the same temperature. On Linux, it is
+    /// read from the `coretemp` hwmon sensors, so it's only available on Intel CPUs.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {}% {:?}°C", cpu.name(), cpu.cpu_usage(), cpu.temperature());
+    /// }
+    /// ```
+    pub fn temperature(&self) -> Option<f32> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.temperature()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the maximum frequency (in MHz) this CPU is currently allowed to run at, or
+    /// `None` if this information isn't available.
+    ///
+    /// Unlike the hardware maximum frequency, this limit is set by the current frequency
+    /// scaling policy, which can be lowered by the user, a power profile or thermal throttling.
+    /// If it's lower than expected, it explains why the CPU doesn't boost. On Linux, it is read
+    /// from `cpufreq/scaling_max_freq`.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!(
+    ///         "{}: {} MHz (max: {:?} MHz)",
+    ///         cpu.name(),
+    ///         cpu.frequency(),
+    ///         cpu.current_max_frequency(),
+    ///     );
+    /// }
+    /// ```
+    pub fn current_max_frequency(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.current_max_frequency()
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Counters of a process.
+///
+/// It is returned by [`System::process_counters`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ProcessCounters {
+    /// Start time of the process (see [`Process::start_time`]), used to detect PID reuse.
+    pub start_time: u64,
+    /// Disk usage of the process.
+    pub disk_usage: DiskUsage,
+    /// CPU time counters of the process.
+    pub cpu: ProcessCpuCounters,
+}
+
+/// CPU time counters of a process.
+///
+/// It is part of [`ProcessCounters`]. Its content depends on the system, so it is only meant to
+/// be given back to [`System::restore_process_counters`] on the same computer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ProcessCpuCounters {
+    pub(crate) user_time: u64,
+    pub(crate) system_time: u64,
+    pub(crate) global_user_time: u64,
+    pub(crate) global_system_time: u64,
+}
+
+/// Time counters of the global CPU and of each CPU.
+///
+/// It is returned by [`System::cpu_counters`]. Its content depends on the system, so it is
+/// only meant to be given back to [`System::restore_cpu_counters`] on the same computer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CpuCounters {
+    pub(crate) global: Vec<u64>,
+    pub(crate) cpus: Vec<Vec<u64>>,
+}
+
+/// Mode of transparent huge pages (THP).
+///
+/// It is returned by [`System::transparent_huge_pages`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// if let Some(mode) = System::transparent_huge_pages() {
+///     println!("transparent huge pages: {mode}");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ThpMode {
+    /// Huge pages are used for all memory allocations when possible.
+    Always,
+    /// Huge pages are used only for the memory regions marked with `madvise(MADV_HUGEPAGE)`.
+    Madvise,
+    /// Huge pages are never used.
+    Never,
+}
+
+impl fmt::Display for ThpMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Always => "always",
+            Self::Madvise => "madvise",
+            Self::Never => "never",
+        })
+    }
+}
+
+/// Statistics about the sockets and the memory used by TCP.
+///
+/// It is returned by [`System::tcp_memory`]. All memory values are in bytes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TcpMemoryStats {
+    /// Number of sockets in use (of all kinds).
+    pub sockets_used: u64,
+    /// Number of TCP sockets in use.
+    pub tcp_in_use: u64,
+    /// Number of TCP sockets not attached to any file descriptor anymore.
+    pub orphans: u64,
+    /// Number of TCP sockets in the `TIME_WAIT` state.
+    pub time_wait: u64,
+    /// Memory used by TCP.
+    pub memory: u64,
+    /// Below this amount of memory, the kernel doesn't limit the memory used by TCP.
+    pub memory_min: u64,
+    /// Above this amount of memory, the kernel starts reducing the memory used by TCP.
+    pub memory_pressure: u64,
+    /// Maximum amount of memory which can be used by TCP.
+    pub memory_max: u64,
+}
+
+impl TcpMemoryStats {
+    /// Returns `true` if the memory used by TCP is above [`TcpMemoryStats::memory_pressure`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if System::tcp_memory().is_some_and(|tcp| tcp.is_under_pressure()) {
+    ///     println!("TCP is under memory pressure");
+    /// }
+    /// ```
+    pub fn is_under_pressure(&self) -> bool {
+        self.memory > self.memory_pressure
+    }
+}
+
+/// Optional information which might not be available on all platforms.
+///
+/// It is used by [`System::supports`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Feature {
+    /// The memory available for (re)use, returned by [`System::available_memory`]. If it's not
+    /// available, the free memory is returned instead.
+    AvailableMemory,
+    /// The load average, returned by [`System::load_average`].
+    LoadAverage,
+    /// The CPU usage of processes, returned by [`Process::cpu_usage`].
+    ProcessCpuUsage,
+    /// The disk usage of processes, returned by [`Process::disk_usage`].
+    ProcessDiskUsage,
+    /// The environment of processes, returned by [`Process::environ`].
+    ProcessEnvironment,
+    /// The tasks (threads) of processes, returned by [`Process::tasks`].
+    ProcessTasks,
+    /// The cgroup limits, returned by [`System::cgroup_limits`]. It requires the cgroup memory
+    /// controller to be available. [`System::cgroup_limits`] can still return `None` if the
+    /// cgroup has no memory limit.
+    CGroupLimits,
+    /// The swap and page fault rates, returned by [`System::swap_in_per_second`],
+    /// [`System::swap_out_per_second`] and [`System::major_page_faults_per_second`].
+    SwapRates,
+    /// The power consumed by the CPU packages, returned by [`System::package_power`]. On Linux,
+    /// it requires the RAPL energy counters to be readable (only by root since Linux 5.10).
+    PackagePower,
+    /// The pressure stall information of the memory, used by
+    /// [`System::is_under_memory_pressure`]. If it's not available, an heuristic based on the
+    /// available memory is used instead.
+    MemoryPressureStall,
+    /// The delays of processes, returned by [`Process::delays`]. On Linux, it requires the
+    /// `CAP_NET_ADMIN` capability and the kernel delay accounting.
+    ProcessDelays,
+    /// The scheduling latency of processes, returned by [`Process::scheduling_latency`].
+    ProcessSchedulingLatency,
+    /// The number of read and write syscalls of processes, returned by
+    /// [`Process::syscall_counts`].
+    ProcessSyscallCounts,
+    /// The umask of processes, returned by [`Process::umask`]. On Linux, it requires Linux 4.7
+    /// or newer.
+    ProcessUmask,
+    /// The signal masks of processes, returned by [`Process::signal_info`].
+    ProcessSignalInfo,
+}
+
+/// Idle state (also known as C-state) of a CPU.
+///
+/// It is returned by [`Cpu::idle_states`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdleState {
+    /// Name of the idle state (like `"POLL"` or `"C1E"`).
+    pub name: String,
+    /// Total time spent by the CPU in this state.
+    pub residency: Duration,
+    /// Number of times the CPU entered this state.
+    pub entries: u64,
 }
 
 #[cfg(test)]
 mod test {
     use crate::*;
+    use std::collections::HashSet;
     use std::str::FromStr;
+    use std::time::{Duration, SystemTime};
 
     // In case `Process::updated` is misused, `System::refresh_processes` might remove them
     // so this test ensures that it doesn't happen.
@@ -2579,6 +5641,22 @@ mod test {
             .any(|(_, proc_)| proc_.cpu_usage() > 0.0));
     }
 
+    #[test]
+    fn check_process_cpu_usage_normalized() {
+        if !IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        let mut s = System::new();
+        s.refresh_cpu_all();
+        s.refresh_processes(ProcessesToUpdate::All, false);
+        std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_processes(ProcessesToUpdate::All, true);
+        let nb_cpus = s.cpus().len() as f32;
+        assert!(s.processes().values().all(|proc_| {
+            (proc_.cpu_usage_normalized() - proc_.cpu_usage() / nb_cpus).abs() < 0.001
+        }));
+    }
+
     #[test]
     fn check_cpu_usage() {
         if !IS_SUPPORTED_SYSTEM {
@@ -2597,6 +5675,26 @@ mod test {
         panic!("CPU usage is always zero...");
     }
 
+    #[test]
+    fn check_cpu_usage_for() {
+        let mut s = System::new();
+        // No CPU matches these indexes.
+        assert_eq!(s.cpu_usage_for(&[]), 0.);
+        assert_eq!(s.cpu_usage_for(&[usize::MAX]), 0.);
+        if !IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        s.refresh_cpu_usage();
+        std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_cpu_usage();
+        let usage = s.cpus()[0].cpu_usage();
+        assert_eq!(s.cpu_usage_for(&[0]), usage);
+        assert_eq!(s.cpu_usage_for(&[0, usize::MAX]), usage);
+        let all = (0..s.cpus().len()).collect::<Vec<_>>();
+        let average = s.cpu_usage_for(&all);
+        assert!((0. ..=100.).contains(&average));
+    }
+
     #[test]
     fn check_system_info() {
         // We don't want to test on unsupported systems.
@@ -2680,6 +5778,644 @@ mod test {
         assert!(System::physical_core_count().unwrap_or(0) <= s.cpus().len());
     }
 
+    #[test]
+    fn check_missing_process_grace_period() {
+        if !IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        let pid = get_current_pid().expect("failed to get current pid");
+        let mut s = System::new();
+        s.set_missing_process_grace_period(2);
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+        let run_time = s.process(pid).unwrap().run_time();
+
+        // Simulate that the current process couldn't be refreshed.
+        let process = s.inner.processes_mut().remove(&pid).unwrap();
+        s.missing_processes.insert(
+            pid,
+            super::MissingProcess {
+                process,
+                remaining_refreshes: 2,
+            },
+        );
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+        assert!(s.missing_processes.is_empty());
+        // It's the same `Process` as before, not a new one.
+        assert!(s.process(pid).unwrap().run_time() >= run_time);
+
+        // A process which isn't refreshed anymore is dropped after the grace period.
+        let fake_pid = Pid::from_u32(u32::MAX / 2);
+        // Its PID doesn't matter since it's never listed.
+        let process = s.inner.processes_mut().remove(&pid).unwrap();
+        s.missing_processes.insert(
+            fake_pid,
+            super::MissingProcess {
+                process,
+                remaining_refreshes: 2,
+            },
+        );
+        s.refresh_processes(ProcessesToUpdate::All, true);
+        assert!(s.process(fake_pid).is_none());
+        assert_eq!(s.missing_processes[&fake_pid].remaining_refreshes, 1);
+        s.refresh_processes(ProcessesToUpdate::All, true);
+        assert!(s.missing_processes.is_empty());
+    }
+
+    #[test]
+    fn check_current_process() {
+        let mut s = System::new();
+        assert!(s.current_process().is_none());
+        if IS_SUPPORTED_SYSTEM {
+            let pid = get_current_pid().expect("failed to get current pid");
+            s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+            assert_eq!(s.current_process().map(Process::pid), Some(pid));
+        }
+    }
+
+    #[test]
+    fn check_current_memory_usage() {
+        if IS_SUPPORTED_SYSTEM && !cfg!(target_os = "freebsd") {
+            assert!(current_memory_usage().unwrap() > 0);
+        } else {
+            assert_eq!(current_memory_usage(), None);
+        }
+    }
+
+    #[test]
+    fn check_memory_percent() {
+        if !IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        let pid = get_current_pid().expect("failed to get current pid");
+        let mut s = System::new();
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+        let process = s.process(pid).expect("current process not found");
+        // The memory wasn't refreshed yet.
+        assert_eq!(process.memory_percent(&s), 0.);
+
+        s.refresh_memory();
+        let process = s.process(pid).expect("current process not found");
+        let percent = process.memory_percent(&s);
+        assert!(
+            percent > 0. && percent <= 100.,
+            "invalid percentage: {percent}"
+        );
+    }
+
+    #[test]
+    fn check_processes_started_after() {
+        let s = System::new_all();
+        assert_eq!(
+            s.processes_started_after(SystemTime::UNIX_EPOCH).count(),
+            s.processes().len()
+        );
+        let future = SystemTime::now() + Duration::from_secs(3600);
+        assert_eq!(s.processes_started_after(future).count(), 0);
+
+        if IS_SUPPORTED_SYSTEM {
+            let pid = get_current_pid().expect("failed to get current pid");
+            let start_time = s
+                .process(pid)
+                .expect("current process not found")
+                .start_time();
+            // The boot time used to get the start time is rounded to the second.
+            let time = SystemTime::UNIX_EPOCH + Duration::from_secs(start_time.saturating_sub(1));
+            assert!(s.processes_started_after(time).any(|p| p.pid() == pid));
+            assert!(s
+                .processes_started_after(time)
+                .all(|p| p.start_time() + 1 >= start_time));
+        }
+        if IS_SUPPORTED_SYSTEM && cfg!(any(target_os = "linux", target_os = "android")) {
+            // The current process was started before `now`, even if it was during the same
+            // second.
+            std::thread::sleep(Duration::from_millis(50));
+            let pid = get_current_pid().expect("failed to get current pid");
+            let now = SystemTime::now();
+            assert!(!s.processes_started_after(now).any(|p| p.pid() == pid));
+        }
+    }
+
+    #[test]
+    fn check_refresh_processes_with_callback() {
+        if !IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        let pid = get_current_pid().expect("failed to get current pid");
+        let missing = Pid::from(usize::MAX / 2);
+        let mut s = System::new();
+
+        let mut events = Vec::new();
+        s.refresh_processes_with_callback(
+            ProcessesToUpdate::Some(&[pid, missing]),
+            ProcessRefreshKind::nothing(),
+            |event| events.push(event),
+        );
+        assert_eq!(events, [ProcessEvent::Started(pid)]);
+
+        events.clear();
+        s.refresh_processes_with_callback(
+            ProcessesToUpdate::Some(&[pid, missing]),
+            ProcessRefreshKind::nothing(),
+            |event| events.push(event),
+        );
+        assert_eq!(events, [ProcessEvent::Updated(pid)]);
+
+        events.clear();
+        s.refresh_processes_with_callback(
+            ProcessesToUpdate::All,
+            ProcessRefreshKind::nothing(),
+            |event| events.push(event),
+        );
+        assert!(events.contains(&ProcessEvent::Updated(pid)));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| !matches!(event, ProcessEvent::Exited(_)))
+                .count(),
+            s.processes().len()
+        );
+    }
+
+    #[cfg(all(target_os = "linux", not(feature = "unknown-ci")))]
+    #[test]
+    fn check_refresh_processes_with_callback_exited() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .expect("failed to start sleep");
+        let pid = Pid::from_u32(child.id());
+        let mut s = System::new();
+
+        let mut events = Vec::new();
+        s.refresh_processes_with_callback(
+            ProcessesToUpdate::Some(&[pid]),
+            ProcessRefreshKind::nothing(),
+            |event| events.push(event),
+        );
+        assert_eq!(events, [ProcessEvent::Started(pid)]);
+
+        child.kill().expect("failed to kill sleep");
+        child.wait().expect("failed to wait for sleep");
+        events.clear();
+        s.refresh_processes_with_callback(
+            ProcessesToUpdate::Some(&[pid]),
+            ProcessRefreshKind::nothing(),
+            |event| events.push(event),
+        );
+        assert_eq!(events, [ProcessEvent::Exited(pid)]);
+        assert!(s.process(pid).is_none());
+    }
+
+    #[test]
+    fn check_process_name_sources() {
+        if !IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        let pid = get_current_pid().expect("failed to get current pid");
+        let mut s = System::new();
+        s.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing(),
+        );
+        let process = s.process(pid).expect("current process not found");
+        if cfg!(any(target_os = "linux", target_os = "android")) {
+            assert_eq!(process.name_from_comm(), Some(process.name()));
+        } else {
+            assert_eq!(process.name_from_comm(), None);
+        }
+        assert_eq!(process.name_from_exe(), None);
+        assert_eq!(process.name_from_cmdline(), None);
+
+        s.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing()
+                .with_exe(UpdateKind::Always)
+                .with_cmd(UpdateKind::Always),
+        );
+        let process = s.process(pid).expect("current process not found");
+        let exe = std::env::current_exe().expect("failed to get current exe");
+        assert_eq!(process.name_from_exe(), exe.file_name());
+        let arg0 = std::env::args_os().next().expect("failed to get argv[0]");
+        assert_eq!(
+            process.name_from_cmdline(),
+            std::path::Path::new(&arg0).file_name()
+        );
+    }
+
+    #[test]
+    fn check_init_system() {
+        let s = System::new_all();
+        let init = s.init_system();
+        if IS_SUPPORTED_SYSTEM && s.process(Pid::from(1)).is_some() {
+            assert!(init.is_some_and(|init| !init.is_empty()));
+        } else {
+            assert_eq!(init, None);
+        }
+    }
+
+    #[test]
+    fn check_balloon_memory() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+            assert_eq!(System::balloon_memory(), None);
+        } else if let Some(balloon_memory) = System::balloon_memory() {
+            let mut s = System::new();
+            s.refresh_memory();
+            assert!(balloon_memory <= s.total_memory());
+        }
+    }
+
+    #[test]
+    fn check_is_under_memory_pressure() {
+        let mut s = System::new();
+        // The memory wasn't refreshed, so only PSI can be used.
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+            assert!(!s.is_under_memory_pressure());
+        }
+        s.refresh_memory();
+        if IS_SUPPORTED_SYSTEM {
+            // Nothing can be below a ratio of 0 nor above 100% of the time.
+            assert!(!s.is_under_memory_pressure_with(100., 0.));
+            if SystemInner::memory_pressure_avg10().is_none() {
+                assert!(s.is_under_memory_pressure_with(100., 1.1));
+            }
+        }
+    }
+
+    #[test]
+    fn check_pid_max() {
+        let mut s = System::new();
+        assert_eq!(s.max_pid_in_use(), None);
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+            assert_eq!(System::pid_max(), None);
+            return;
+        }
+        s.refresh_processes(ProcessesToUpdate::All, false);
+        let max_pid_in_use = s.max_pid_in_use().expect("failed to get max PID in use");
+        assert!(max_pid_in_use >= get_current_pid().unwrap());
+        // The `pid_max` value itself is never used.
+        assert!(max_pid_in_use < System::pid_max().expect("failed to get PID max"));
+    }
+
+    #[test]
+    fn check_os_install_time() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", windows))) {
+            assert_eq!(System::os_install_time(), None);
+        } else if let Some(install_time) = System::os_install_time() {
+            assert!(install_time <= SystemTime::now());
+        }
+    }
+
+    #[test]
+    fn check_conntrack() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+            assert_eq!(System::conntrack_count(), None);
+            assert_eq!(System::conntrack_max(), None);
+        } else if let Some(max) = System::conntrack_max() {
+            // If the module is unloaded in between, we get `None`.
+            if let Some(count) = System::conntrack_count() {
+                assert!(count <= max);
+            }
+        }
+    }
+
+    #[test]
+    fn check_supports() {
+        let features = [
+            Feature::AvailableMemory,
+            Feature::LoadAverage,
+            Feature::ProcessCpuUsage,
+            Feature::ProcessDiskUsage,
+            Feature::ProcessEnvironment,
+            Feature::ProcessTasks,
+            Feature::CGroupLimits,
+            Feature::SwapRates,
+            Feature::PackagePower,
+            Feature::MemoryPressureStall,
+            Feature::ProcessDelays,
+            Feature::ProcessSchedulingLatency,
+            Feature::ProcessSyscallCounts,
+            Feature::ProcessUmask,
+            Feature::ProcessSignalInfo,
+        ];
+        if !IS_SUPPORTED_SYSTEM {
+            assert!(features.iter().all(|feature| !System::supports(*feature)));
+        } else if cfg!(any(target_os = "linux", target_os = "android")) {
+            assert!(System::supports(Feature::ProcessTasks));
+            assert!(System::supports(Feature::ProcessSignalInfo));
+            assert_eq!(
+                System::supports(Feature::ProcessDiskUsage),
+                std::path::Path::new("/proc/self/io").exists(),
+            );
+            assert_eq!(
+                System::supports(Feature::MemoryPressureStall),
+                std::path::Path::new("/proc/pressure/memory").exists(),
+            );
+
+            let mut s = System::new();
+            let pid = get_current_pid().expect("failed to get current pid");
+            s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+            let process = s.process(pid).expect("current process not found");
+            assert_eq!(
+                System::supports(Feature::ProcessDelays),
+                process.delays().is_some(),
+            );
+            assert_eq!(
+                System::supports(Feature::ProcessUmask),
+                process.umask().is_some(),
+            );
+        } else {
+            assert!(!System::supports(Feature::ProcessTasks));
+        }
+    }
+
+    #[test]
+    fn check_swap_rates() {
+        let mut s = System::new();
+        s.refresh_memory_and_swap_rates();
+        assert_eq!(s.swap_in_per_second(), 0.);
+        assert_eq!(s.swap_out_per_second(), 0.);
+        assert_eq!(s.major_page_faults_per_second(), 0.);
+        std::thread::sleep(Duration::from_millis(10));
+        s.refresh_memory_and_swap_rates();
+        assert!(s.swap_in_per_second() >= 0.);
+        assert!(s.swap_out_per_second() >= 0.);
+        assert!(s.major_page_faults_per_second() >= 0.);
+    }
+
+    #[test]
+    fn check_vm_setting() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+            assert_eq!(System::vm_setting("swappiness"), None);
+        } else if let Some(swappiness) = System::vm_setting("swappiness") {
+            assert!((0..=200).contains(&swappiness));
+        }
+        assert_eq!(System::vm_setting("../kernel/pid_max"), None);
+        assert_eq!(System::vm_setting(""), None);
+    }
+
+    #[test]
+    fn check_tcp_memory() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+            assert_eq!(System::tcp_memory(), None);
+        } else if let Some(tcp) = System::tcp_memory() {
+            assert!(tcp.memory_min <= tcp.memory_pressure);
+            assert!(tcp.memory_pressure <= tcp.memory_max);
+            assert!(tcp.memory_max > 0);
+        }
+    }
+
+    #[test]
+    fn check_page_size() {
+        let page_size = System::page_size();
+        if IS_SUPPORTED_SYSTEM {
+            assert!(
+                page_size.is_power_of_two(),
+                "invalid page size: {page_size}"
+            );
+        } else {
+            assert_eq!(page_size, 0);
+        }
+    }
+
+    #[test]
+    #[cfg(all(unix, not(feature = "unknown-ci")))]
+    fn check_is_elevated() {
+        assert_eq!(System::is_elevated(), unsafe { libc::geteuid() } == 0);
+    }
+
+    #[test]
+    fn check_process_ancestors() {
+        let s = System::new_all();
+        assert!(s.process_ancestors(Pid::from(usize::MAX / 2)).is_empty());
+
+        if IS_SUPPORTED_SYSTEM {
+            let pid = get_current_pid().expect("failed to get current pid");
+            let ancestors = s.process_ancestors(pid);
+            assert_eq!(
+                ancestors.first().copied(),
+                s.process(pid).and_then(Process::parent)
+            );
+            assert!(!ancestors.contains(&pid));
+            let unique = ancestors.iter().collect::<HashSet<_>>();
+            assert_eq!(unique.len(), ancestors.len());
+        }
+    }
+
+    #[test]
+    fn check_top_processes() {
+        let s = System::new_all();
+        assert!(s.top_processes_by_memory(0).is_empty());
+        assert!(s.top_processes_by_cpu(0).is_empty());
+
+        let top = s.top_processes_by_memory(3);
+        assert_eq!(top.len(), s.processes().len().min(3));
+        assert!(top.windows(2).all(|w| w[0].memory() >= w[1].memory()));
+        if let Some(first) = top.first() {
+            assert_eq!(
+                Some(first.memory()),
+                s.processes().values().map(|p| p.memory()).max()
+            );
+        }
+        let top = s.top_processes_by_cpu(3);
+        assert_eq!(top.len(), s.processes().len().min(3));
+        assert!(top.windows(2).all(|w| w[0].cpu_usage() >= w[1].cpu_usage()));
+
+        // Asking for more processes than there are returns all of them.
+        assert_eq!(
+            s.top_processes_by_memory(usize::MAX / 2).len(),
+            s.processes().len()
+        );
+    }
+
+    #[test]
+    fn check_available_entropy() {
+        if IS_SUPPORTED_SYSTEM && cfg!(target_os = "linux") {
+            assert!(System::available_entropy().is_some());
+        } else {
+            assert_eq!(System::available_entropy(), None);
+        }
+    }
+
+    #[test]
+    fn check_smt() {
+        if IS_SUPPORTED_SYSTEM {
+            let threads_per_core = System::threads_per_core();
+            if let Some(threads_per_core) = threads_per_core {
+                assert!(threads_per_core > 0);
+            }
+            if let (Some(smt_enabled), Some(threads_per_core)) =
+                (System::smt_enabled(), threads_per_core)
+            {
+                // SMT could be supported but disabled.
+                assert!(!smt_enabled || threads_per_core > 1);
+            }
+        } else {
+            assert_eq!(System::smt_enabled(), None);
+            assert_eq!(System::threads_per_core(), None);
+        }
+    }
+
+    #[test]
+    fn check_cpus_counts() {
+        if IS_SUPPORTED_SYSTEM && cfg!(any(target_os = "linux", target_os = "android")) {
+            let possible = System::cpus_possible();
+            let present = System::cpus_present();
+            let online = System::cpus_online();
+            if let (Some(possible), Some(present)) = (possible, present) {
+                assert!(present <= possible);
+            }
+            if let (Some(present), Some(online)) = (present, online) {
+                assert!(online <= present);
+            }
+        } else {
+            assert_eq!(System::cpus_possible(), None);
+            assert_eq!(System::cpus_present(), None);
+            assert_eq!(System::cpus_online(), None);
+        }
+    }
+
+    #[test]
+    fn check_kernel_modules() {
+        let modules = System::kernel_modules();
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "windows"))) {
+            assert!(modules.is_empty());
+        }
+        assert!(modules.iter().all(|module| !module.name.is_empty()));
+    }
+
+    #[test]
+    fn check_timezone_and_locale() {
+        if !IS_SUPPORTED_SYSTEM {
+            assert!(System::timezone().is_none());
+            assert!(System::locale().is_none());
+        } else {
+            assert_ne!(System::timezone().as_deref(), Some(""));
+            assert_ne!(System::locale().as_deref(), Some(""));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn check_process_from_pidfd() {
+        use std::os::unix::io::AsRawFd;
+
+        let file = std::fs::File::open("Cargo.toml").unwrap();
+        assert_eq!(System::process_from_pidfd(file.as_raw_fd()), None);
+        assert_eq!(System::process_from_pidfd(-1), None);
+
+        #[cfg(all(target_os = "linux", not(feature = "unknown-ci")))]
+        unsafe {
+            let fd = libc::syscall(libc::SYS_pidfd_open, libc::getpid(), 0) as libc::c_int;
+            // `pidfd_open` requires Linux 5.3.
+            if fd >= 0 {
+                let pid = System::process_from_pidfd(fd);
+                libc::close(fd);
+                assert_eq!(pid, get_current_pid().ok());
+            }
+        }
+    }
+
+    #[test]
+    fn check_measure_cpu_usage() {
+        let report = System::measure_cpu_usage(Duration::ZERO);
+        if IS_SUPPORTED_SYSTEM {
+            let s = System::new_with_specifics(
+                RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing()),
+            );
+            assert_eq!(report.per_cpu.len(), s.cpus().len());
+        } else {
+            assert!(report.per_cpu.is_empty());
+        }
+        assert!(report.global >= 0.);
+        assert!(report.per_cpu.iter().all(|usage| *usage >= 0.));
+    }
+
+    #[test]
+    fn check_package_power() {
+        let mut s = System::new();
+        // The energy counters are only read if asked.
+        s.refresh_cpu_usage();
+        std::thread::sleep(Duration::from_millis(100));
+        s.refresh_cpu_usage();
+        assert_eq!(s.package_power(), None);
+        // Two samples are needed.
+        let refresh_kind = CpuRefreshKind::nothing().with_energy();
+        s.refresh_cpu_specifics(refresh_kind);
+        assert_eq!(s.package_power(), None);
+        std::thread::sleep(Duration::from_millis(100));
+        s.refresh_cpu_specifics(refresh_kind);
+        if let Some(power) = s.package_power() {
+            assert!(power >= 0.);
+        }
+    }
+
+    #[test]
+    fn check_cpu_current_max_frequency() {
+        let s =
+            System::new_with_specifics(RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing()));
+        for cpu in s.cpus() {
+            let max_frequency = cpu.current_max_frequency();
+            if !IS_SUPPORTED_SYSTEM || cfg!(not(target_os = "linux")) {
+                assert!(max_frequency.is_none());
+            } else if let Some(max_frequency) = max_frequency {
+                assert!(max_frequency > 0);
+            }
+        }
+    }
+
+    #[test]
+    fn check_cpu_idle_states() {
+        let s =
+            System::new_with_specifics(RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing()));
+        for cpu in s.cpus() {
+            let states = cpu.idle_states();
+            if !IS_SUPPORTED_SYSTEM || cfg!(not(target_os = "linux")) {
+                assert!(states.is_none());
+            } else if let Some(states) = states {
+                assert!(!states.is_empty());
+                assert!(states.iter().all(|state| !state.name.is_empty()));
+            }
+        }
+    }
+
+    #[test]
+    fn check_memory_errors() {
+        let errors = System::memory_errors();
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(target_os = "linux")) {
+            assert!(errors.is_none());
+        } else if let Some(errors) = errors {
+            assert!(!errors.controllers.is_empty());
+        }
+    }
+
+    #[test]
+    fn check_interrupts_per_cpu() {
+        let stats = System::interrupts_per_cpu();
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(target_os = "linux")) {
+            assert!(stats.is_none());
+        } else if let Some(stats) = stats {
+            assert!(!stats.cpus.is_empty());
+            assert!(stats
+                .interrupts
+                .iter()
+                .all(|interrupt| interrupt.counts.len() <= stats.cpus.len()));
+            assert_eq!(stats.total_per_cpu().len(), stats.cpus.len());
+        }
+    }
+
+    #[test]
+    fn check_secure_boot_enabled() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "windows"))) {
+            assert_eq!(System::secure_boot_enabled(), None);
+        } else if cfg!(target_os = "linux") && !std::path::Path::new("/sys/firmware/efi").exists() {
+            // Not booted with UEFI.
+            assert_eq!(System::secure_boot_enabled(), None);
+        }
+    }
+
     // This test only exists to ensure that the `Display` and `Debug` traits are implemented on the
     // `ProcessStatus` enum on all targets.
     #[test]
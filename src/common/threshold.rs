@@ -0,0 +1,197 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// Tracks whether a metric crossed a threshold, with hysteresis.
+///
+/// The threshold is triggered when the value goes up to the `high` watermark and only cleared
+/// once it goes back down to the `low` watermark, so a value oscillating around a single limit
+/// doesn't keep changing the state.
+///
+#[cfg_attr(not(feature = "system"), doc = "```ignore")]
+/// ```no_run
+/// use sysinfo::{System, Threshold};
+///
+/// let mut s = System::new();
+/// // Triggered above 90% of used memory, cleared below 80%.
+/// let mut threshold = Threshold::new(0.9, 0.8);
+///
+/// loop {
+///     s.refresh_memory();
+///     threshold.update(s.used_memory() as f64 / s.total_memory() as f64);
+///     if threshold.state_changed() {
+///         if threshold.is_triggered() {
+///             println!("memory usage is high");
+///         } else {
+///             println!("memory usage is back to normal");
+///         }
+///     }
+///     std::thread::sleep(std::time::Duration::from_secs(1));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold {
+    high: f64,
+    low: f64,
+    triggered: bool,
+    state_changed: bool,
+}
+
+impl Threshold {
+    /// Creates a new, not triggered, [`Threshold`].
+    ///
+    /// It is triggered when the value is greater than or equal to `high` and cleared when it is
+    /// less than or equal to `low`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is greater than `high` or if one of them is NaN.
+    ///
+    /// ```
+    /// use sysinfo::Threshold;
+    ///
+    /// let threshold = Threshold::new(0.9, 0.8);
+    /// assert!(!threshold.is_triggered());
+    /// ```
+    pub fn new(high: f64, low: f64) -> Self {
+        assert!(
+            low <= high,
+            "the low watermark ({low}) must not be greater than the high one ({high})"
+        );
+        Self {
+            high,
+            low,
+            triggered: false,
+            state_changed: false,
+        }
+    }
+
+    /// Returns the value at which the threshold is triggered.
+    ///
+    /// ```
+    /// use sysinfo::Threshold;
+    ///
+    /// let threshold = Threshold::new(0.9, 0.8);
+    /// assert_eq!(threshold.high(), 0.9);
+    /// ```
+    pub fn high(&self) -> f64 {
+        self.high
+    }
+
+    /// Returns the value at which the threshold is cleared.
+    ///
+    /// ```
+    /// use sysinfo::Threshold;
+    ///
+    /// let threshold = Threshold::new(0.9, 0.8);
+    /// assert_eq!(threshold.low(), 0.8);
+    /// ```
+    pub fn low(&self) -> f64 {
+        self.low
+    }
+
+    /// Updates the threshold with a new value of the metric and returns `true` if its state
+    /// changed.
+    ///
+    /// NaN values are ignored.
+    ///
+    /// ```
+    /// use sysinfo::Threshold;
+    ///
+    /// let mut threshold = Threshold::new(0.9, 0.8);
+    /// assert!(threshold.update(0.95));
+    /// // Still above the low watermark.
+    /// assert!(!threshold.update(0.85));
+    /// assert!(threshold.update(0.8));
+    /// ```
+    pub fn update(&mut self, value: f64) -> bool {
+        self.state_changed = if self.triggered {
+            value <= self.low
+        } else {
+            value >= self.high
+        };
+        if self.state_changed {
+            self.triggered = !self.triggered;
+        }
+        self.state_changed
+    }
+
+    /// Returns `true` if the last call to [`Threshold::update`] changed the state.
+    ///
+    /// ```
+    /// use sysinfo::Threshold;
+    ///
+    /// let mut threshold = Threshold::new(0.9, 0.8);
+    /// threshold.update(0.95);
+    /// assert!(threshold.state_changed());
+    /// threshold.update(0.95);
+    /// assert!(!threshold.state_changed());
+    /// ```
+    pub fn state_changed(&self) -> bool {
+        self.state_changed
+    }
+
+    /// Returns `true` if the high watermark was reached and the low one wasn't reached since.
+    ///
+    /// ```
+    /// use sysinfo::Threshold;
+    ///
+    /// let mut threshold = Threshold::new(0.9, 0.8);
+    /// threshold.update(0.95);
+    /// assert!(threshold.is_triggered());
+    /// ```
+    pub fn is_triggered(&self) -> bool {
+        self.triggered
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Threshold;
+
+    #[test]
+    fn check_threshold_hysteresis() {
+        let mut threshold = Threshold::new(90., 80.);
+
+        let changes = [50., 89.9, 90., 95., 85., 89.9, 80.1, 80., 85., 90.]
+            .into_iter()
+            .map(|value| (threshold.update(value), threshold.is_triggered()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            [
+                (false, false),
+                (false, false),
+                (true, true),
+                (false, true),
+                (false, true),
+                (false, true),
+                (false, true),
+                (true, false),
+                (false, false),
+                (true, true),
+            ]
+        );
+
+        // NaN doesn't change anything.
+        assert!(!threshold.update(f64::NAN));
+        assert!(threshold.is_triggered());
+    }
+
+    #[test]
+    fn check_threshold_single_watermark() {
+        // Without hysteresis, every crossing changes the state.
+        let mut threshold = Threshold::new(1., 1.);
+
+        assert!(threshold.update(1.));
+        assert!(threshold.update(1.));
+        assert!(!threshold.update(0.));
+        assert!(threshold.update(2.));
+        assert!(!threshold.update(1.5));
+        assert!(threshold.update(0.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_threshold_invalid_watermarks() {
+        Threshold::new(1., 2.);
+    }
+}
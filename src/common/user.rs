@@ -1,6 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 use crate::{Gid, Uid, UserInner};
 
@@ -191,6 +193,7 @@ impl Group {
 /// ```
 pub struct Users {
     users: Vec<User>,
+    names: Mutex<HashMap<Uid, Option<String>>>,
 }
 
 impl Default for Users {
@@ -207,7 +210,10 @@ impl From<Users> for Vec<User> {
 
 impl From<Vec<User>> for Users {
     fn from(users: Vec<User>) -> Self {
-        Self { users }
+        Self {
+            users,
+            names: Mutex::new(HashMap::new()),
+        }
     }
 }
 
@@ -258,7 +264,7 @@ impl Users {
     /// }
     /// ```
     pub fn new() -> Self {
-        Self { users: Vec::new() }
+        Self::from(Vec::new())
     }
 
     /// Creates a new [`Users`][crate::Users] type with the user list loaded.
@@ -307,6 +313,8 @@ impl Users {
 
     /// The user list will be emptied then completely recomputed.
     ///
+    /// It also clears the cache used by [`Users::get_user_name_by_id`].
+    ///
     /// ```no_run
     /// use sysinfo::Users;
     ///
@@ -315,6 +323,14 @@ impl Users {
     /// ```
     pub fn refresh(&mut self) {
         crate::sys::get_users(&mut self.users);
+        self.clear_names_cache();
+    }
+
+    fn clear_names_cache(&mut self) {
+        match self.names.get_mut() {
+            Ok(names) => names.clear(),
+            Err(error) => error.into_inner().clear(),
+        }
     }
 
     /// Returns the [`User`] matching the given `user_id`.
@@ -348,6 +364,48 @@ impl Users {
     pub fn get_user_by_id(&self, user_id: &Uid) -> Option<&User> {
         self.users.iter().find(|user| user.id() == user_id)
     }
+
+    /// Returns the name of the user matching the given `user_id`.
+    ///
+    /// The user is first looked up in the user list. If it's not in it (like when the list
+    /// wasn't filled or for users coming from a network directory), the system is asked
+    /// directly (through NSS on Unix systems). Results, including the users which couldn't be
+    /// found, are cached so resolving the same `user_id` again is cheap.
+    ///
+    /// **Important**: the cache is only cleared when [`Users::refresh`] is called, so a user
+    /// created, renamed or removed since the first lookup won't be reflected until then.
+    ///
+    /// ⚠️ Users which aren't in the user list are never found on platforms not supported by
+    /// this crate.
+    ///
+    #[cfg_attr(feature = "system", doc = "```no_run")]
+    #[cfg_attr(not(feature = "system"), doc = "```ignore")]
+    /// use sysinfo::{System, Users};
+    ///
+    /// let s = System::new_all();
+    /// let users = Users::new_with_refreshed_list();
+    ///
+    /// for process in s.processes().values() {
+    ///     if let Some(user_id) = process.user_id() {
+    ///         println!("{:?}: {:?}", process.name(), users.get_user_name_by_id(user_id));
+    ///     }
+    /// }
+    /// ```
+    pub fn get_user_name_by_id(&self, user_id: &Uid) -> Option<String> {
+        let mut names = match self.names.lock() {
+            Ok(names) => names,
+            Err(error) => error.into_inner(),
+        };
+        if let Some(name) = names.get(user_id) {
+            return name.clone();
+        }
+        let name = match self.get_user_by_id(user_id) {
+            Some(user) => Some(user.name().to_owned()),
+            None => crate::sys::get_user_name(user_id),
+        };
+        names.insert(user_id.clone(), name.clone());
+        name
+    }
 }
 
 /// Interacting with groups.
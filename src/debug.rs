@@ -107,6 +107,25 @@ impl std::fmt::Debug for crate::Component {
     }
 }
 
+#[cfg(feature = "gpu")]
+impl std::fmt::Debug for crate::Gpus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "gpu")]
+impl std::fmt::Debug for crate::Gpu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Gpu")
+            .field("name", &self.name())
+            .field("VRAM total", &self.vram_total())
+            .field("VRAM used", &self.vram_used())
+            .field("utilization", &self.utilization())
+            .finish()
+    }
+}
+
 #[cfg(feature = "network")]
 impl std::fmt::Debug for crate::Networks {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -130,7 +149,26 @@ impl std::fmt::Debug for crate::NetworkData {
             .field("total errors income", &self.total_errors_on_received())
             .field("errors outcome", &self.errors_on_transmitted())
             .field("total errors outcome", &self.total_errors_on_transmitted())
+            .field(
+                "dropped packets income",
+                &self.packets_dropped_on_received(),
+            )
+            .field(
+                "total dropped packets income",
+                &self.total_packets_dropped_on_received(),
+            )
+            .field(
+                "dropped packets outcome",
+                &self.packets_dropped_on_transmitted(),
+            )
+            .field(
+                "total dropped packets outcome",
+                &self.total_packets_dropped_on_transmitted(),
+            )
             .field("maximum transfer unit", &self.mtu())
+            .field("maximum supported transfer unit", &self.max_mtu())
+            .field("duplex", &self.duplex())
+            .field("autonegotiation", &self.autonegotiation())
             .finish()
     }
 }
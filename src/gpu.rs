@@ -0,0 +1,57 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Gpu;
+
+pub(crate) struct GpuInner {
+    pub(crate) updated: bool,
+}
+
+impl GpuInner {
+    pub(crate) fn name(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn vram_total(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn vram_used(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn utilization(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
+
+pub(crate) struct GpusInner {
+    pub(crate) gpus: Vec<Gpu>,
+}
+
+impl GpusInner {
+    pub(crate) fn new() -> Self {
+        Self { gpus: Vec::new() }
+    }
+
+    pub(crate) fn from_vec(gpus: Vec<Gpu>) -> Self {
+        Self { gpus }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Gpu> {
+        self.gpus
+    }
+
+    pub(crate) fn list(&self) -> &[Gpu] {
+        &self.gpus
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Gpu] {
+        &mut self.gpus
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // Doesn't do anything.
+    }
+}
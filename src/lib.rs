@@ -71,16 +71,22 @@ cfg_if! {
 #[cfg(feature = "component")]
 pub use crate::common::component::{Component, Components};
 #[cfg(feature = "disk")]
-pub use crate::common::disk::{Disk, DiskKind, DiskRefreshKind, Disks};
+pub use crate::common::disk::{Disk, DiskKind, DiskRefreshKind, Disks, MountPropagation};
+#[cfg(feature = "gpu")]
+pub use crate::common::gpu::{Gpu, Gpus};
 #[cfg(feature = "network")]
 pub use crate::common::network::{
-    IpNetwork, IpNetworkFromStrError, MacAddr, MacAddrFromStrError, NetworkData, Networks,
+    Duplex, IpNetwork, IpNetworkFromStrError, MacAddr, MacAddrFromStrError, NetworkCounters,
+    NetworkData, Networks, WolConfig, WolMode,
 };
 #[cfg(feature = "system")]
 pub use crate::common::system::{
-    get_current_pid, CGroupLimits, Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process,
-    ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, RefreshKind, Signal, System, ThreadKind,
-    UpdateKind,
+    current_memory_usage, get_current_pid, CGroupLimits, Cpu, CpuCounters, CpuRefreshKind,
+    CpuUsageReport, FdBreakdown, Feature, GpuUsage, IdleState, IntegrityLevel, Interrupt,
+    InterruptStats, KernelModule, LoadAvg, MemoryControllerErrors, MemoryErrors, MemoryRefreshKind,
+    NetworkUsage, Pid, Process, ProcessCounters, ProcessCpuCounters, ProcessEvent,
+    ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, RefreshKind, Signal, SignalInfo, System,
+    TaskDelays, TcpMemoryStats, ThpMode, ThreadKind, UpdateKind,
 };
 #[cfg(feature = "user")]
 pub use crate::common::user::{Group, Groups, User, Users};
@@ -92,8 +98,27 @@ pub use crate::sys::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
 #[cfg(any(feature = "system", feature = "disk"))]
 pub use crate::common::DiskUsage;
 
+pub use crate::common::error::SysinfoError;
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    feature = "linux-proc-events",
+    not(feature = "unknown-ci")
+))]
+pub use crate::common::process_events::{ProcessEventListener, ProcessLifecycleEvent};
+#[cfg(feature = "system")]
+pub use crate::common::process_watcher::ProcessWatcher;
+#[cfg(all(windows, feature = "windows-services", not(feature = "unknown-ci")))]
+pub use crate::common::service::{Service, ServiceStartType, ServiceState, Services};
+pub use crate::common::threshold::Threshold;
+
 #[cfg(feature = "user")]
 pub(crate) use crate::common::user::GroupInner;
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    feature = "linux-proc-events",
+    not(feature = "unknown-ci")
+))]
+pub(crate) use crate::sys::ProcessEventListenerInner;
 #[cfg(feature = "user")]
 pub(crate) use crate::sys::UserInner;
 #[cfg(feature = "component")]
@@ -102,8 +127,12 @@ pub(crate) use crate::sys::{ComponentInner, ComponentsInner};
 pub(crate) use crate::sys::{CpuInner, ProcessInner, SystemInner};
 #[cfg(feature = "disk")]
 pub(crate) use crate::sys::{DiskInner, DisksInner};
+#[cfg(feature = "gpu")]
+pub(crate) use crate::sys::{GpuInner, GpusInner};
 #[cfg(feature = "network")]
 pub(crate) use crate::sys::{NetworkDataInner, NetworksInner};
+#[cfg(all(windows, feature = "windows-services", not(feature = "unknown-ci")))]
+pub(crate) use crate::sys::{ServiceInner, ServicesInner};
 
 pub use crate::sys::IS_SUPPORTED_SYSTEM;
 
@@ -118,6 +147,16 @@ mod debug;
 mod serde;
 pub(crate) mod utils;
 
+// The GPU information is only available on Linux, the other systems use this no-op backend.
+#[cfg(all(
+    feature = "gpu",
+    any(
+        feature = "unknown-ci",
+        not(any(target_os = "linux", target_os = "android"))
+    )
+))]
+mod gpu;
+
 // Make formattable by rustfmt.
 #[cfg(any())]
 mod network;
@@ -182,6 +221,51 @@ pub fn set_open_files_limit(mut _new_limit: isize) -> bool {
     }
 }
 
+/// Runs `f` while making the Linux backend read the content of the files from `files` (with
+/// the file path as key) instead of the filesystem.
+///
+/// It allows to run `sysinfo` against data captured beforehand (to write tests for example):
+/// files which aren't in `files` are considered as missing. The minimum interval between two
+/// CPUs usage refreshes ([`MINIMUM_CPU_UPDATE_INTERVAL`]) is ignored while replaying.
+///
+/// Only the CPUs (`/proc/stat`, `/proc/cpuinfo`, etc), memory (`/proc/meminfo`, etc) and
+/// network interfaces statistics (`/sys/class/net`) information is supported. The other
+/// information (like the processes or the network addresses) might be partially read from the
+/// system, so it shouldn't be relied upon while replaying.
+///
+/// While `f` is running, the replayed files are used by all threads and other calls to
+/// `replay` are blocked until it returns, so `replay` must not be called from `f`.
+///
+/// ⚠️ This function is only available on Linux with the `linux-replay` feature.
+///
+#[cfg_attr(feature = "system", doc = "```no_run")]
+#[cfg_attr(not(feature = "system"), doc = "```ignore")]
+/// use sysinfo::System;
+/// use std::collections::HashMap;
+/// use std::path::PathBuf;
+///
+/// let stat = |idle| format!("cpu  10 0 10 {idle} 0 0 0 0 0 0\ncpu0 10 0 10 {idle} 0 0 0 0 0 0\n");
+/// let mut s = System::new();
+/// for idle in [100, 120] {
+///     let files = HashMap::from([(PathBuf::from("/proc/stat"), stat(idle))]);
+///     sysinfo::replay(files, || s.refresh_cpu_usage());
+/// }
+/// println!("{}%", s.global_cpu_usage());
+/// ```
+///
+/// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+#[cfg(all(
+    feature = "linux-replay",
+    any(target_os = "linux", target_os = "android"),
+    not(feature = "unknown-ci")
+))]
+pub fn replay<R>(
+    files: std::collections::HashMap<std::path::PathBuf, String>,
+    f: impl FnOnce() -> R,
+) -> R {
+    crate::sys::replay::replay(files, f)
+}
+
 #[cfg(doctest)]
 mod doctest {
     macro_rules! compile_fail_import {
@@ -197,21 +281,43 @@ use sysinfo::", stringify!($imports), r";
     #[cfg(not(feature = "system"))]
     compile_fail_import!(
         no_system_feature =>
+        current_memory_usage,
         get_current_pid,
         CGroupLimits,
         Cpu,
+        CpuCounters,
         CpuRefreshKind,
+        CpuUsageReport,
         DiskUsage,
+        FdBreakdown,
+        Feature,
+        GpuUsage,
+        IdleState,
+        IntegrityLevel,
+        Interrupt,
+        InterruptStats,
+        KernelModule,
         LoadAvg,
+        MemoryControllerErrors,
+        MemoryErrors,
         MemoryRefreshKind,
+        NetworkUsage,
         Pid,
         Process,
+        ProcessCounters,
+        ProcessCpuCounters,
+        ProcessEvent,
         ProcessesToUpdate,
         ProcessRefreshKind,
+        ProcessWatcher,
         ProcessStatus,
         RefreshKind,
         Signal,
+        SignalInfo,
         System,
+        TaskDelays,
+        TcpMemoryStats,
+        ThpMode,
         ThreadKind,
         UpdateKind,
     );
@@ -222,6 +328,7 @@ use sysinfo::", stringify!($imports), r";
         Disk,
         Disks,
         DiskKind,
+        MountPropagation,
     );
 
     #[cfg(not(feature = "component"))]
@@ -231,13 +338,24 @@ use sysinfo::", stringify!($imports), r";
         Components,
     );
 
+    #[cfg(not(feature = "gpu"))]
+    compile_fail_import!(
+        no_gpu_feature =>
+        Gpu,
+        Gpus,
+    );
+
     #[cfg(not(feature = "network"))]
     compile_fail_import!(
         no_network_feature =>
+        Duplex,
         IpNetwork,
         MacAddr,
+        NetworkCounters,
         NetworkData,
         Networks,
+        WolConfig,
+        WolMode,
     );
 
     #[cfg(not(feature = "user"))]
@@ -313,13 +431,35 @@ mod test {
                     ));
                 assert!(s
                     .processes()
-                    .iter()
-                    .filter_map(|(_, p)| p.user_id())
+                    .values()
+                    .filter_map(|p| p.user_id())
                     .any(|uid| users.get_user_by_id(uid).is_some()));
             }
         }
     }
 
+    #[cfg(feature = "user")]
+    #[test]
+    fn check_user_name_cache() {
+        let mut users = Users::new_with_refreshed_list();
+        if let Some(user) = users.first() {
+            let (uid, name) = (user.id().clone(), Some(user.name().to_owned()));
+            assert_eq!(users.get_user_name_by_id(&uid), name);
+            // This time, the value comes from the cache.
+            assert_eq!(users.get_user_name_by_id(&uid), name);
+            users.refresh();
+            assert_eq!(users.get_user_name_by_id(&uid), name);
+        }
+        #[cfg(not(target_os = "windows"))]
+        if IS_SUPPORTED_SYSTEM {
+            // The root user can be resolved even if it's not in the user list.
+            assert_eq!(
+                Users::new().get_user_name_by_id(&crate::Uid(0)).as_deref(),
+                Some("root")
+            );
+        }
+    }
+
     #[cfg(all(feature = "system", feature = "user"))]
     #[test]
     fn check_all_process_uids_resolvable() {
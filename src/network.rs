@@ -1,16 +1,27 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::network_helper::{get_interface_address, get_interface_ip_networks};
-use crate::NetworkData;
+use crate::{IpNetwork, NetworkData};
 
 /// Interface addresses are OS-independent
 pub(crate) fn refresh_networks_addresses(interfaces: &mut HashMap<String, NetworkData>) {
-    let interface_networks = unsafe { get_interface_ip_networks() };
-    for (interface_name, ip_networks) in interface_networks {
-        if let Some(interface) = interfaces.get_mut(&interface_name) {
-            interface.inner.ip_networks = ip_networks.into_iter().collect::<Vec<_>>();
+    match unsafe { get_interface_ip_networks() } {
+        Some(mut interface_networks) => {
+            for (interface_name, interface) in interfaces.iter_mut() {
+                // An interface which isn't listed doesn't have any address anymore.
+                let ip_networks = interface_networks
+                    .remove(interface_name)
+                    .unwrap_or_default();
+                interface.inner.addresses_changed =
+                    update_ip_networks(&mut interface.inner.ip_networks, ip_networks);
+            }
+        }
+        None => {
+            for interface in interfaces.values_mut() {
+                interface.inner.addresses_changed = false;
+            }
         }
     }
     match unsafe { get_interface_address() } {
@@ -26,3 +37,62 @@ pub(crate) fn refresh_networks_addresses(interfaces: &mut HashMap<String, Networ
         }
     }
 }
+
+// Replaces `ip_networks` with `new_ip_networks` and returns `true` if they're different.
+fn update_ip_networks(
+    ip_networks: &mut Vec<IpNetwork>,
+    new_ip_networks: HashSet<IpNetwork>,
+) -> bool {
+    // The order of the addresses isn't relevant.
+    let changed = ip_networks.len() != new_ip_networks.len()
+        || ip_networks.iter().any(|net| !new_ip_networks.contains(net));
+    *ip_networks = new_ip_networks.into_iter().collect();
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::update_ip_networks;
+    use crate::IpNetwork;
+
+    use std::collections::HashSet;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn test_update_ip_networks() {
+        let v4 = IpNetwork {
+            addr: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 12)),
+            prefix: 24,
+        };
+        let v6 = IpNetwork {
+            addr: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            prefix: 128,
+        };
+        let mut ip_networks = Vec::new();
+
+        // No address before nor after.
+        assert!(!update_ip_networks(&mut ip_networks, HashSet::new()));
+        // A new address (DHCP lease for example).
+        assert!(update_ip_networks(&mut ip_networks, HashSet::from([v4])));
+        assert_eq!(ip_networks, [v4]);
+        // The order doesn't matter.
+        assert!(update_ip_networks(
+            &mut ip_networks,
+            HashSet::from([v4, v6])
+        ));
+        ip_networks.reverse();
+        assert!(!update_ip_networks(
+            &mut ip_networks,
+            HashSet::from([v4, v6])
+        ));
+        // One of the addresses is replaced.
+        let other = IpNetwork { prefix: 16, ..v4 };
+        assert!(update_ip_networks(
+            &mut ip_networks,
+            HashSet::from([other, v6])
+        ));
+        // All the addresses are gone (VPN disconnection for example).
+        assert!(update_ip_networks(&mut ip_networks, HashSet::new()));
+        assert!(ip_networks.is_empty());
+    }
+}
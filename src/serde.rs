@@ -3,6 +3,7 @@
 #[cfg(any(
     feature = "component",
     feature = "disk",
+    feature = "gpu",
     feature = "network",
     feature = "system",
     feature = "user"
@@ -62,6 +63,23 @@ impl Serialize for crate::DiskKind {
     }
 }
 
+#[cfg(feature = "disk")]
+impl Serialize for crate::MountPropagation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Shared => (0, "Shared"),
+            Self::Slave => (1, "Slave"),
+            Self::Private => (2, "Private"),
+            Self::Unbindable => (3, "Unbindable"),
+        };
+
+        serializer.serialize_unit_variant("MountPropagation", index, variant)
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::Pid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -176,6 +194,25 @@ impl Serialize for crate::CGroupLimits {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::IntegrityLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Untrusted => (0, "Untrusted"),
+            Self::Low => (1, "Low"),
+            Self::Medium => (2, "Medium"),
+            Self::High => (3, "High"),
+            Self::System => (4, "System"),
+            Self::Protected => (5, "Protected"),
+        };
+
+        serializer.serialize_unit_variant("IntegrityLevel", index, variant)
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::ThreadKind {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -282,6 +319,301 @@ impl Serialize for crate::ProcessStatus {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::KernelModule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("KernelModule", 3)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("size", &self.size)?;
+        state.serialize_field("use_count", &self.use_count)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::CpuUsageReport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuUsageReport", 2)?;
+
+        state.serialize_field("global", &self.global)?;
+        state.serialize_field("per_cpu", &self.per_cpu)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::InterruptStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("InterruptStats", 2)?;
+
+        state.serialize_field("cpus", &self.cpus)?;
+        state.serialize_field("interrupts", &self.interrupts)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::Interrupt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Interrupt", 3)?;
+
+        state.serialize_field("irq", &self.irq)?;
+        state.serialize_field("counts", &self.counts)?;
+        state.serialize_field("description", &self.description)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ProcessCounters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessCounters", 3)?;
+
+        state.serialize_field("start_time", &self.start_time)?;
+        state.serialize_field("disk_usage", &self.disk_usage)?;
+        state.serialize_field("cpu", &self.cpu)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ProcessCpuCounters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessCpuCounters", 4)?;
+
+        state.serialize_field("user_time", &self.user_time)?;
+        state.serialize_field("system_time", &self.system_time)?;
+        state.serialize_field("global_user_time", &self.global_user_time)?;
+        state.serialize_field("global_system_time", &self.global_system_time)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::CpuCounters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuCounters", 2)?;
+
+        state.serialize_field("global", &self.global)?;
+        state.serialize_field("cpus", &self.cpus)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::IdleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("IdleState", 3)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("residency", &self.residency)?;
+        state.serialize_field("entries", &self.entries)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::MemoryErrors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `1` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MemoryErrors", 1)?;
+
+        state.serialize_field("controllers", &self.controllers)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::MemoryControllerErrors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MemoryControllerErrors", 3)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("corrected", &self.corrected)?;
+        state.serialize_field("uncorrected", &self.uncorrected)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::FdBreakdown {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("FdBreakdown", 5)?;
+
+        state.serialize_field("files", &self.files)?;
+        state.serialize_field("sockets", &self.sockets)?;
+        state.serialize_field("pipes", &self.pipes)?;
+        state.serialize_field("anon_inodes", &self.anon_inodes)?;
+        state.serialize_field("others", &self.others)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ThpMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Always => (0, "Always"),
+            Self::Madvise => (1, "Madvise"),
+            Self::Never => (2, "Never"),
+        };
+
+        serializer.serialize_unit_variant("ThpMode", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::TcpMemoryStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `8` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("TcpMemoryStats", 8)?;
+
+        state.serialize_field("sockets_used", &self.sockets_used)?;
+        state.serialize_field("tcp_in_use", &self.tcp_in_use)?;
+        state.serialize_field("orphans", &self.orphans)?;
+        state.serialize_field("time_wait", &self.time_wait)?;
+        state.serialize_field("memory", &self.memory)?;
+        state.serialize_field("memory_min", &self.memory_min)?;
+        state.serialize_field("memory_pressure", &self.memory_pressure)?;
+        state.serialize_field("memory_max", &self.memory_max)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::TaskDelays {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("TaskDelays", 6)?;
+
+        state.serialize_field("cpu", &self.cpu)?;
+        state.serialize_field("block_io", &self.block_io)?;
+        state.serialize_field("swap_in", &self.swap_in)?;
+        state.serialize_field("memory_reclaim", &self.memory_reclaim)?;
+        state.serialize_field("thrashing", &self.thrashing)?;
+        state.serialize_field("compaction", &self.compaction)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::SignalInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SignalInfo", 4)?;
+
+        state.serialize_field("blocked", &self.blocked)?;
+        state.serialize_field("pending", &self.pending)?;
+        state.serialize_field("ignored", &self.ignored)?;
+        state.serialize_field("caught", &self.caught)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::GpuUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("GpuUsage", 2)?;
+
+        state.serialize_field("memory_vram", &self.memory_vram)?;
+        state.serialize_field("engines", &self.engines)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::NetworkUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkUsage", 4)?;
+
+        state.serialize_field("total_received_bytes", &self.total_received_bytes)?;
+        state.serialize_field("received_bytes", &self.received_bytes)?;
+        state.serialize_field("total_transmitted_bytes", &self.total_transmitted_bytes)?;
+        state.serialize_field("transmitted_bytes", &self.transmitted_bytes)?;
+
+        state.end()
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::DiskUsage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -316,13 +648,42 @@ impl Serialize for crate::Component {
     where
         S: Serializer,
     {
-        // `4` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Component", 4)?;
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Component", 5)?;
 
         state.serialize_field("temperature", &self.temperature())?;
         state.serialize_field("max", &self.max())?;
         state.serialize_field("critical", &self.critical())?;
         state.serialize_field("label", &self.label())?;
+        state.serialize_field("id", &self.id())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "gpu")]
+impl Serialize for crate::Gpus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "gpu")]
+impl Serialize for crate::Gpu {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Gpu", 4)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("vram_total", &self.vram_total())?;
+        state.serialize_field("vram_used", &self.vram_used())?;
+        state.serialize_field("utilization", &self.utilization())?;
 
         state.end()
     }
@@ -344,8 +705,8 @@ impl Serialize for crate::NetworkData {
     where
         S: Serializer,
     {
-        // `14` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("NetworkData", 14)?;
+        // `26` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkData", 26)?;
 
         state.serialize_field("received", &self.received())?;
         state.serialize_field("total_received", &self.total_received())?;
@@ -365,9 +726,113 @@ impl Serialize for crate::NetworkData {
             "total_errors_on_transmitted",
             &self.total_errors_on_transmitted(),
         )?;
+        state.serialize_field(
+            "packets_dropped_on_received",
+            &self.packets_dropped_on_received(),
+        )?;
+        state.serialize_field(
+            "total_packets_dropped_on_received",
+            &self.total_packets_dropped_on_received(),
+        )?;
+        state.serialize_field(
+            "packets_dropped_on_transmitted",
+            &self.packets_dropped_on_transmitted(),
+        )?;
+        state.serialize_field(
+            "total_packets_dropped_on_transmitted",
+            &self.total_packets_dropped_on_transmitted(),
+        )?;
         state.serialize_field("mac_address", &self.mac_address())?;
         state.serialize_field("ip_networks", &self.ip_networks())?;
         state.serialize_field("mtu", &self.mtu())?;
+        state.serialize_field("max_mtu", &self.max_mtu())?;
+        state.serialize_field("carrier_changes", &self.carrier_changes())?;
+        state.serialize_field("duplex", &self.duplex())?;
+        state.serialize_field("autonegotiation", &self.autonegotiation())?;
+        state.serialize_field("vlan_id", &self.vlan_id())?;
+        state.serialize_field("parent_interface", &self.parent_interface())?;
+        state.serialize_field("wake_on_lan", &self.wake_on_lan())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::Duplex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Full => (0, "Full"),
+            Self::Half => (1, "Half"),
+        };
+
+        serializer.serialize_unit_variant("Duplex", index, variant)
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::NetworkCounters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `9` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkCounters", 9)?;
+
+        state.serialize_field("received", &self.received)?;
+        state.serialize_field("transmitted", &self.transmitted)?;
+        state.serialize_field("packets_received", &self.packets_received)?;
+        state.serialize_field("packets_transmitted", &self.packets_transmitted)?;
+        state.serialize_field("errors_on_received", &self.errors_on_received)?;
+        state.serialize_field("errors_on_transmitted", &self.errors_on_transmitted)?;
+        state.serialize_field(
+            "packets_dropped_on_received",
+            &self.packets_dropped_on_received,
+        )?;
+        state.serialize_field(
+            "packets_dropped_on_transmitted",
+            &self.packets_dropped_on_transmitted,
+        )?;
+        state.serialize_field("last_refresh", &self.last_refresh)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::WolMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Phy => (0, "Phy"),
+            Self::Unicast => (1, "Unicast"),
+            Self::Multicast => (2, "Multicast"),
+            Self::Broadcast => (3, "Broadcast"),
+            Self::Arp => (4, "Arp"),
+            Self::MagicPacket => (5, "MagicPacket"),
+            Self::SecureOn => (6, "SecureOn"),
+            Self::Filter => (7, "Filter"),
+        };
+
+        serializer.serialize_unit_variant("WolMode", index, variant)
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::WolConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("WolConfig", 2)?;
+
+        state.serialize_field("supported", &self.supported)?;
+        state.serialize_field("enabled", &self.enabled)?;
 
         state.end()
     }
@@ -461,3 +926,80 @@ impl Serialize for crate::Uid {
         serializer.serialize_newtype_struct("Uid", &self.to_string())
     }
 }
+
+#[cfg(all(windows, feature = "windows-services", not(feature = "unknown-ci")))]
+impl Serialize for crate::Services {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(all(windows, feature = "windows-services", not(feature = "unknown-ci")))]
+impl Serialize for crate::Service {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Service", 5)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("display_name", &self.display_name())?;
+        state.serialize_field("state", &self.state())?;
+        state.serialize_field("start_type", &self.start_type())?;
+        state.serialize_field("pid", &self.pid())?;
+
+        state.end()
+    }
+}
+
+#[cfg(all(windows, feature = "windows-services", not(feature = "unknown-ci")))]
+impl Serialize for crate::ServiceState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::Stopped => (0, "Stopped", None),
+            Self::StartPending => (1, "StartPending", None),
+            Self::StopPending => (2, "StopPending", None),
+            Self::Running => (3, "Running", None),
+            Self::ContinuePending => (4, "ContinuePending", None),
+            Self::PausePending => (5, "PausePending", None),
+            Self::Paused => (6, "Paused", None),
+            Self::Unknown(n) => (7, "Unknown", Some(n)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("ServiceState", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("ServiceState", index, variant)
+        }
+    }
+}
+
+#[cfg(all(windows, feature = "windows-services", not(feature = "unknown-ci")))]
+impl Serialize for crate::ServiceStartType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::Boot => (0, "Boot", None),
+            Self::System => (1, "System", None),
+            Self::Automatic => (2, "Automatic", None),
+            Self::Manual => (3, "Manual", None),
+            Self::Disabled => (4, "Disabled", None),
+            Self::Unknown(n) => (5, "Unknown", Some(n)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("ServiceStartType", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("ServiceStartType", index, variant)
+        }
+    }
+}
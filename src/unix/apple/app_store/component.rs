@@ -23,6 +23,10 @@ impl ComponentInner {
         ""
     }
 
+    pub(crate) fn id(&self) -> &str {
+        ""
+    }
+
     pub(crate) fn refresh(&mut self) {}
 }
 
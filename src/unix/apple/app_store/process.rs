@@ -4,7 +4,7 @@ use std::ffi::{OsStr, OsString};
 use std::path::Path;
 use std::process::ExitStatus;
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{DiskUsage, Gid, Pid, ProcessCpuCounters, ProcessStatus, Signal, Uid};
 
 pub(crate) struct ProcessInner;
 
@@ -77,6 +77,14 @@ impl ProcessInner {
         DiskUsage::default()
     }
 
+    pub(crate) fn restore_disk_usage(&mut self, _usage: &DiskUsage) {}
+
+    pub(crate) fn cpu_counters(&self) -> ProcessCpuCounters {
+        ProcessCpuCounters::default()
+    }
+
+    pub(crate) fn restore_cpu_counters(&mut self, _counters: &ProcessCpuCounters) {}
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         None
     }
@@ -240,6 +240,25 @@ pub(crate) fn physical_core_count() -> Option<usize> {
     }
 }
 
+pub(crate) fn threads_per_core() -> Option<usize> {
+    let mut logical_core_count: u32 = 0;
+
+    unsafe {
+        if !get_sys_value_by_name(
+            b"hw.logicalcpu\0",
+            &mut mem::size_of::<u32>(),
+            &mut logical_core_count as *mut u32 as *mut c_void,
+        ) || logical_core_count == 0
+        {
+            return None;
+        }
+    }
+    match physical_core_count()? {
+        0 => None,
+        physical_core_count => Some((logical_core_count as usize / physical_core_count).max(1)),
+    }
+}
+
 #[inline]
 fn get_in_use(cpu_info: *mut i32, offset: isize) -> i64 {
     unsafe {
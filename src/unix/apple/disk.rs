@@ -119,6 +119,13 @@ impl DiskInner {
         }
     }
 
+    pub(crate) fn restore_usage(&mut self, usage: &DiskUsage) {
+        self.old_read_bytes = usage.total_read_bytes;
+        self.read_bytes = usage.total_read_bytes;
+        self.old_written_bytes = usage.total_written_bytes;
+        self.written_bytes = usage.total_written_bytes;
+    }
+
     fn refresh_kind(&mut self, refresh_kind: DiskRefreshKind) {
         if refresh_kind.kind() && self.type_ == DiskKind::Unknown(-1) {
             #[cfg(target_os = "macos")]
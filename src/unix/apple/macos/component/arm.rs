@@ -158,6 +158,10 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn id(&self) -> &str {
+        &self.label
+    }
+
     pub(crate) fn refresh(&mut self) {
         unsafe {
             let Some(event) =
@@ -145,6 +145,10 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn id(&self) -> &str {
+        &self.label
+    }
+
     pub(crate) fn refresh(&mut self) {
         self.temperature = self.ffi_part.temperature();
         if let Some(temperature) = self.temperature {
@@ -8,7 +8,10 @@ use std::process::ExitStatus;
 
 use libc::{c_int, c_void, kill};
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    DiskUsage, Gid, Pid, Process, ProcessCpuCounters, ProcessRefreshKind, ProcessStatus, Signal,
+    Uid,
+};
 
 use crate::sys::process::ThreadStatus;
 use crate::sys::system::Wrap;
@@ -197,6 +200,21 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn restore_disk_usage(&mut self, usage: &DiskUsage) {
+        self.old_read_bytes = usage.total_read_bytes;
+        self.read_bytes = usage.total_read_bytes;
+        self.old_written_bytes = usage.total_written_bytes;
+        self.written_bytes = usage.total_written_bytes;
+    }
+
+    pub(crate) fn cpu_counters(&self) -> ProcessCpuCounters {
+        ProcessCpuCounters::default()
+    }
+
+    // The CPU usage is computed against the time elapsed since the previous refresh of the
+    // `System`, so restoring the process times would only create a spike.
+    pub(crate) fn restore_cpu_counters(&mut self, _counters: &ProcessCpuCounters) {}
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         self.user_id.as_ref()
     }
@@ -40,6 +40,10 @@ cfg_if! {
         pub(crate) use self::component::{ComponentInner, ComponentsInner};
     }
 
+    if #[cfg(feature = "gpu")] {
+        pub(crate) use crate::gpu::{GpuInner, GpusInner};
+    }
+
     if #[cfg(feature = "network")] {
         pub mod network;
 
@@ -51,7 +55,7 @@ cfg_if! {
         pub mod users;
 
         pub(crate) use crate::unix::groups::get_groups;
-        pub(crate) use crate::unix::users::{get_users, UserInner};
+        pub(crate) use crate::unix::users::{get_user_name, get_users, UserInner};
     }
 }
 
@@ -8,9 +8,11 @@ use libc::{
 use std::collections::{hash_map, HashMap};
 use std::mem::{size_of, MaybeUninit};
 use std::ptr::null_mut;
+use std::time::Duration;
 
+use crate::common::network::RefreshTimer;
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::{IpNetwork, MacAddr, NetworkCounters, NetworkData};
 
 // FIXME: To be removed once https://github.com/rust-lang/libc/pull/4022 is merged and released.
 #[repr(C)]
@@ -37,7 +39,8 @@ fn update_field(old_field: &mut u64, new_field: &mut u64, value: u64) {
     *new_field = value;
 }
 
-fn update_network_data(inner: &mut NetworkDataInner, data: &if_data64) {
+fn update_network_data(inner: &mut NetworkDataInner, mib_data: &ifmibdata) {
+    let data = &mib_data.ifmd_data;
     update_field(&mut inner.old_out, &mut inner.current_out, data.ifi_obytes);
     update_field(&mut inner.old_in, &mut inner.current_in, data.ifi_ibytes);
 
@@ -62,6 +65,17 @@ fn update_network_data(inner: &mut NetworkDataInner, data: &if_data64) {
         &mut inner.errors_out,
         data.ifi_oerrors,
     );
+
+    update_field(
+        &mut inner.old_dropped_in,
+        &mut inner.dropped_in,
+        data.ifi_iqdrops,
+    );
+    update_field(
+        &mut inner.old_dropped_out,
+        &mut inner.dropped_out,
+        mib_data.ifmd_snd_drops as _,
+    );
 }
 
 pub(crate) struct NetworksInner {
@@ -80,15 +94,12 @@ impl NetworksInner {
     }
 
     pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
+        for interface in self.interfaces.values_mut() {
+            interface.inner.updated = false;
+        }
         self.update_networks();
         if remove_not_listed_interfaces {
-            self.interfaces.retain(|_, i| {
-                if !i.inner.updated {
-                    return false;
-                }
-                i.inner.updated = false;
-                true
-            });
+            self.interfaces.retain(|_, i| i.inner.updated);
         }
         refresh_networks_addresses(&mut self.interfaces);
     }
@@ -186,11 +197,12 @@ impl NetworksInner {
                                 );
                             } else {
                                 let data = mib_data.assume_init();
-                                update_network_data(interface, &data.ifmd_data);
+                                update_network_data(interface, &data);
                             }
                             if interface.mtu != mtu {
                                 interface.mtu = mtu
                             }
+                            interface.refresh_timer.update();
                             interface.updated = true;
                         }
                         hash_map::Entry::Vacant(e) => {
@@ -200,6 +212,8 @@ impl NetworksInner {
                             let packets_out;
                             let errors_in;
                             let errors_out;
+                            let dropped_in;
+                            let dropped_out;
 
                             if ret < 0 {
                                 sysinfo_debug!(
@@ -212,9 +226,11 @@ impl NetworksInner {
                                 packets_out = 0;
                                 errors_in = 0;
                                 errors_out = 0;
+                                dropped_in = 0;
+                                dropped_out = 0;
                             } else {
-                                let data = mib_data.assume_init();
-                                let data = data.ifmd_data;
+                                let mib_data = mib_data.assume_init();
+                                let data = mib_data.ifmd_data;
 
                                 current_in = data.ifi_ibytes;
                                 current_out = data.ifi_obytes;
@@ -222,6 +238,8 @@ impl NetworksInner {
                                 packets_out = data.ifi_opackets;
                                 errors_in = data.ifi_ierrors;
                                 errors_out = data.ifi_oerrors;
+                                dropped_in = data.ifi_iqdrops;
+                                dropped_out = mib_data.ifmd_snd_drops as _;
                             }
 
                             e.insert(NetworkData {
@@ -238,10 +256,16 @@ impl NetworksInner {
                                     old_errors_in: errors_in,
                                     errors_out,
                                     old_errors_out: errors_out,
+                                    dropped_in,
+                                    old_dropped_in: dropped_in,
+                                    dropped_out,
+                                    old_dropped_out: dropped_out,
                                     updated: true,
                                     mac_addr: MacAddr::UNSPECIFIED,
                                     ip_networks: vec![],
+                                    addresses_changed: false,
                                     mtu,
+                                    refresh_timer: RefreshTimer::new(),
                                 },
                             });
                         }
@@ -266,16 +290,48 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    dropped_in: u64,
+    old_dropped_in: u64,
+    dropped_out: u64,
+    old_dropped_out: u64,
     updated: bool,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     /// IP networks
     pub(crate) ip_networks: Vec<IpNetwork>,
+    /// Whether the IP networks changed during the last refresh.
+    pub(crate) addresses_changed: bool,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    /// Time elapsed between the two last refreshes.
+    refresh_timer: RefreshTimer,
 }
 
 impl NetworkDataInner {
+    pub(crate) fn restore_counters(&mut self, counters: &NetworkCounters) {
+        self.old_in = counters.received;
+        self.current_in = counters.received;
+        self.old_out = counters.transmitted;
+        self.current_out = counters.transmitted;
+        self.old_packets_in = counters.packets_received;
+        self.packets_in = counters.packets_received;
+        self.old_packets_out = counters.packets_transmitted;
+        self.packets_out = counters.packets_transmitted;
+        self.old_errors_in = counters.errors_on_received;
+        self.errors_in = counters.errors_on_received;
+        self.old_errors_out = counters.errors_on_transmitted;
+        self.errors_out = counters.errors_on_transmitted;
+        self.old_dropped_in = counters.packets_dropped_on_received;
+        self.dropped_in = counters.packets_dropped_on_received;
+        self.old_dropped_out = counters.packets_dropped_on_transmitted;
+        self.dropped_out = counters.packets_dropped_on_transmitted;
+        self.refresh_timer.restore(counters.last_refresh);
+    }
+
+    pub(crate) fn last_refresh(&self) -> Duration {
+        self.refresh_timer.last_refresh()
+    }
+
     pub(crate) fn received(&self) -> u64 {
         self.current_in.saturating_sub(self.old_in)
     }
@@ -324,6 +380,22 @@ impl NetworkDataInner {
         self.errors_out
     }
 
+    pub(crate) fn packets_dropped_on_received(&self) -> u64 {
+        self.dropped_in.saturating_sub(self.old_dropped_in)
+    }
+
+    pub(crate) fn total_packets_dropped_on_received(&self) -> u64 {
+        self.dropped_in
+    }
+
+    pub(crate) fn packets_dropped_on_transmitted(&self) -> u64 {
+        self.dropped_out.saturating_sub(self.old_dropped_out)
+    }
+
+    pub(crate) fn total_packets_dropped_on_transmitted(&self) -> u64 {
+        self.dropped_out
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -332,7 +404,19 @@ impl NetworkDataInner {
         &self.ip_networks
     }
 
+    pub(crate) fn addresses_changed(&self) -> bool {
+        self.addresses_changed
+    }
+
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn is_updated(&self) -> bool {
+        self.updated
+    }
+
+    pub(crate) fn elapsed_since_last_refresh(&self) -> Option<Duration> {
+        self.refresh_timer.elapsed()
+    }
 }
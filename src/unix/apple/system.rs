@@ -6,8 +6,8 @@ use crate::sys::process::*;
 use crate::sys::utils::{get_sys_value, get_sys_value_by_name};
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    Cpu, CpuCounters, CpuRefreshKind, InterruptStats, KernelModule, LoadAvg, MemoryErrors,
+    MemoryRefreshKind, Pid, Process, ProcessEvent, ProcessRefreshKind, ProcessesToUpdate,
 };
 
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
@@ -215,6 +215,20 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn refresh_swap_rates(&mut self) {}
+
+    pub(crate) fn swap_in_per_second(&self) -> f64 {
+        0.
+    }
+
+    pub(crate) fn swap_out_per_second(&self) -> f64 {
+        0.
+    }
+
+    pub(crate) fn major_page_faults_per_second(&self) -> f64 {
+        0.
+    }
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
@@ -233,6 +247,7 @@ impl SystemInner {
         &mut self,
         _processes_to_update: ProcessesToUpdate<'_>,
         _refresh_kind: ProcessRefreshKind,
+        _on_event: &mut dyn FnMut(ProcessEvent),
     ) -> usize {
         0
     }
@@ -242,6 +257,7 @@ impl SystemInner {
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
         refresh_kind: ProcessRefreshKind,
+        on_event: &mut dyn FnMut(ProcessEvent),
     ) -> usize {
         use crate::utils::into_iter;
         use std::sync::atomic::{AtomicUsize, Ordering};
@@ -311,9 +327,14 @@ impl SystemInner {
                     })
                     .collect()
             };
-            entries.into_iter().for_each(|entry| {
-                self.process_list.insert(entry.pid(), entry);
-            });
+            for entry in entries {
+                let pid = entry.pid();
+                // If there was already a process with this PID, it was replaced by a new one.
+                if self.process_list.insert(pid, entry).is_some() {
+                    on_event(ProcessEvent::Exited(pid));
+                }
+                on_event(ProcessEvent::Started(pid));
+            }
             nb_updated.into_inner()
         } else {
             0
@@ -344,6 +365,12 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpu_counters(&self) -> CpuCounters {
+        CpuCounters::default()
+    }
+
+    pub(crate) fn restore_cpu_counters(&mut self, _counters: &CpuCounters) {}
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -530,6 +557,144 @@ impl SystemInner {
     pub(crate) fn physical_core_count() -> Option<usize> {
         physical_core_count()
     }
+
+    pub(crate) fn physical_socket_count() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn available_entropy() -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn transparent_huge_pages() -> Option<crate::ThpMode> {
+        None
+    }
+
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn smt_enabled() -> Option<bool> {
+        threads_per_core().map(|nb| nb > 1)
+    }
+
+    pub(crate) fn threads_per_core() -> Option<usize> {
+        threads_per_core()
+    }
+
+    pub(crate) fn cpus_possible() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_present() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_online() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn kernel_modules() -> Vec<KernelModule> {
+        Vec::new()
+    }
+
+    pub(crate) fn current_memory_usage() -> Option<u64> {
+        unsafe {
+            let mut task_info = mem::zeroed::<libc::proc_taskinfo>();
+            let size = mem::size_of::<libc::proc_taskinfo>() as libc::c_int;
+            if libc::proc_pidinfo(
+                libc::getpid(),
+                libc::PROC_PIDTASKINFO,
+                0,
+                &mut task_info as *mut libc::proc_taskinfo as *mut libc::c_void,
+                size,
+            ) != size
+            {
+                sysinfo_debug!("proc_pidinfo failed");
+                return None;
+            }
+            Some(task_info.pti_resident_size)
+        }
+    }
+
+    pub(crate) fn memory_errors() -> Option<MemoryErrors> {
+        None
+    }
+
+    pub(crate) fn interrupts_per_cpu() -> Option<InterruptStats> {
+        None
+    }
+
+    pub(crate) fn process_from_pidfd(_fd: std::os::unix::io::RawFd) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        crate::unix::utils::get_timezone()
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        crate::unix::utils::get_locale()
+    }
+
+    pub(crate) fn is_elevated() -> bool {
+        crate::unix::utils::is_elevated()
+    }
+
+    pub(crate) fn page_size() -> u64 {
+        crate::unix::utils::page_size()
+    }
+
+    pub(crate) fn logical_cpu_count() -> usize {
+        crate::unix::utils::logical_cpu_count()
+    }
+
+    pub(crate) fn balloon_memory() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn conntrack_count() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn conntrack_max() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn vm_setting(_name: &str) -> Option<i64> {
+        None
+    }
+
+    pub(crate) fn supports(feature: crate::Feature) -> bool {
+        use crate::Feature;
+
+        match feature {
+            Feature::AvailableMemory | Feature::LoadAverage => true,
+            Feature::ProcessCpuUsage | Feature::ProcessDiskUsage | Feature::ProcessEnvironment => {
+                cfg!(not(any(
+                    target_os = "ios",
+                    all(target_os = "macos", feature = "apple-sandbox")
+                )))
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn tcp_memory() -> Option<crate::TcpMemoryStats> {
+        None
+    }
+
+    pub(crate) fn memory_pressure_avg10() -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn os_install_time() -> Option<SystemTime> {
+        None
+    }
+
+    pub(crate) fn pid_max() -> Option<Pid> {
+        None
+    }
 }
 
 fn get_system_info(value: c_int, default: Option<&str>) -> Option<String> {
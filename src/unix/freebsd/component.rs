@@ -28,6 +28,11 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn id(&self) -> &str {
+        // `id` is nul-terminated.
+        std::str::from_utf8(self.id.strip_suffix(b"\0").unwrap_or(&self.id)).unwrap_or(&self.label)
+    }
+
     pub(crate) fn refresh(&mut self) {
         unsafe {
             self.temperature = refresh_component(&self.id);
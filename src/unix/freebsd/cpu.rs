@@ -3,7 +3,7 @@
 use crate::sys::utils::{
     get_sys_value_array, get_sys_value_by_name, get_sys_value_str_by_name, init_mib,
 };
-use crate::{Cpu, CpuRefreshKind};
+use crate::{Cpu, CpuCounters, CpuRefreshKind};
 
 use libc::{c_int, c_ulong};
 
@@ -112,6 +112,38 @@ impl CpusWrapper {
             cpu.inner.cpu_usage = compute_cpu_usage(&new_cp_times[index..], &old_cp_times[index..]);
         }
     }
+
+    pub(crate) fn counters(&self) -> CpuCounters {
+        CpuCounters {
+            global: self.cp_time.get_new().iter().map(|v| *v as u64).collect(),
+            cpus: self
+                .cp_times
+                .get_new()
+                .chunks(libc::CPUSTATES as usize)
+                .map(|cp_time| cp_time.iter().map(|v| *v as u64).collect())
+                .collect(),
+        }
+    }
+
+    pub(crate) fn restore_counters(&mut self, counters: &CpuCounters) {
+        if counters.global.len() == libc::CPUSTATES as usize {
+            for (v, restored) in self.cp_time.get_new_mut().iter_mut().zip(&counters.global) {
+                *v = *restored as _;
+            }
+        }
+        for (cp_time, restored) in self
+            .cp_times
+            .get_new_mut()
+            .chunks_mut(libc::CPUSTATES as usize)
+            .zip(&counters.cpus)
+        {
+            if restored.len() == cp_time.len() {
+                for (v, restored) in cp_time.iter_mut().zip(restored) {
+                    *v = *restored as _;
+                }
+            }
+        }
+    }
 }
 
 pub(crate) struct CpuInner {
@@ -164,6 +196,20 @@ pub(crate) fn physical_core_count() -> Option<usize> {
     }
 }
 
+pub(crate) fn threads_per_core() -> Option<usize> {
+    let mut threads_per_core: c_int = 0;
+
+    unsafe {
+        if get_sys_value_by_name(b"kern.smp.threads_per_core\0", &mut threads_per_core)
+            && threads_per_core > 0
+        {
+            Some(threads_per_core as _)
+        } else {
+            None
+        }
+    }
+}
+
 unsafe fn get_frequency_for_cpu(cpu_nb: usize) -> u64 {
     let mut frequency: c_int = 0;
 
@@ -222,4 +268,13 @@ impl<T: Clone> VecSwitcher<T> {
             &self.v1
         }
     }
+
+    /// Unlike `get_mut`, it doesn't switch the "old" and the "new".
+    pub fn get_new_mut(&mut self) -> &mut [T] {
+        if self.first {
+            &mut self.v2
+        } else {
+            &mut self.v1
+        }
+    }
 }
@@ -2,10 +2,12 @@
 
 use std::collections::{hash_map, HashMap};
 use std::mem::MaybeUninit;
+use std::time::Duration;
 
 use super::utils;
+use crate::common::network::RefreshTimer;
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::{IpNetwork, MacAddr, NetworkCounters, NetworkData};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $data:expr) => {{
@@ -35,13 +37,7 @@ impl NetworksInner {
         }
         if remove_not_listed_interfaces {
             // Remove interfaces which are gone.
-            self.interfaces.retain(|_, i| {
-                if !i.inner.updated {
-                    return false;
-                }
-                i.inner.updated = false;
-                true
-            });
+            self.interfaces.retain(|_, i| i.inner.updated);
         }
         refresh_networks_addresses(&mut self.interfaces);
     }
@@ -94,9 +90,12 @@ impl NetworksInner {
                         old_and_new!(interface, ifi_opackets, old_ifi_opackets, data);
                         old_and_new!(interface, ifi_ierrors, old_ifi_ierrors, data);
                         old_and_new!(interface, ifi_oerrors, old_ifi_oerrors, data);
+                        old_and_new!(interface, ifi_iqdrops, old_ifi_iqdrops, data);
+                        old_and_new!(interface, ifi_oqdrops, old_ifi_oqdrops, data);
                         if interface.mtu != mtu {
                             interface.mtu = mtu;
                         }
+                        interface.refresh_timer.update();
                         interface.updated = true;
                     }
                     hash_map::Entry::Vacant(e) => {
@@ -118,10 +117,16 @@ impl NetworksInner {
                                 old_ifi_ierrors: 0,
                                 ifi_oerrors: data.ifi_oerrors,
                                 old_ifi_oerrors: 0,
+                                ifi_iqdrops: data.ifi_iqdrops,
+                                old_ifi_iqdrops: 0,
+                                ifi_oqdrops: data.ifi_oqdrops,
+                                old_ifi_oqdrops: 0,
                                 updated: true,
                                 mac_addr: MacAddr::UNSPECIFIED,
                                 ip_networks: vec![],
+                                addresses_changed: false,
                                 mtu,
+                                refresh_timer: RefreshTimer::new(),
                             },
                         });
                     }
@@ -152,17 +157,51 @@ pub(crate) struct NetworkDataInner {
     /// similar to `ifi_ierrors`
     ifi_oerrors: u64,
     old_ifi_oerrors: u64,
+    /// Total number of packets received which were dropped.
+    ifi_iqdrops: u64,
+    old_ifi_iqdrops: u64,
+    /// similar to `ifi_iqdrops`
+    ifi_oqdrops: u64,
+    old_ifi_oqdrops: u64,
     /// Whether or not the above data has been updated during refresh
     updated: bool,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     /// IP networks
     pub(crate) ip_networks: Vec<IpNetwork>,
+    /// Whether the IP networks changed during the last refresh.
+    pub(crate) addresses_changed: bool,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    /// Time elapsed between the two last refreshes.
+    refresh_timer: RefreshTimer,
 }
 
 impl NetworkDataInner {
+    pub(crate) fn restore_counters(&mut self, counters: &NetworkCounters) {
+        self.old_ifi_ibytes = counters.received;
+        self.ifi_ibytes = counters.received;
+        self.old_ifi_obytes = counters.transmitted;
+        self.ifi_obytes = counters.transmitted;
+        self.old_ifi_ipackets = counters.packets_received;
+        self.ifi_ipackets = counters.packets_received;
+        self.old_ifi_opackets = counters.packets_transmitted;
+        self.ifi_opackets = counters.packets_transmitted;
+        self.old_ifi_ierrors = counters.errors_on_received;
+        self.ifi_ierrors = counters.errors_on_received;
+        self.old_ifi_oerrors = counters.errors_on_transmitted;
+        self.ifi_oerrors = counters.errors_on_transmitted;
+        self.old_ifi_iqdrops = counters.packets_dropped_on_received;
+        self.ifi_iqdrops = counters.packets_dropped_on_received;
+        self.old_ifi_oqdrops = counters.packets_dropped_on_transmitted;
+        self.ifi_oqdrops = counters.packets_dropped_on_transmitted;
+        self.refresh_timer.restore(counters.last_refresh);
+    }
+
+    pub(crate) fn last_refresh(&self) -> Duration {
+        self.refresh_timer.last_refresh()
+    }
+
     pub(crate) fn received(&self) -> u64 {
         self.ifi_ibytes.saturating_sub(self.old_ifi_ibytes)
     }
@@ -211,6 +250,22 @@ impl NetworkDataInner {
         self.ifi_oerrors
     }
 
+    pub(crate) fn packets_dropped_on_received(&self) -> u64 {
+        self.ifi_iqdrops.saturating_sub(self.old_ifi_iqdrops)
+    }
+
+    pub(crate) fn total_packets_dropped_on_received(&self) -> u64 {
+        self.ifi_iqdrops
+    }
+
+    pub(crate) fn packets_dropped_on_transmitted(&self) -> u64 {
+        self.ifi_oqdrops.saturating_sub(self.old_ifi_oqdrops)
+    }
+
+    pub(crate) fn total_packets_dropped_on_transmitted(&self) -> u64 {
+        self.ifi_oqdrops
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -219,7 +274,19 @@ impl NetworkDataInner {
         &self.ip_networks
     }
 
+    pub(crate) fn addresses_changed(&self) -> bool {
+        self.addresses_changed
+    }
+
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn is_updated(&self) -> bool {
+        self.updated
+    }
+
+    pub(crate) fn elapsed_since_last_refresh(&self) -> Option<Duration> {
+        self.refresh_timer.elapsed()
+    }
 }
@@ -1,6 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    DiskUsage, Gid, Pid, Process, ProcessCpuCounters, ProcessRefreshKind, ProcessStatus, Signal,
+    Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -143,6 +146,20 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn restore_disk_usage(&mut self, usage: &DiskUsage) {
+        self.old_read_bytes = usage.total_read_bytes;
+        self.read_bytes = usage.total_read_bytes;
+        self.old_written_bytes = usage.total_written_bytes;
+        self.written_bytes = usage.total_written_bytes;
+    }
+
+    pub(crate) fn cpu_counters(&self) -> ProcessCpuCounters {
+        ProcessCpuCounters::default()
+    }
+
+    // The CPU usage is computed by the kernel, so there is nothing to restore.
+    pub(crate) fn restore_cpu_counters(&mut self, _counters: &ProcessCpuCounters) {}
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         Some(&self.user_id)
     }
@@ -1,8 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessInner,
-    ProcessRefreshKind, ProcessesToUpdate,
+    Cpu, CpuCounters, CpuRefreshKind, InterruptStats, KernelModule, LoadAvg, MemoryErrors,
+    MemoryRefreshKind, Pid, Process, ProcessEvent, ProcessInner, ProcessRefreshKind,
+    ProcessesToUpdate,
 };
 
 use std::cell::UnsafeCell;
@@ -14,7 +15,7 @@ use std::ptr::NonNull;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, SystemTime};
 
-use crate::sys::cpu::{physical_core_count, CpusWrapper};
+use crate::sys::cpu::{physical_core_count, threads_per_core, CpusWrapper};
 use crate::sys::process::get_exe;
 use crate::sys::utils::{
     self, boot_time, c_buf_to_os_string, c_buf_to_utf8_string, from_cstr_array, get_sys_value,
@@ -103,6 +104,20 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn refresh_swap_rates(&mut self) {}
+
+    pub(crate) fn swap_in_per_second(&self) -> f64 {
+        0.
+    }
+
+    pub(crate) fn swap_out_per_second(&self) -> f64 {
+        0.
+    }
+
+    pub(crate) fn major_page_faults_per_second(&self) -> f64 {
+        0.
+    }
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
@@ -120,8 +135,9 @@ impl SystemInner {
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
         refresh_kind: ProcessRefreshKind,
+        on_event: &mut dyn FnMut(ProcessEvent),
     ) -> usize {
-        unsafe { self.refresh_procs(processes_to_update, refresh_kind) }
+        unsafe { self.refresh_procs(processes_to_update, refresh_kind, on_event) }
     }
 
     // COMMON PART
@@ -148,6 +164,14 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpu_counters(&self) -> CpuCounters {
+        self.cpus.counters()
+    }
+
+    pub(crate) fn restore_cpu_counters(&mut self, counters: &CpuCounters) {
+        self.cpus.restore_counters(counters);
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -279,6 +303,132 @@ impl SystemInner {
     pub(crate) fn physical_core_count() -> Option<usize> {
         physical_core_count()
     }
+
+    pub(crate) fn physical_socket_count() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn available_entropy() -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn transparent_huge_pages() -> Option<crate::ThpMode> {
+        None
+    }
+
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn smt_enabled() -> Option<bool> {
+        threads_per_core().map(|nb| nb > 1)
+    }
+
+    pub(crate) fn threads_per_core() -> Option<usize> {
+        threads_per_core()
+    }
+
+    pub(crate) fn cpus_possible() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_present() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_online() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn kernel_modules() -> Vec<KernelModule> {
+        Vec::new()
+    }
+
+    pub(crate) fn current_memory_usage() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn memory_errors() -> Option<MemoryErrors> {
+        None
+    }
+
+    pub(crate) fn interrupts_per_cpu() -> Option<InterruptStats> {
+        None
+    }
+
+    pub(crate) fn process_from_pidfd(_fd: std::os::unix::io::RawFd) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        crate::unix::utils::get_timezone().or_else(|| {
+            // `tzsetup` copies the timezone file to `/etc/localtime` and writes its name here.
+            std::fs::read_to_string("/var/db/zoneinfo")
+                .ok()
+                .map(|name| name.trim().to_owned())
+                .filter(|name| !name.is_empty())
+        })
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        crate::unix::utils::get_locale()
+    }
+
+    pub(crate) fn is_elevated() -> bool {
+        crate::unix::utils::is_elevated()
+    }
+
+    pub(crate) fn page_size() -> u64 {
+        crate::unix::utils::page_size()
+    }
+
+    pub(crate) fn logical_cpu_count() -> usize {
+        crate::unix::utils::logical_cpu_count()
+    }
+
+    pub(crate) fn balloon_memory() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn conntrack_count() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn conntrack_max() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn vm_setting(_name: &str) -> Option<i64> {
+        None
+    }
+
+    pub(crate) fn supports(feature: crate::Feature) -> bool {
+        use crate::Feature;
+
+        matches!(
+            feature,
+            Feature::LoadAverage
+                | Feature::ProcessCpuUsage
+                | Feature::ProcessDiskUsage
+                | Feature::ProcessEnvironment
+        )
+    }
+
+    pub(crate) fn tcp_memory() -> Option<crate::TcpMemoryStats> {
+        None
+    }
+
+    pub(crate) fn memory_pressure_avg10() -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn os_install_time() -> Option<SystemTime> {
+        None
+    }
+
+    pub(crate) fn pid_max() -> Option<Pid> {
+        None
+    }
 }
 
 impl SystemInner {
@@ -286,6 +436,7 @@ impl SystemInner {
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
         refresh_kind: ProcessRefreshKind,
+        on_event: &mut dyn FnMut(ProcessEvent),
     ) -> usize {
         let mut count = 0;
         let kvm_procs = libc::kvm_getprocs(
@@ -359,7 +510,12 @@ impl SystemInner {
         };
 
         for process in new_processes {
-            self.process_list.insert(process.inner.pid, process);
+            let pid = process.inner.pid;
+            // If there was already a process with this PID, it was replaced by a new one.
+            if self.process_list.insert(pid, process).is_some() {
+                on_event(ProcessEvent::Exited(pid));
+            }
+            on_event(ProcessEvent::Started(pid));
         }
         let kvm_procs: &mut [utils::KInfoProc] =
             std::slice::from_raw_parts_mut(kvm_procs as _, count as _);
@@ -309,9 +309,11 @@ impl ComponentInner {
                 new_comp.inner.label = new_comp.inner.format_label("temp", id);
             }
 
+            // Labels aren't unique (several NVMe drives have a "Composite" sensor for example), so
+            // components are matched by their input file.
             if let Some(comp) = components
                 .iter_mut()
-                .find(|comp| comp.inner.label == new_comp.inner.label)
+                .find(|comp| comp.inner.input_file == new_comp.inner.input_file)
             {
                 comp.inner.update_from(new_comp);
             } else {
@@ -359,6 +361,13 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn id(&self) -> &str {
+        self.input_file
+            .as_deref()
+            .and_then(Path::to_str)
+            .unwrap_or(&self.label)
+    }
+
     pub(crate) fn refresh(&mut self) {
         let current = self
             .input_file
@@ -437,3 +446,33 @@ impl ComponentsInner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentInner;
+    use std::fs;
+
+    #[test]
+    fn test_from_hwmon_same_label() {
+        let hwmon = tempfile::tempdir().expect("failed to create temporary directory");
+        // Two NVMe drives with the same sensor label.
+        for (folder, temperature) in [("hwmon1", "45000\n"), ("hwmon2", "50000\n")] {
+            let folder = hwmon.path().join(folder);
+            fs::create_dir(&folder).unwrap();
+            fs::write(folder.join("name"), "nvme\n").unwrap();
+            fs::write(folder.join("temp1_label"), "Composite\n").unwrap();
+            fs::write(folder.join("temp1_input"), temperature).unwrap();
+        }
+
+        let mut components = Vec::new();
+        for _ in 0..2 {
+            ComponentInner::from_hwmon(&mut components, &hwmon.path().join("hwmon1"));
+            ComponentInner::from_hwmon(&mut components, &hwmon.path().join("hwmon2"));
+        }
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].label(), components[1].label());
+        assert_ne!(components[0].id(), components[1].id());
+        assert_eq!(components[0].temperature(), Some(45.));
+        assert_eq!(components[1].temperature(), Some(50.));
+    }
+}
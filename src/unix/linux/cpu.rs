@@ -3,12 +3,12 @@
 #![allow(clippy::too_many_arguments)]
 
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use crate::sys::utils::to_u64;
-use crate::{Cpu, CpuRefreshKind};
+use crate::sys::utils::{get_all_utf8_data, open_file, to_u64};
+use crate::{Cpu, CpuCounters, CpuRefreshKind, IdleState};
 
 macro_rules! to_str {
     ($e:expr) => {
@@ -47,6 +47,9 @@ impl CpusWrapper {
             .last_update
             .map(|last_update| last_update.elapsed() > crate::MINIMUM_CPU_UPDATE_INTERVAL)
             .unwrap_or(true);
+        // Replayed data doesn't depend on the time between two refreshes.
+        #[cfg(feature = "linux-replay")]
+        let need_cpu_usage_update = need_cpu_usage_update || crate::sys::replay::is_replaying();
 
         let first = self.cpus.is_empty();
         let mut vendors_brands = if first {
@@ -59,7 +62,7 @@ impl CpusWrapper {
         // we don't want to update CPUs times.
         if need_cpu_usage_update {
             self.last_update = Some(Instant::now());
-            let f = match File::open("/proc/stat") {
+            let f = match open_file("/proc/stat") {
                 Ok(f) => f,
                 Err(_e) => {
                     sysinfo_debug!("failed to retrieve CPU information: {:?}", _e);
@@ -177,6 +180,24 @@ impl CpusWrapper {
         (self.global_cpu.total_time, self.global_cpu.old_total_time)
     }
 
+    pub(crate) fn counters(&self) -> CpuCounters {
+        CpuCounters {
+            global: self.global_cpu.new_values.counters(),
+            cpus: self
+                .cpus
+                .iter()
+                .map(|cpu| cpu.inner.usage.new_values.counters())
+                .collect(),
+        }
+    }
+
+    pub(crate) fn restore_counters(&mut self, counters: &CpuCounters) {
+        self.global_cpu.restore(&counters.global);
+        for (cpu, values) in self.cpus.iter_mut().zip(counters.cpus.iter()) {
+            cpu.inner.usage.restore(values);
+        }
+    }
+
     pub(crate) fn len(&self) -> usize {
         self.cpus.len()
     }
@@ -230,6 +251,43 @@ impl CpuValues {
         self.guest_nice = guest_nice;
     }
 
+    fn counters(&self) -> Vec<u64> {
+        vec![
+            self.user,
+            self.nice,
+            self.system,
+            self.idle,
+            self.iowait,
+            self.irq,
+            self.softirq,
+            self.steal,
+            self.guest,
+            self.guest_nice,
+        ]
+    }
+
+    /// Builds the values from the ones returned by `counters`. Since `guest` and `guest_nice`
+    /// were already subtracted, `set` cannot be used.
+    fn from_counters(values: &[u64]) -> Option<Self> {
+        match *values {
+            [user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice] => {
+                Some(Self {
+                    user,
+                    nice,
+                    system,
+                    idle,
+                    iowait,
+                    irq,
+                    softirq,
+                    steal,
+                    guest,
+                    guest_nice,
+                })
+            }
+            _ => None,
+        }
+    }
+
     /// Returns work time.
     pub fn work_time(&self) -> u64 {
         self.user
@@ -255,6 +313,7 @@ impl CpuValues {
 #[derive(Default)]
 pub(crate) struct CpuUsage {
     percent: f32,
+    steal_percent: f32,
     old_values: CpuValues,
     new_values: CpuValues,
     total_time: u64,
@@ -282,6 +341,7 @@ impl CpuUsage {
             old_values: CpuValues::default(),
             new_values,
             percent: 0f32,
+            steal_percent: 0f32,
             total_time: 0,
             old_total_time: 0,
         }
@@ -321,11 +381,27 @@ impl CpuUsage {
         if self.percent > 100. {
             self.percent = 100.; // to prevent the percentage to go above 100%
         }
+        self.steal_percent = (min!(self.new_values.steal, self.old_values.steal, 0.)
+            / min!(self.total_time, self.old_total_time, 1.)
+            * 100.)
+            .min(100.);
+    }
+
+    /// The restored values become the reference of the next call to `set`.
+    fn restore(&mut self, values: &[u64]) {
+        if let Some(values) = CpuValues::from_counters(values) {
+            self.new_values = values;
+            self.total_time = values.total_time();
+        }
     }
 
     pub(crate) fn usage(&self) -> f32 {
         self.percent
     }
+
+    pub(crate) fn steal_time_percent(&self) -> f32 {
+        self.steal_percent
+    }
 }
 
 pub(crate) struct CpuInner {
@@ -386,6 +462,10 @@ impl CpuInner {
         self.usage.percent
     }
 
+    pub(crate) fn steal_time_percent(&self) -> f32 {
+        self.usage.steal_percent
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -402,11 +482,109 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         &self.brand
     }
+
+    pub(crate) fn idle_states(&self) -> Option<Vec<IdleState>> {
+        read_idle_states(
+            &Path::new("/sys/devices/system/cpu")
+                .join(&self.name)
+                .join("cpuidle"),
+        )
+    }
+
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        read_core_temperature(
+            &Path::new("/sys/devices/system/cpu")
+                .join(&self.name)
+                .join("topology"),
+            Path::new("/sys/class/hwmon"),
+        )
+    }
+
+    pub(crate) fn current_max_frequency(&self) -> Option<u64> {
+        // The frequency is in kHz.
+        read_u64(
+            &Path::new("/sys/devices/system/cpu")
+                .join(&self.name)
+                .join("cpufreq/scaling_max_freq"),
+        )
+        .map(|frequency| frequency / 1000)
+    }
+}
+
+// The `coretemp` driver creates one hwmon device per package, with a `Package id P` sensor and
+// a `Core C` sensor for each of its cores (`C` being the `core_id` of the CPU topology).
+fn read_core_temperature(topology: &Path, hwmon: &Path) -> Option<f32> {
+    let core_id = read_u64(&topology.join("core_id"))?;
+    let package_id = read_u64(&topology.join("physical_package_id"));
+    let core_label = format!("Core {core_id}");
+
+    std::fs::read_dir(hwmon).ok()?.find_map(|entry| {
+        let path = entry.ok()?.path();
+        if get_all_utf8_data(path.join("name"), 32).ok()?.trim() != "coretemp" {
+            return None;
+        }
+        let mut core_input = None;
+        for entry in std::fs::read_dir(&path).ok()?.flatten() {
+            let file_name = entry.file_name();
+            let Some(sensor) = file_name
+                .to_str()
+                .and_then(|name| name.strip_suffix("_label"))
+            else {
+                continue;
+            };
+            let Ok(label) = get_all_utf8_data(entry.path(), 32) else {
+                continue;
+            };
+            let label = label.trim();
+            if let Some(id) = label.strip_prefix("Package id ") {
+                if package_id.is_some_and(|package_id| id.parse() != Ok(package_id)) {
+                    // This hwmon device is for another package.
+                    return None;
+                }
+            } else if label == core_label {
+                core_input = Some(path.join(format!("{sensor}_input")));
+            }
+        }
+        // The temperature is in millidegrees Celsius.
+        let temperature = get_all_utf8_data(core_input?, 32).ok()?;
+        Some(temperature.trim().parse::<i64>().ok()? as f32 / 1000.)
+    })
+}
+
+// Each idle state has a `stateN` folder in `cpuidle`.
+fn read_idle_states(cpuidle: &Path) -> Option<Vec<IdleState>> {
+    let mut states = std::fs::read_dir(cpuidle)
+        .ok()?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let index = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("state")?
+                .parse::<usize>()
+                .ok()?;
+            let name = get_all_utf8_data(path.join("name"), 64).ok()?;
+            Some((
+                index,
+                IdleState {
+                    name: name.trim().to_owned(),
+                    // The time is in microseconds.
+                    residency: Duration::from_micros(read_u64(&path.join("time"))?),
+                    entries: read_u64(&path.join("usage"))?,
+                },
+            ))
+        })
+        .collect::<Vec<_>>();
+    if states.is_empty() {
+        return None;
+    }
+    states.sort_unstable_by_key(|(index, _)| *index);
+    Some(states.into_iter().map(|(_, state)| state).collect())
 }
 
 pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
     let mut s = String::new();
-    if File::open(format!(
+    if open_file(format!(
         "/sys/devices/system/cpu/cpu{cpu_core_index}/cpufreq/scaling_cur_freq",
     ))
     .and_then(|mut f| f.read_to_string(&mut s))
@@ -420,7 +598,7 @@ pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
         }
     }
     s.clear();
-    if File::open("/proc/cpuinfo")
+    if open_file("/proc/cpuinfo")
         .and_then(|mut f| f.read_to_string(&mut s))
         .is_err()
     {
@@ -439,10 +617,68 @@ pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
         .unwrap_or_default()
 }
 
+/// Parses a CPU list as found in `/sys/devices/system/cpu` (like `0-3,8,10-11`).
+pub(crate) fn parse_cpu_list(list: &str) -> Vec<usize> {
+    let mut cpus = Vec::new();
+    for part in list.trim().split(',').filter(|part| !part.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                if let (Ok(start), Ok(end)) = (start.parse::<usize>(), end.parse::<usize>()) {
+                    cpus.extend(start..=end);
+                }
+            }
+            None => {
+                if let Ok(cpu) = part.parse::<usize>() {
+                    cpus.push(cpu);
+                }
+            }
+        }
+    }
+    cpus
+}
+
+/// Returns the number of CPUs in the `name` list of `/sys/devices/system/cpu` (`possible`,
+/// `present` or `online`).
+pub(crate) fn cpu_list_count(name: &str) -> Option<usize> {
+    let list = std::fs::read_to_string(Path::new("/sys/devices/system/cpu").join(name)).ok()?;
+    Some(parse_cpu_list(&list).len()).filter(|nb| *nb > 0)
+}
+
+pub(crate) fn threads_per_core() -> Option<usize> {
+    std::fs::read_dir("/sys/devices/system/cpu")
+        .ok()?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("cpu")?
+                .parse::<usize>()
+                .ok()?;
+            let siblings =
+                std::fs::read_to_string(entry.path().join("topology/thread_siblings_list")).ok()?;
+            Some(parse_cpu_list(&siblings).len())
+        })
+        .max()
+        .filter(|nb| *nb > 0)
+}
+
+pub(crate) fn smt_enabled() -> Option<bool> {
+    if let Ok(active) = std::fs::read_to_string("/sys/devices/system/cpu/smt/active") {
+        match active.trim() {
+            "1" => return Some(true),
+            "0" => return Some(false),
+            _ => {}
+        }
+    }
+    // Kernels older than 4.19 don't have the `smt` folder.
+    threads_per_core().map(|nb| nb > 1)
+}
+
 #[allow(unused_assignments)]
 pub(crate) fn get_physical_core_count() -> Option<usize> {
     let mut s = String::new();
-    if let Err(_e) = File::open("/proc/cpuinfo").and_then(|mut f| f.read_to_string(&mut s)) {
+    if let Err(_e) = open_file("/proc/cpuinfo").and_then(|mut f| f.read_to_string(&mut s)) {
         sysinfo_debug!("Cannot read `/proc/cpuinfo` file: {:?}", _e);
         return None;
     }
@@ -496,6 +732,32 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
     Some(core_ids_and_physical_ids.len())
 }
 
+pub(crate) fn get_physical_socket_count() -> Option<usize> {
+    let mut s = String::new();
+    if let Err(_e) = open_file("/proc/cpuinfo").and_then(|mut f| f.read_to_string(&mut s)) {
+        sysinfo_debug!("Cannot read `/proc/cpuinfo` file: {:?}", _e);
+        return None;
+    }
+    count_physical_ids(&s)
+}
+
+// Returns the number of distinct "physical id" values of a `/proc/cpuinfo` file, or `None` if
+// there isn't any (like on most ARM systems).
+fn count_physical_ids(cpuinfo: &str) -> Option<usize> {
+    let physical_ids = cpuinfo
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key.trim() == "physical id").then(|| value.trim())
+        })
+        .collect::<HashSet<_>>();
+    if physical_ids.is_empty() {
+        None
+    } else {
+        Some(physical_ids.len())
+    }
+}
+
 /// Obtain the implementer of this CPU core.
 ///
 /// This has been obtained from util-linux's lscpu implementation, see
@@ -721,7 +983,7 @@ fn get_arm_part(implementer: u32, part: u32) -> Option<&'static str> {
 /// Returns the brand/vendor string for the first CPU (which should be the same for all CPUs).
 pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
     let mut s = String::new();
-    if File::open("/proc/cpuinfo")
+    if open_file("/proc/cpuinfo")
         .and_then(|mut f| f.read_to_string(&mut s))
         .is_err()
     {
@@ -835,3 +1097,262 @@ pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
     }
     cpus
 }
+
+/// Energy counters of the RAPL (Running Average Power Limit) packages, used to compute the power
+/// consumed by the CPU packages between two refreshes.
+pub(crate) struct PackageEnergy {
+    // Last value of `energy_uj` of each package.
+    last_energy: HashMap<PathBuf, u64>,
+    last_update: Option<Instant>,
+    power: Option<f64>,
+}
+
+impl PackageEnergy {
+    pub(crate) fn new() -> Self {
+        Self {
+            last_energy: HashMap::new(),
+            last_update: None,
+            power: None,
+        }
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        let now = Instant::now();
+        let mut energy = HashMap::new();
+        // Energy consumed (in microjoules) since the last refresh.
+        let mut consumed = 0;
+        let mut complete = true;
+
+        for zone in rapl_package_zones(Path::new("/sys/class/powercap")) {
+            let Some(new) = read_u64(&zone.join("energy_uj")) else {
+                // Since Linux 5.10, `energy_uj` is only readable by root.
+                continue;
+            };
+            match self.last_energy.get(&zone) {
+                Some(&old) => {
+                    let max = read_u64(&zone.join("max_energy_range_uj")).unwrap_or(0);
+                    consumed += energy_delta(old, new, max);
+                }
+                None => complete = false,
+            }
+            energy.insert(zone, new);
+        }
+
+        self.power = match self.last_update {
+            Some(last_update) if complete && !energy.is_empty() => {
+                let elapsed = now.duration_since(last_update).as_secs_f64();
+                if elapsed > 0. {
+                    Some(consumed as f64 / 1_000_000. / elapsed)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        self.last_energy = energy;
+        self.last_update = Some(now);
+    }
+
+    pub(crate) fn power(&self) -> Option<f64> {
+        self.power
+    }
+
+    /// Returns `true` if the energy counter of at least one package can be read.
+    pub(crate) fn is_available() -> bool {
+        rapl_package_zones(Path::new("/sys/class/powercap"))
+            .iter()
+            .any(|zone| read_u64(&zone.join("energy_uj")).is_some())
+    }
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    get_all_utf8_data(path, 32).ok()?.trim().parse().ok()
+}
+
+/// Returns the RAPL zones of the CPU packages (subzones like `core` or `dram` are skipped since
+/// their energy is already part of their package).
+fn rapl_package_zones(powercap: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(powercap) else {
+        return Vec::new();
+    };
+    let mut zones = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            // Packages are named `intel-rapl:N` (also on AMD), subzones `intel-rapl:N:M`.
+            let index = name.strip_prefix("intel-rapl:")?;
+            if index.contains(':') {
+                return None;
+            }
+            let zone_name = get_all_utf8_data(path.join("name"), 64).ok()?;
+            zone_name.trim().starts_with("package").then_some(path)
+        })
+        .collect::<Vec<_>>();
+    zones.sort();
+    zones
+}
+
+/// Returns the energy consumed between `old` and `new`, taking into account that the counter
+/// wraps around once it reaches `max`.
+fn energy_delta(old: u64, new: u64, max: u64) -> u64 {
+    if new >= old {
+        new - old
+    } else {
+        max.saturating_sub(old).saturating_add(new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        count_physical_ids, energy_delta, parse_cpu_list, rapl_package_zones,
+        read_core_temperature, read_idle_states,
+    };
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn test_count_physical_ids() {
+        let cpuinfo = "\
+processor\t: 0
+physical id\t: 0
+core id\t\t: 0
+
+processor\t: 1
+physical id\t: 0
+core id\t\t: 1
+
+processor\t: 2
+physical id\t: 1
+core id\t\t: 0
+";
+        assert_eq!(count_physical_ids(cpuinfo), Some(2));
+        assert_eq!(
+            count_physical_ids("processor\t: 0\nBogoMIPS\t: 108.00\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0"), vec![0]);
+        assert_eq!(parse_cpu_list("0,4\n"), vec![0, 4]);
+        assert_eq!(parse_cpu_list("0-3,8,10-11"), vec![0, 1, 2, 3, 8, 10, 11]);
+        assert_eq!(parse_cpu_list(""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_energy_delta() {
+        assert_eq!(energy_delta(100, 250, 1_000), 150);
+        assert_eq!(energy_delta(900, 50, 1_000), 150);
+        assert_eq!(energy_delta(100, 100, 1_000), 0);
+        // Unknown maximum.
+        assert_eq!(energy_delta(900, 50, 0), 50);
+    }
+
+    #[test]
+    fn test_rapl_package_zones() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let powercap = dir.path();
+        for (zone, name) in [
+            ("intel-rapl:0", "package-0"),
+            ("intel-rapl:0:0", "core"),
+            ("intel-rapl:1", "package-1"),
+            ("intel-rapl:2", "psys"),
+            ("intel-rapl-mmio:0", "package-0"),
+        ] {
+            std::fs::create_dir(powercap.join(zone)).unwrap();
+            std::fs::write(powercap.join(zone).join("name"), format!("{name}\n")).unwrap();
+        }
+
+        assert_eq!(
+            rapl_package_zones(powercap),
+            vec![powercap.join("intel-rapl:0"), powercap.join("intel-rapl:1")]
+        );
+        assert!(rapl_package_zones(&powercap.join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_read_idle_states() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let cpuidle = dir.path();
+        assert!(read_idle_states(cpuidle).is_none());
+
+        for (state, name, time, usage) in [
+            ("state10", "C10", "5000000\n", "12\n"),
+            ("state0", "POLL", "1500\n", "400\n"),
+            ("state1", "C1", "2000000\n", "9001\n"),
+        ] {
+            let path = cpuidle.join(state);
+            std::fs::create_dir(&path).unwrap();
+            std::fs::write(path.join("name"), format!("{name}\n")).unwrap();
+            std::fs::write(path.join("time"), time).unwrap();
+            std::fs::write(path.join("usage"), usage).unwrap();
+        }
+
+        let states = read_idle_states(cpuidle).expect("failed to read idle states");
+        let states = states
+            .iter()
+            .map(|s| (s.name.as_str(), s.residency, s.entries))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            states,
+            [
+                ("POLL", Duration::from_micros(1_500), 400),
+                ("C1", Duration::from_secs(2), 9_001),
+                ("C10", Duration::from_secs(5), 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_core_temperature() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let hwmon = dir.path().join("hwmon");
+        let write_sensor = |device: &str, sensor: &str, label: &str, input: &str| {
+            let device = hwmon.join(device);
+            fs::create_dir_all(&device).unwrap();
+            fs::write(device.join(format!("{sensor}_label")), label).unwrap();
+            fs::write(device.join(format!("{sensor}_input")), input).unwrap();
+        };
+        // Two packages, with the same core IDs.
+        fs::create_dir_all(hwmon.join("hwmon0")).unwrap();
+        fs::write(hwmon.join("hwmon0/name"), "coretemp\n").unwrap();
+        write_sensor("hwmon0", "temp1", "Package id 0\n", "50000\n");
+        write_sensor("hwmon0", "temp2", "Core 0\n", "45000\n");
+        write_sensor("hwmon0", "temp3", "Core 4\n", "47500\n");
+        fs::create_dir_all(hwmon.join("hwmon1")).unwrap();
+        fs::write(hwmon.join("hwmon1/name"), "coretemp\n").unwrap();
+        write_sensor("hwmon1", "temp1", "Package id 1\n", "60000\n");
+        write_sensor("hwmon1", "temp2", "Core 0\n", "61000\n");
+        // Another sensor with a matching label.
+        fs::create_dir_all(hwmon.join("hwmon2")).unwrap();
+        fs::write(hwmon.join("hwmon2/name"), "nvme\n").unwrap();
+        write_sensor("hwmon2", "temp1", "Core 0\n", "30000\n");
+
+        let topology = |core_id: &str, package_id: &str| {
+            let topology = dir.path().join(format!("cpu-{core_id}-{package_id}"));
+            fs::create_dir_all(&topology).unwrap();
+            fs::write(topology.join("core_id"), core_id).unwrap();
+            fs::write(topology.join("physical_package_id"), package_id).unwrap();
+            topology
+        };
+        assert_eq!(
+            read_core_temperature(&topology("0", "0"), &hwmon),
+            Some(45.)
+        );
+        assert_eq!(
+            read_core_temperature(&topology("4", "0"), &hwmon),
+            Some(47.5)
+        );
+        assert_eq!(
+            read_core_temperature(&topology("0", "1"), &hwmon),
+            Some(61.)
+        );
+        assert_eq!(read_core_temperature(&topology("1", "1"), &hwmon), None);
+        assert_eq!(
+            read_core_temperature(&dir.path().join("missing"), &hwmon),
+            None
+        );
+    }
+}
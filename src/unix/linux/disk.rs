@@ -1,9 +1,10 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::utils::{get_all_utf8_data, to_cpath};
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage, MountPropagation};
 
 use libc::statvfs;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
 use std::fs;
@@ -11,6 +12,7 @@ use std::mem::MaybeUninit;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 /// Copied from [`psutil`]:
 ///
@@ -42,12 +44,29 @@ pub(crate) struct DiskInner {
     mount_point: PathBuf,
     total_space: u64,
     available_space: u64,
+    data_used_space: Option<u64>,
+    raw_used_space: Option<u64>,
     is_removable: bool,
     is_read_only: bool,
+    trim_enabled: bool,
+    is_bind_mount: Option<bool>,
+    propagation: Option<MountPropagation>,
     old_written_bytes: u64,
     old_read_bytes: u64,
     written_bytes: u64,
     read_bytes: u64,
+    old_reads_completed: u64,
+    old_writes_completed: u64,
+    reads_completed: u64,
+    writes_completed: u64,
+    old_read_time_ms: u64,
+    old_write_time_ms: u64,
+    read_time_ms: u64,
+    write_time_ms: u64,
+    #[cfg(feature = "raw-access")]
+    raw_mount_line: String,
+    // The root folder of the mount namespace of the disk, `None` for the current one.
+    root: Option<PathBuf>,
     updated: bool,
 }
 
@@ -76,6 +95,19 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn data_used_space(&self) -> Option<u64> {
+        self.data_used_space
+    }
+
+    pub(crate) fn raw_used_space(&self) -> Option<u64> {
+        self.raw_used_space
+    }
+
+    #[cfg(feature = "raw-access")]
+    pub(crate) fn raw_mount_line(&self) -> Option<&str> {
+        Some(&self.raw_mount_line)
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -84,6 +116,52 @@ impl DiskInner {
         self.is_read_only
     }
 
+    pub(crate) fn supports_trim(&self) -> Option<bool> {
+        match self.actual_device_name {
+            Some(ref actual_device_name) => {
+                supports_trim(Path::new("/sys/class/block"), actual_device_name)
+            }
+            None => supports_trim(
+                Path::new("/sys/class/block"),
+                &get_actual_device_name(&self.device_name),
+            ),
+        }
+    }
+
+    pub(crate) fn io_scheduler(&self) -> Option<String> {
+        match self.actual_device_name {
+            Some(ref actual_device_name) => {
+                io_scheduler(Path::new("/sys/class/block"), actual_device_name)
+            }
+            None => io_scheduler(
+                Path::new("/sys/class/block"),
+                &get_actual_device_name(&self.device_name),
+            ),
+        }
+    }
+
+    pub(crate) fn trim_enabled(&self) -> bool {
+        self.trim_enabled
+    }
+
+    pub(crate) fn is_bind_mount(&self) -> Option<bool> {
+        self.is_bind_mount
+    }
+
+    pub(crate) fn propagation(&self) -> Option<MountPropagation> {
+        self.propagation
+    }
+
+    fn update_mount(&mut self, mount: &MountLine<'_>, details: Option<(bool, MountPropagation)>) {
+        self.trim_enabled = is_trim_enabled(mount.fs_mntops);
+        self.is_bind_mount = details.map(|(is_bind_mount, _)| is_bind_mount);
+        self.propagation = details.map(|(_, propagation)| propagation);
+        #[cfg(feature = "raw-access")]
+        {
+            self.raw_mount_line = mount.line.to_owned();
+        }
+    }
+
     pub(crate) fn refresh_specifics(&mut self, refresh_kind: DiskRefreshKind) -> bool {
         self.efficient_refresh(refresh_kind, &disk_stats(&refresh_kind), false)
     }
@@ -107,6 +185,14 @@ impl DiskInner {
                 self.old_written_bytes = self.written_bytes;
                 self.read_bytes = stat.sectors_read * SECTOR_SIZE;
                 self.written_bytes = stat.sectors_written * SECTOR_SIZE;
+                self.old_reads_completed = self.reads_completed;
+                self.old_writes_completed = self.writes_completed;
+                self.old_read_time_ms = self.read_time_ms;
+                self.old_write_time_ms = self.write_time_ms;
+                self.reads_completed = stat.reads_completed;
+                self.writes_completed = stat.writes_completed;
+                self.read_time_ms = stat.read_time_ms;
+                self.write_time_ms = stat.write_time_ms;
             } else {
                 sysinfo_debug!("Failed to update disk i/o stats");
             }
@@ -117,8 +203,18 @@ impl DiskInner {
         }
 
         if refresh_kind.storage() {
+            let mount_point = match self.root {
+                Some(ref root) => Cow::Owned(
+                    root.join(
+                        self.mount_point
+                            .strip_prefix("/")
+                            .unwrap_or(&self.mount_point),
+                    ),
+                ),
+                None => Cow::Borrowed(self.mount_point.as_path()),
+            };
             if let Some((total_space, available_space, is_read_only)) =
-                unsafe { load_statvfs_values(&self.mount_point) }
+                unsafe { load_statvfs_values(&mount_point) }
             {
                 self.total_space = total_space;
                 self.available_space = available_space;
@@ -126,6 +222,14 @@ impl DiskInner {
                     self.is_read_only = is_read_only;
                 }
             }
+            if self.file_system == "btrfs" {
+                let actual_device_name = self
+                    .actual_device_name
+                    .get_or_insert_with(|| get_actual_device_name(&self.device_name));
+                let used = btrfs_used_space(Path::new("/sys/fs/btrfs"), actual_device_name);
+                self.data_used_space = used.map(|(data, _)| data);
+                self.raw_used_space = used.map(|(_, raw)| raw);
+            }
         }
 
         true
@@ -139,12 +243,53 @@ impl DiskInner {
             total_written_bytes: self.written_bytes,
         }
     }
+
+    pub(crate) fn restore_usage(&mut self, usage: &DiskUsage) {
+        self.old_read_bytes = usage.total_read_bytes;
+        self.read_bytes = usage.total_read_bytes;
+        self.old_written_bytes = usage.total_written_bytes;
+        self.written_bytes = usage.total_written_bytes;
+    }
+
+    pub(crate) fn average_read_latency(&self) -> Option<Duration> {
+        average_latency(
+            self.reads_completed
+                .saturating_sub(self.old_reads_completed),
+            self.read_time_ms.saturating_sub(self.old_read_time_ms),
+        )
+    }
+
+    pub(crate) fn average_write_latency(&self) -> Option<Duration> {
+        average_latency(
+            self.writes_completed
+                .saturating_sub(self.old_writes_completed),
+            self.write_time_ms.saturating_sub(self.old_write_time_ms),
+        )
+    }
+}
+
+fn average_latency(completed: u64, time_ms: u64) -> Option<Duration> {
+    if completed == 0 {
+        return None;
+    }
+    Some(Duration::from_micros(
+        time_ms.saturating_mul(1_000) / completed,
+    ))
 }
 
 impl crate::DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            namespace_proc_path: None,
+        }
+    }
+
+    #[cfg(feature = "system")]
+    pub(crate) fn from_process_namespace(pid: crate::Pid) -> Self {
+        Self {
+            disks: Vec::with_capacity(2),
+            namespace_proc_path: Some(Path::new("/proc").join(pid.to_string())),
         }
     }
 
@@ -153,11 +298,22 @@ impl crate::DisksInner {
         remove_not_listed_disks: bool,
         refresh_kind: DiskRefreshKind,
     ) {
-        get_all_list(
-            &mut self.disks,
-            &get_all_utf8_data("/proc/mounts", 16_385).unwrap_or_default(),
-            refresh_kind,
-        );
+        match self.namespace_proc_path {
+            Some(ref proc_path) => get_all_list(
+                &mut self.disks,
+                &get_all_utf8_data(proc_path.join("mountinfo"), 16_385).unwrap_or_default(),
+                MountsFormat::MountInfo {
+                    root: proc_path.join("root"),
+                },
+                refresh_kind,
+            ),
+            None => get_all_list(
+                &mut self.disks,
+                &get_all_utf8_data("/proc/mounts", 16_385).unwrap_or_default(),
+                MountsFormat::Mounts,
+                refresh_kind,
+            ),
+        }
 
         if remove_not_listed_disks {
             self.disks.retain_mut(|disk| {
@@ -202,6 +358,29 @@ fn get_actual_device_name(device: &OsStr) -> String {
         .unwrap_or_default()
 }
 
+// Returns the used space of the btrfs filesystem using the given device, counting each copy once
+// and then all of them.
+fn btrfs_used_space(sys_fs_btrfs: &Path, actual_device_name: &str) -> Option<(u64, u64)> {
+    let read = |path: PathBuf| -> Option<u64> {
+        u64::from_str(get_all_utf8_data(path, 32).ok()?.trim()).ok()
+    };
+
+    // Each filesystem has a folder named after its UUID, listing the devices it uses.
+    let fs_path = fs::read_dir(sys_fs_btrfs)
+        .ok()?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .find(|path| path.join("devices").join(actual_device_name).exists())?;
+    let allocation = fs_path.join("allocation");
+
+    let mut data = 0u64;
+    let mut raw = 0u64;
+    for kind in ["data", "metadata", "system"] {
+        data = data.saturating_add(read(allocation.join(kind).join("bytes_used"))?);
+        raw = raw.saturating_add(read(allocation.join(kind).join("disk_used"))?);
+    }
+    Some((data, raw))
+}
+
 unsafe fn load_statvfs_values(mount_point: &Path) -> Option<(u64, u64, bool)> {
     let mount_point_cpath = to_cpath(mount_point);
     let mut stat: MaybeUninit<statvfs> = MaybeUninit::uninit();
@@ -229,13 +408,14 @@ unsafe fn load_statvfs_values(mount_point: &Path) -> Option<(u64, u64, bool)> {
 }
 
 fn new_disk(
-    device_name: &OsStr,
-    mount_point: &Path,
-    file_system: &OsStr,
+    mount: &MountLine<'_>,
+    details: Option<(bool, MountPropagation)>,
+    root: Option<&Path>,
     removable_entries: &[PathBuf],
     procfs_disk_stats: &HashMap<String, DiskStat>,
     refresh_kind: DiskRefreshKind,
 ) -> Disk {
+    let device_name = OsStr::new(mount.fs_spec);
     let is_removable = removable_entries
         .iter()
         .any(|e| e.as_os_str() == device_name);
@@ -245,19 +425,36 @@ fn new_disk(
             type_: DiskKind::Unknown(-1),
             device_name: device_name.to_owned(),
             actual_device_name: None,
-            file_system: file_system.to_owned(),
-            mount_point: mount_point.to_owned(),
+            file_system: mount.fs_vfstype.into(),
+            mount_point: PathBuf::from(&mount.fs_file),
             total_space: 0,
             available_space: 0,
+            data_used_space: None,
+            raw_used_space: None,
             is_removable,
             is_read_only: false,
+            trim_enabled: false,
+            is_bind_mount: None,
+            propagation: None,
             old_read_bytes: 0,
             old_written_bytes: 0,
             read_bytes: 0,
             written_bytes: 0,
+            old_reads_completed: 0,
+            old_writes_completed: 0,
+            reads_completed: 0,
+            writes_completed: 0,
+            old_read_time_ms: 0,
+            old_write_time_ms: 0,
+            read_time_ms: 0,
+            write_time_ms: 0,
+            #[cfg(feature = "raw-access")]
+            raw_mount_line: String::new(),
+            root: root.map(Path::to_path_buf),
             updated: true,
         },
     };
+    disk.inner.update_mount(mount, details);
     disk.inner
         .efficient_refresh(refresh_kind, procfs_disk_stats, true);
     disk
@@ -334,7 +531,98 @@ fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
     }
 }
 
-fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefreshKind) {
+// Format of the `content` given to `get_all_list`.
+enum MountsFormat {
+    // `/proc/mounts`.
+    Mounts,
+    // `/proc/<pid>/mountinfo`, `root` being the `/proc/<pid>/root` folder.
+    MountInfo { root: PathBuf },
+}
+
+// Unescapes the octal sequences the kernel uses in mount points.
+fn unescape_mount_point(mount_point: &str) -> String {
+    mount_point
+        .replace("\\134", "\\")
+        .replace("\\040", " ")
+        .replace("\\011", "\t")
+        .replace("\\012", "\n")
+}
+
+// A line of `/proc/mounts` or `/proc/<pid>/mountinfo` with the fields we use.
+struct MountLine<'a> {
+    line: &'a str,
+    fs_spec: &'a str,
+    fs_file: String,
+    fs_vfstype: &'a str,
+    fs_mntops: &'a str,
+}
+
+// Returns the `fs_spec`, `fs_file`, `fs_vfstype` and `fs_mntops` fields of a `/proc/mounts` line.
+fn parse_mounts_line(line: &str) -> (&str, String, &str, &str) {
+    // mounts format
+    // http://man7.org/linux/man-pages/man5/fstab.5.html
+    // fs_spec<tab>fs_file<tab>fs_vfstype<tab>other fields
+    let mut fields = line.split_whitespace();
+    let fs_spec = fields.next().unwrap_or("");
+    let fs_file = unescape_mount_point(fields.next().unwrap_or(""));
+    let fs_vfstype = fields.next().unwrap_or("");
+    let fs_mntops = fields.next().unwrap_or("");
+    (fs_spec, fs_file, fs_vfstype, fs_mntops)
+}
+
+// Returns the same fields as `parse_mounts_line` from a `/proc/<pid>/mountinfo` line. The
+// options are the super block ones, which is where `discard` is.
+fn parse_mountinfo_line(line: &str) -> (&str, String, &str, &str) {
+    // mountinfo format
+    // https://man7.org/linux/man-pages/man5/proc_pid_mountinfo.5.html
+    // mount_id parent_id major:minor root mount_point mount_options [optional fields] - \
+    // fs_type source super_options
+    let (mount, fs) = line.split_once(" - ").unwrap_or((line, ""));
+    let fs_file = unescape_mount_point(mount.split_whitespace().nth(4).unwrap_or(""));
+    let mut fields = fs.split_whitespace();
+    let fs_vfstype = fields.next().unwrap_or("");
+    let fs_spec = fields.next().unwrap_or("");
+    let fs_mntops = fields.next().unwrap_or("");
+    (fs_spec, fs_file, fs_vfstype, fs_mntops)
+}
+
+// Returns the mount point, whether it's a bind mount and the propagation type of a
+// `/proc/<pid>/mountinfo` line.
+fn parse_mountinfo_details(line: &str) -> Option<(String, (bool, MountPropagation))> {
+    let (mount, _) = line.split_once(" - ")?;
+    let mut fields = mount.split_whitespace().skip(3);
+    // Only bind mounts have a `root` other than the root of their file system.
+    let is_bind_mount = fields.next()? != "/";
+    let mount_point = unescape_mount_point(fields.next()?);
+    // The remaining fields are the mount options and then the optional fields.
+    let (mut shared, mut slave, mut unbindable) = (false, false, false);
+    for field in fields.skip(1) {
+        if field.starts_with("shared:") {
+            shared = true;
+        } else if field.starts_with("master:") {
+            slave = true;
+        } else if field == "unbindable" {
+            unbindable = true;
+        }
+    }
+    let propagation = if shared {
+        MountPropagation::Shared
+    } else if slave {
+        MountPropagation::Slave
+    } else if unbindable {
+        MountPropagation::Unbindable
+    } else {
+        MountPropagation::Private
+    };
+    Some((mount_point, (is_bind_mount, propagation)))
+}
+
+fn get_all_list(
+    container: &mut Vec<Disk>,
+    content: &str,
+    format: MountsFormat,
+    refresh_kind: DiskRefreshKind,
+) {
     // The goal of this array is to list all removable devices (the ones whose name starts with
     // "usb-").
     let removable_entries = match fs::read_dir("/dev/disk/by-id/") {
@@ -355,29 +643,42 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
     };
 
     let procfs_disk_stats = disk_stats(&refresh_kind);
+    let root = match format {
+        MountsFormat::Mounts => None,
+        MountsFormat::MountInfo { ref root } => Some(root.as_path()),
+    };
+    // `/proc/mounts` doesn't tell if a mount is a bind mount nor its propagation type, so we get
+    // them from `/proc/self/mountinfo`. If a mount point is used more than once, the last mount
+    // is the visible one.
+    let mounts_details = match format {
+        MountsFormat::Mounts => get_all_utf8_data("/proc/self/mountinfo", 16_385)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(parse_mountinfo_details)
+            .collect::<HashMap<_, _>>(),
+        MountsFormat::MountInfo { .. } => HashMap::new(),
+    };
 
-    for (fs_spec, fs_file, fs_vfstype) in content
+    for mount in content
         .lines()
         .map(|line| {
             let line = line.trim_start();
-            // mounts format
-            // http://man7.org/linux/man-pages/man5/fstab.5.html
-            // fs_spec<tab>fs_file<tab>fs_vfstype<tab>other fields
-            let mut fields = line.split_whitespace();
-            let fs_spec = fields.next().unwrap_or("");
-            let fs_file = fields
-                .next()
-                .unwrap_or("")
-                .replace("\\134", "\\")
-                .replace("\\040", " ")
-                .replace("\\011", "\t")
-                .replace("\\012", "\n");
-            let fs_vfstype = fields.next().unwrap_or("");
-            (fs_spec, fs_file, fs_vfstype)
+            let (fs_spec, fs_file, fs_vfstype, fs_mntops) = match format {
+                MountsFormat::Mounts => parse_mounts_line(line),
+                MountsFormat::MountInfo { .. } => parse_mountinfo_line(line),
+            };
+            MountLine {
+                line,
+                fs_spec,
+                fs_file,
+                fs_vfstype,
+                fs_mntops,
+            }
         })
-        .filter(|(fs_spec, fs_file, fs_vfstype)| {
+        .filter(|mount| {
+            let fs_file = &mount.fs_file;
             // Check if fs_vfstype is one of our 'ignored' file systems.
-            let filtered = match *fs_vfstype {
+            let filtered = match mount.fs_vfstype {
                 "rootfs" | // https://www.kernel.org/doc/Documentation/filesystems/ramfs-rootfs-initramfs.txt
                 "sysfs" | // pseudo file system for kernel objects
                 "proc" |  // another pseudo file system
@@ -402,24 +703,31 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
                fs_file.starts_with("/sys") || // check if fs_file is an 'ignored' mount point
                fs_file.starts_with("/proc") ||
                (fs_file.starts_with("/run") && !fs_file.starts_with("/run/media")) ||
-               fs_spec.starts_with("sunrpc"))
+               mount.fs_spec.starts_with("sunrpc"))
         })
     {
-        let mount_point = Path::new(&fs_file);
+        let details = match format {
+            MountsFormat::Mounts => mounts_details.get(&mount.fs_file).copied(),
+            MountsFormat::MountInfo { .. } => {
+                parse_mountinfo_details(mount.line).map(|(_, details)| details)
+            }
+        };
+        let mount_point = Path::new(&mount.fs_file);
         if let Some(disk) = container.iter_mut().find(|d| {
             d.inner.mount_point == mount_point
-                && d.inner.device_name == fs_spec
-                && d.inner.file_system == fs_vfstype
+                && d.inner.device_name == mount.fs_spec
+                && d.inner.file_system == mount.fs_vfstype
         }) {
             disk.inner
                 .efficient_refresh(refresh_kind, &procfs_disk_stats, false);
             disk.inner.updated = true;
+            disk.inner.update_mount(&mount, details);
             continue;
         }
         container.push(new_disk(
-            fs_spec.as_ref(),
-            mount_point,
-            fs_vfstype.as_ref(),
+            &mount,
+            details,
+            root,
             &removable_entries,
             &procfs_disk_stats,
             refresh_kind,
@@ -427,6 +735,47 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
     }
 }
 
+// Returns `true` if the `discard` option is in the `fs_mntops` field of `/proc/mounts`, which can
+// also be written `discard=async` or `discard=sync` (for btrfs for example).
+fn is_trim_enabled(fs_mntops: &str) -> bool {
+    fs_mntops
+        .split(',')
+        .any(|option| option == "discard" || option.starts_with("discard="))
+}
+
+// Returns the `queue` folder of the given block device.
+fn find_queue(sys_class_block: &Path, actual_device_name: &str) -> Option<PathBuf> {
+    if actual_device_name.is_empty() {
+        return None;
+    }
+    let device = fs::canonicalize(sys_class_block.join(actual_device_name)).ok()?;
+    // Partitions don't have a `queue` folder, it's in the folder of their parent device.
+    [device.join("queue"), device.parent()?.join("queue")]
+        .into_iter()
+        .find(|path| path.is_dir())
+}
+
+// Returns `true` if the discard granularity of the given block device isn't 0.
+fn supports_trim(sys_class_block: &Path, actual_device_name: &str) -> Option<bool> {
+    let queue = find_queue(sys_class_block, actual_device_name)?;
+    let granularity = get_all_utf8_data(queue.join("discard_granularity"), 32).ok()?;
+    Some(u64::from_str(granularity.trim()).ok()? > 0)
+}
+
+// The `scheduler` file lists the available schedulers, the active one being between brackets
+// (like `mq-deadline kyber [bfq] none`).
+fn io_scheduler(sys_class_block: &Path, actual_device_name: &str) -> Option<String> {
+    let queue = find_queue(sys_class_block, actual_device_name)?;
+    let schedulers = get_all_utf8_data(queue.join("scheduler"), 256).ok()?;
+    let schedulers = schedulers.trim();
+    let active = schedulers
+        .split_whitespace()
+        .find_map(|scheduler| scheduler.strip_prefix('[')?.strip_suffix(']'))
+        // Devices without scheduler support only have `none`, without brackets.
+        .unwrap_or(schedulers);
+    (!active.is_empty()).then(|| active.to_owned())
+}
+
 /// Disk IO stat information from `/proc/diskstats` file.
 ///
 /// To fully understand these fields, please see the
@@ -455,8 +804,12 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
 /// Doc reference: https://www.kernel.org/doc/Documentation/iostats.txt
 #[derive(Debug, PartialEq)]
 struct DiskStat {
+    reads_completed: u64,
     sectors_read: u64,
+    read_time_ms: u64,
+    writes_completed: u64,
     sectors_written: u64,
+    write_time_ms: u64,
 }
 
 impl DiskStat {
@@ -465,15 +818,28 @@ impl DiskStat {
         let mut iter = line.split_whitespace();
         // 3rd field
         let name = iter.nth(2).map(ToString::to_string)?;
+        let mut next = |n| iter.nth(n).and_then(|v| u64::from_str(v).ok()).unwrap_or(0);
+        // 4th field
+        let reads_completed = next(0);
         // 6th field
-        let sectors_read = iter.nth(2).and_then(|v| u64::from_str(v).ok()).unwrap_or(0);
+        let sectors_read = next(1);
+        // 7th field
+        let read_time_ms = next(0);
+        // 8th field
+        let writes_completed = next(0);
         // 10th field
-        let sectors_written = iter.nth(3).and_then(|v| u64::from_str(v).ok()).unwrap_or(0);
+        let sectors_written = next(1);
+        // 11th field
+        let write_time_ms = next(0);
         Some((
             name,
             Self {
+                reads_completed,
                 sectors_read,
+                read_time_ms,
+                writes_completed,
                 sectors_written,
+                write_time_ms,
             },
         ))
     }
@@ -512,8 +878,14 @@ fn disk_stats_inner(content: &str) -> HashMap<String, DiskStat> {
 
 #[cfg(test)]
 mod test {
-    use super::{disk_stats_inner, DiskStat};
+    use super::{
+        average_latency, btrfs_used_space, disk_stats_inner, io_scheduler, is_trim_enabled,
+        parse_mountinfo_details, parse_mountinfo_line, parse_mounts_line, supports_trim, DiskStat,
+    };
+    use crate::MountPropagation;
     use std::collections::HashMap;
+    use std::fs;
+    use std::time::Duration;
 
     #[test]
     fn test_disk_stat_parsing() {
@@ -533,51 +905,79 @@ mod test {
             (
                 "nvme0n1".to_string(),
                 DiskStat {
+                    reads_completed: 571695,
                     sectors_read: 38943220,
+                    read_time_ms: 165643,
+                    writes_completed: 9824246,
                     sectors_written: 462375378,
+                    write_time_ms: 4140037,
                 },
             ),
             (
                 "nvme0n1p1".to_string(),
                 DiskStat {
+                    reads_completed: 240,
                     sectors_read: 15468,
+                    read_time_ms: 48,
+                    writes_completed: 2,
                     sectors_written: 2,
+                    write_time_ms: 0,
                 },
             ),
             (
                 "nvme0n1p2".to_string(),
                 DiskStat {
+                    reads_completed: 243,
                     sectors_read: 11626,
+                    read_time_ms: 26,
+                    writes_completed: 63,
                     sectors_written: 616,
+                    write_time_ms: 125,
                 },
             ),
             (
                 "nvme0n1p3".to_string(),
                 DiskStat {
+                    reads_completed: 571069,
                     sectors_read: 38910302,
+                    read_time_ms: 165547,
+                    writes_completed: 9824180,
                     sectors_written: 462374760,
+                    write_time_ms: 4139911,
                 },
             ),
             (
                 "dm-0".to_string(),
                 DiskStat {
+                    reads_completed: 670206,
                     sectors_read: 38909056,
+                    read_time_ms: 259490,
+                    writes_completed: 10900330,
                     sectors_written: 462374760,
+                    write_time_ms: 12906518,
                 },
             ),
             (
                 "zram0".to_string(),
                 DiskStat {
+                    reads_completed: 2382,
                     sectors_read: 20984,
+                    read_time_ms: 11,
+                    writes_completed: 260261,
                     sectors_written: 2082088,
+                    write_time_ms: 2063,
                 },
             ),
             // This one ensures that we read the correct fields.
             (
                 "bla".to_string(),
                 DiskStat {
+                    reads_completed: 4,
                     sectors_read: 6,
+                    read_time_ms: 7,
+                    writes_completed: 8,
                     sectors_written: 10,
+                    write_time_ms: 11,
                 },
             ),
         ]);
@@ -585,6 +985,14 @@ mod test {
         assert_eq!(data, expected_data);
     }
 
+    #[test]
+    fn test_average_latency() {
+        assert_eq!(average_latency(0, 0), None);
+        assert_eq!(average_latency(0, 10), None);
+        assert_eq!(average_latency(4, 10), Some(Duration::from_micros(2_500)));
+        assert_eq!(average_latency(3, 0), Some(Duration::ZERO));
+    }
+
     #[test]
     fn disk_entry_with_less_information() {
         let file_content = "\
@@ -597,19 +1005,178 @@ mod test {
             (
                 "autofs".to_string(),
                 DiskStat {
+                    reads_completed: 0,
                     sectors_read: 0,
+                    read_time_ms: 0,
+                    writes_completed: 0,
                     sectors_written: 0,
+                    write_time_ms: 0,
                 },
             ),
             (
                 "vfat".to_string(),
                 DiskStat {
+                    reads_completed: 0,
                     sectors_read: 0,
+                    read_time_ms: 0,
+                    writes_completed: 0,
                     sectors_written: 0,
+                    write_time_ms: 0,
                 },
             ),
         ]);
 
         assert_eq!(data, expected_data);
     }
+
+    #[test]
+    fn test_parse_mount_lines() {
+        assert_eq!(
+            parse_mounts_line("/dev/sda1 /mnt/my\\040disk ext4 rw,discard 0 0"),
+            ("/dev/sda1", "/mnt/my disk".to_owned(), "ext4", "rw,discard"),
+        );
+        assert_eq!(
+            parse_mountinfo_line(
+                "36 35 98:0 / /mnt/my\\040disk rw,noatime master:1 - ext4 /dev/sda1 rw,discard"
+            ),
+            ("/dev/sda1", "/mnt/my disk".to_owned(), "ext4", "rw,discard"),
+        );
+        // Without optional fields.
+        assert_eq!(
+            parse_mountinfo_line("22 1 0:21 / /proc rw,nosuid - proc proc rw"),
+            ("proc", "/proc".to_owned(), "proc", "rw"),
+        );
+    }
+
+    #[test]
+    fn test_parse_mountinfo_details() {
+        assert_eq!(
+            parse_mountinfo_details("29 1 259:2 / / rw,relatime shared:1 - ext4 /dev/nvme0n1p2 rw"),
+            Some(("/".to_owned(), (false, MountPropagation::Shared))),
+        );
+        assert_eq!(
+            parse_mountinfo_details(
+                "612 29 259:2 /home/user/my\\040dir /mnt/data rw master:1 - ext4 /dev/nvme0n1p2 rw"
+            ),
+            Some(("/mnt/data".to_owned(), (true, MountPropagation::Slave))),
+        );
+        assert_eq!(
+            parse_mountinfo_details(
+                "613 29 0:52 / /mnt/a\\040b rw shared:5 master:1 - tmpfs tmpfs rw"
+            ),
+            Some(("/mnt/a b".to_owned(), (false, MountPropagation::Shared))),
+        );
+        assert_eq!(
+            parse_mountinfo_details("614 29 0:53 / /mnt/c rw,nosuid unbindable - tmpfs tmpfs rw"),
+            Some(("/mnt/c".to_owned(), (false, MountPropagation::Unbindable))),
+        );
+        assert_eq!(
+            parse_mountinfo_details("615 29 0:54 / /mnt/d rw - tmpfs tmpfs rw"),
+            Some(("/mnt/d".to_owned(), (false, MountPropagation::Private))),
+        );
+        assert_eq!(parse_mountinfo_details("invalid"), None);
+    }
+
+    #[test]
+    fn test_is_trim_enabled() {
+        assert!(is_trim_enabled("rw,relatime,discard"));
+        assert!(is_trim_enabled(
+            "rw,noatime,ssd,discard=async,space_cache=v2"
+        ));
+        assert!(!is_trim_enabled("rw,relatime,nodiscard"));
+        assert!(!is_trim_enabled("rw,relatime"));
+        assert!(!is_trim_enabled(""));
+    }
+
+    #[test]
+    fn test_supports_trim() {
+        let sys = tempfile::tempdir().expect("failed to create temporary directory");
+        let devices = sys.path().join("devices");
+        for (device, granularity) in [("nvme0n1", "512\n"), ("sda", "0\n")] {
+            fs::create_dir_all(devices.join(device).join("queue")).unwrap();
+            fs::write(
+                devices.join(device).join("queue/discard_granularity"),
+                granularity,
+            )
+            .unwrap();
+        }
+        fs::create_dir_all(devices.join("nvme0n1/nvme0n1p1")).unwrap();
+        let class_block = sys.path().join("class/block");
+        fs::create_dir_all(&class_block).unwrap();
+        for (name, target) in [
+            ("nvme0n1", "nvme0n1"),
+            ("nvme0n1p1", "nvme0n1/nvme0n1p1"),
+            ("sda", "sda"),
+        ] {
+            std::os::unix::fs::symlink(devices.join(target), class_block.join(name)).unwrap();
+        }
+
+        assert_eq!(supports_trim(&class_block, "nvme0n1"), Some(true));
+        assert_eq!(supports_trim(&class_block, "nvme0n1p1"), Some(true));
+        assert_eq!(supports_trim(&class_block, "sda"), Some(false));
+        assert_eq!(supports_trim(&class_block, "sdb"), None);
+        assert_eq!(supports_trim(&class_block, ""), None);
+    }
+
+    #[test]
+    fn test_io_scheduler() {
+        let sys = tempfile::tempdir().expect("failed to create temporary directory");
+        let devices = sys.path().join("devices");
+        for (device, scheduler) in [
+            ("nvme0n1", "[none] mq-deadline kyber bfq\n"),
+            ("sda", "mq-deadline kyber [bfq] none\n"),
+            ("loop0", "none\n"),
+        ] {
+            fs::create_dir_all(devices.join(device).join("queue")).unwrap();
+            fs::write(devices.join(device).join("queue/scheduler"), scheduler).unwrap();
+        }
+        fs::create_dir_all(devices.join("sda/sda1")).unwrap();
+        let class_block = sys.path().join("class/block");
+        fs::create_dir_all(&class_block).unwrap();
+        for (name, target) in [
+            ("nvme0n1", "nvme0n1"),
+            ("sda", "sda"),
+            ("sda1", "sda/sda1"),
+            ("loop0", "loop0"),
+        ] {
+            std::os::unix::fs::symlink(devices.join(target), class_block.join(name)).unwrap();
+        }
+
+        assert_eq!(
+            io_scheduler(&class_block, "nvme0n1").as_deref(),
+            Some("none")
+        );
+        assert_eq!(io_scheduler(&class_block, "sda").as_deref(), Some("bfq"));
+        assert_eq!(io_scheduler(&class_block, "sda1").as_deref(), Some("bfq"));
+        assert_eq!(io_scheduler(&class_block, "loop0").as_deref(), Some("none"));
+        assert_eq!(io_scheduler(&class_block, "sdb"), None);
+    }
+
+    #[test]
+    fn test_btrfs_used_space() {
+        let sys_fs_btrfs = tempfile::tempdir().expect("failed to create temporary directory");
+        let fs_path = sys_fs_btrfs.path().join("0a1b2c3d-uuid");
+        fs::create_dir_all(fs_path.join("devices/sda2")).unwrap();
+        // A mirrored filesystem: data and metadata are stored twice.
+        for (kind, bytes_used, disk_used) in [
+            ("data", "1000\n", "2000\n"),
+            ("metadata", "100\n", "200\n"),
+            ("system", "10\n", "20\n"),
+        ] {
+            let path = fs_path.join("allocation").join(kind);
+            fs::create_dir_all(&path).unwrap();
+            fs::write(path.join("bytes_used"), bytes_used).unwrap();
+            fs::write(path.join("disk_used"), disk_used).unwrap();
+        }
+
+        assert_eq!(
+            btrfs_used_space(sys_fs_btrfs.path(), "sda2"),
+            Some((1110, 2220))
+        );
+        assert_eq!(btrfs_used_space(sys_fs_btrfs.path(), "sdb1"), None);
+
+        // Kernels without `disk_used`.
+        fs::remove_file(fs_path.join("allocation/data/disk_used")).unwrap();
+        assert_eq!(btrfs_used_space(sys_fs_btrfs.path(), "sda2"), None);
+    }
 }
@@ -0,0 +1,155 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_all_utf8_data;
+use crate::Gpu;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(crate) struct GpuInner {
+    name: String,
+    // The `/sys/class/drm/<name>/device` folder.
+    device_path: PathBuf,
+    vram_total: Option<u64>,
+    vram_used: Option<u64>,
+    utilization: Option<f32>,
+    pub(crate) updated: bool,
+}
+
+fn read_number<T: std::str::FromStr>(path: &Path) -> Option<T> {
+    get_all_utf8_data(path, 32).ok()?.trim().parse().ok()
+}
+
+impl GpuInner {
+    fn new(name: String, device_path: PathBuf) -> Self {
+        let mut gpu = Self {
+            name,
+            device_path,
+            vram_total: None,
+            vram_used: None,
+            utilization: None,
+            updated: true,
+        };
+        gpu.refresh();
+        gpu
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn vram_total(&self) -> Option<u64> {
+        self.vram_total
+    }
+
+    pub(crate) fn vram_used(&self) -> Option<u64> {
+        self.vram_used
+    }
+
+    pub(crate) fn utilization(&self) -> Option<f32> {
+        self.utilization
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.vram_total = read_number(&self.device_path.join("mem_info_vram_total"));
+        self.vram_used = read_number(&self.device_path.join("mem_info_vram_used"));
+        self.utilization = read_number(&self.device_path.join("gpu_busy_percent"));
+    }
+}
+
+pub(crate) struct GpusInner {
+    pub(crate) gpus: Vec<Gpu>,
+}
+
+impl GpusInner {
+    pub(crate) fn new() -> Self {
+        Self { gpus: Vec::new() }
+    }
+
+    pub(crate) fn from_vec(gpus: Vec<Gpu>) -> Self {
+        Self { gpus }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Gpu> {
+        self.gpus
+    }
+
+    pub(crate) fn list(&self) -> &[Gpu] {
+        &self.gpus
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Gpu] {
+        &mut self.gpus
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.refresh_from(Path::new("/sys/class/drm"));
+    }
+
+    fn refresh_from(&mut self, drm_path: &Path) {
+        let Ok(entries) = fs::read_dir(drm_path) else {
+            return;
+        };
+        let mut names = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            // Connectors (like `card0-DP-1`) are listed alongside the cards.
+            .filter(|name| {
+                name.strip_prefix("card")
+                    .is_some_and(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            if let Some(gpu) = self.gpus.iter_mut().find(|gpu| gpu.inner.name == name) {
+                gpu.inner.refresh();
+                gpu.inner.updated = true;
+                continue;
+            }
+            let device_path = drm_path.join(&name).join("device");
+            self.gpus.push(Gpu {
+                inner: GpuInner::new(name, device_path),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GpusInner;
+    use std::fs;
+
+    #[test]
+    fn test_refresh_gpus() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let amdgpu = dir.path().join("card1/device");
+        fs::create_dir_all(&amdgpu).unwrap();
+        fs::write(amdgpu.join("mem_info_vram_total"), "8573157376\n").unwrap();
+        fs::write(amdgpu.join("mem_info_vram_used"), "1073741824\n").unwrap();
+        fs::write(amdgpu.join("gpu_busy_percent"), "42\n").unwrap();
+        // A GPU without these files (like with the `i915` driver).
+        fs::create_dir_all(dir.path().join("card0/device")).unwrap();
+        fs::create_dir_all(dir.path().join("card0-DP-1")).unwrap();
+        fs::create_dir_all(dir.path().join("renderD128")).unwrap();
+
+        let mut gpus = GpusInner::new();
+        gpus.refresh_from(dir.path());
+        let list = gpus.list();
+        assert_eq!(list.len(), 2);
+
+        assert_eq!(list[0].name(), "card0");
+        assert_eq!(list[0].vram_total(), None);
+        assert_eq!(list[0].vram_used(), None);
+        assert_eq!(list[0].utilization(), None);
+
+        assert_eq!(list[1].name(), "card1");
+        assert_eq!(list[1].vram_total(), Some(8_573_157_376));
+        assert_eq!(list[1].vram_used(), Some(1_073_741_824));
+        assert_eq!(list[1].utilization(), Some(42.));
+
+        // Existing GPUs are updated in place.
+        fs::write(amdgpu.join("gpu_busy_percent"), "7\n").unwrap();
+        gpus.refresh_from(dir.path());
+        assert_eq!(gpus.list().len(), 2);
+        assert_eq!(gpus.list()[1].utilization(), Some(7.));
+    }
+}
@@ -2,11 +2,20 @@
 
 pub(crate) mod utils;
 
+#[cfg(feature = "linux-replay")]
+pub(crate) mod replay;
+
+#[cfg(feature = "linux-proc-events")]
+pub(crate) mod process_events;
+#[cfg(feature = "linux-proc-events")]
+pub(crate) use self::process_events::ProcessEventListenerInner;
+
 cfg_if! {
     if #[cfg(feature = "system")] {
         pub mod cpu;
         pub mod process;
         pub mod system;
+        pub(crate) mod taskstats;
 
         pub(crate) use self::cpu::CpuInner;
         pub(crate) use self::process::ProcessInner;
@@ -26,6 +35,12 @@ cfg_if! {
         pub(crate) use self::component::{ComponentInner, ComponentsInner};
     }
 
+    if #[cfg(feature = "gpu")] {
+        pub mod gpu;
+
+        pub(crate) use self::gpu::{GpuInner, GpusInner};
+    }
+
     if #[cfg(feature = "network")] {
         pub mod network;
 
@@ -34,7 +49,7 @@ cfg_if! {
 
     if #[cfg(feature = "user")] {
         pub(crate) use crate::unix::groups::get_groups;
-        pub(crate) use crate::unix::users::{get_users, UserInner};
+        pub(crate) use crate::unix::users::{get_user_name, get_users, UserInner};
     }
 }
 
@@ -49,8 +64,16 @@ mod cpu;
 #[cfg(any())]
 mod disk;
 #[cfg(any())]
+mod gpu;
+#[cfg(any())]
 mod network;
 #[cfg(any())]
 mod process;
 #[cfg(any())]
+mod process_events;
+#[cfg(any())]
+mod replay;
+#[cfg(any())]
 mod system;
+#[cfg(any())]
+mod taskstats;
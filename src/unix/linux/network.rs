@@ -1,12 +1,16 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::collections::{hash_map, HashMap};
-use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
 use std::path::Path;
+use std::time::Duration;
 
+use crate::common::network::RefreshTimer;
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::sys::utils::{open_file, read_dir_paths};
+use crate::{Duplex, IpNetwork, MacAddr, NetworkCounters, NetworkData, WolConfig, WolMode};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident) => {{
@@ -22,40 +26,350 @@ macro_rules! old_and_new {
 
 #[allow(clippy::ptr_arg)]
 fn read<P: AsRef<Path>>(parent: P, path: &str, data: &mut Vec<u8>) -> u64 {
-    if let Ok(mut f) = File::open(parent.as_ref().join(path)) {
-        if let Ok(size) = f.read(data) {
-            let mut i = 0;
-            let mut ret = 0;
-
-            while i < size && i < data.len() && data[i] >= b'0' && data[i] <= b'9' {
-                ret *= 10;
-                ret += (data[i] - b'0') as u64;
-                i += 1;
+    read_optional(parent, path, data).unwrap_or(0)
+}
+
+// Same as `read` but returns `None` if the file couldn't be read.
+#[allow(clippy::ptr_arg)]
+fn read_optional<P: AsRef<Path>>(parent: P, path: &str, data: &mut Vec<u8>) -> Option<u64> {
+    let mut f = open_file(parent.as_ref().join(path)).ok()?;
+    let size = f.read(data).ok()?;
+    let mut i = 0;
+    let mut ret = 0;
+
+    while i < size && i < data.len() && data[i] >= b'0' && data[i] <= b'9' {
+        ret *= 10;
+        ret += (data[i] - b'0') as u64;
+        i += 1;
+    }
+    Some(ret)
+}
+
+// Values from `linux/netlink.h` and `linux/if_link.h`.
+const NLMSG_HDR_LEN: usize = 16;
+const IFINFOMSG_LEN: usize = 16;
+const NLMSG_ERROR: u16 = 2;
+const NLMSG_DONE: u16 = 3;
+const RTM_GETLINK: u16 = 18;
+const RTM_NEWLINK: u16 = 16;
+const IFLA_IFNAME: u16 = 3;
+const IFLA_MAX_MTU: u16 = 51;
+
+fn align4(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_ne_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Parses the `RTM_NEWLINK` messages of a `RTM_GETLINK` dump answer and fills `max_mtus` with
+/// the `IFLA_MAX_MTU` attribute of each interface. Returns `true` if the end of the dump was
+/// reached.
+fn parse_link_messages(mut buffer: &[u8], max_mtus: &mut HashMap<String, u64>) -> bool {
+    while let (Some(len), Some(kind)) = (read_u32(buffer, 0), read_u16(buffer, 4)) {
+        let len = len as usize;
+        let Some(message) = buffer.get(..len).filter(|_| len >= NLMSG_HDR_LEN) else {
+            break;
+        };
+        match kind {
+            NLMSG_DONE | NLMSG_ERROR => return true,
+            RTM_NEWLINK => {
+                let mut attributes = message.get(NLMSG_HDR_LEN + IFINFOMSG_LEN..).unwrap_or(&[]);
+                let mut name = None;
+                let mut max_mtu = None;
+                while let (Some(attr_len), Some(attr_kind)) =
+                    (read_u16(attributes, 0), read_u16(attributes, 2))
+                {
+                    let attr_len = attr_len as usize;
+                    let Some(payload) = attributes.get(4..attr_len) else {
+                        break;
+                    };
+                    match attr_kind {
+                        IFLA_IFNAME => {
+                            let payload = payload.split(|b| *b == 0).next().unwrap_or(payload);
+                            name = std::str::from_utf8(payload).ok();
+                        }
+                        IFLA_MAX_MTU => max_mtu = read_u32(payload, 0),
+                        _ => {}
+                    }
+                    attributes = attributes.get(align4(attr_len)..).unwrap_or(&[]);
+                }
+                // `0` means that the driver doesn't set a maximum.
+                if let (Some(name), Some(max_mtu @ 1..)) = (name, max_mtu) {
+                    max_mtus.insert(name.to_owned(), max_mtu as u64);
+                }
             }
-            return ret;
+            _ => {}
         }
+        buffer = buffer.get(align4(len)..).unwrap_or(&[]);
+    }
+    false
+}
+
+/// Retrieves the maximum MTU supported by each interface using a `RTM_GETLINK` netlink request.
+fn get_max_mtus() -> io::Result<HashMap<String, u64>> {
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+            libc::NETLINK_ROUTE,
+        )
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // Closes the socket when dropped.
+    let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+    let fd = socket.as_raw_fd();
+
+    let mut request = [0u8; NLMSG_HDR_LEN + IFINFOMSG_LEN];
+    request[0..4].copy_from_slice(&((NLMSG_HDR_LEN + IFINFOMSG_LEN) as u32).to_ne_bytes());
+    request[4..6].copy_from_slice(&RTM_GETLINK.to_ne_bytes());
+    request[6..8].copy_from_slice(&((libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16).to_ne_bytes());
+    request[8..12].copy_from_slice(&1u32.to_ne_bytes());
+    request[16] = libc::AF_UNSPEC as u8;
+    if unsafe { libc::send(fd, request.as_ptr() as *const _, request.len(), 0) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut max_mtus = HashMap::new();
+    let mut buffer = vec![0u8; 32 * 1024];
+    loop {
+        let ret = unsafe { libc::recv(fd, buffer.as_mut_ptr() as *mut _, buffer.len(), 0) };
+        if ret < 0 {
+            let error = io::Error::last_os_error();
+            if error.kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(error);
+        }
+        if ret == 0 || parse_link_messages(&buffer[..ret as usize], &mut max_mtus) {
+            return Ok(max_mtus);
+        }
+    }
+}
+
+// The `duplex` file can't be read if the link is down.
+fn read_duplex(entry_path: &Path) -> Option<Duplex> {
+    let mut duplex = String::new();
+    open_file(entry_path.join("duplex"))
+        .ok()?
+        .read_to_string(&mut duplex)
+        .ok()?;
+    match duplex.trim() {
+        "full" => Some(Duplex::Full),
+        "half" => Some(Duplex::Half),
+        _ => None,
+    }
+}
+
+// Values and structures from `linux/sockios.h` and `linux/ethtool.h`.
+const SIOCETHTOOL: libc::c_ulong = 0x8946;
+const ETHTOOL_GSET: u32 = 0x0000_0001;
+const ETHTOOL_GWOL: u32 = 0x0000_0005;
+const AUTONEG_ENABLE: u8 = 0x01;
+const WOL_MODES: [(u32, WolMode); 8] = [
+    (1 << 0, WolMode::Phy),
+    (1 << 1, WolMode::Unicast),
+    (1 << 2, WolMode::Multicast),
+    (1 << 3, WolMode::Broadcast),
+    (1 << 4, WolMode::Arp),
+    (1 << 5, WolMode::MagicPacket),
+    (1 << 6, WolMode::SecureOn),
+    (1 << 7, WolMode::Filter),
+];
+
+#[repr(C)]
+#[derive(Default)]
+struct EthtoolCmd {
+    cmd: u32,
+    supported: u32,
+    advertising: u32,
+    speed: u16,
+    duplex: u8,
+    port: u8,
+    phy_address: u8,
+    transceiver: u8,
+    autoneg: u8,
+    mdio_support: u8,
+    maxtxpkt: u32,
+    maxrxpkt: u32,
+    speed_hi: u16,
+    eth_tp_mdix: u8,
+    eth_tp_mdix_ctrl: u8,
+    lp_advertising: u32,
+    reserved: [u32; 2],
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct EthtoolWolInfo {
+    cmd: u32,
+    supported: u32,
+    wolopts: u32,
+    sopass: [u8; 6],
+}
+
+// `ifreq` with the `ifr_data` field of its union. It's bigger than `ifreq` on 32-bit targets,
+// which isn't an issue since the kernel only reads the beginning.
+#[repr(C)]
+struct IfReqData {
+    name: [libc::c_char; libc::IFNAMSIZ],
+    data: *mut libc::c_void,
+    _padding: [u8; 16],
+}
+
+/// Returns a socket which can be used to send ethtool commands.
+fn ethtool_socket() -> Option<OwnedFd> {
+    let fd = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0) };
+    // Closes the socket when dropped.
+    (fd >= 0).then(|| unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+/// Sends the ethtool command `data` (starting with its `cmd` field) for the interface, using
+/// the socket `fd`. Returns `false` if it failed.
+fn ethtool_ioctl<T>(fd: libc::c_int, interface_name: &str, data: &mut T) -> bool {
+    if interface_name.len() >= libc::IFNAMSIZ {
+        return false;
+    }
+    let mut request = IfReqData {
+        name: [0; libc::IFNAMSIZ],
+        data: data as *mut T as *mut libc::c_void,
+        _padding: [0; 16],
+    };
+    for (dest, src) in request.name.iter_mut().zip(interface_name.bytes()) {
+        *dest = src as libc::c_char;
     }
-    0
+    // Virtual interfaces (like `lo`) don't support most commands and return `EOPNOTSUPP`.
+    unsafe { libc::ioctl(fd, SIOCETHTOOL as _, &mut request as *mut IfReqData) >= 0 }
 }
 
+/// Returns `true` if autonegotiation is enabled on the interface, using the socket `fd`.
+fn read_autonegotiation(fd: libc::c_int, interface_name: &str) -> Option<bool> {
+    let mut cmd = EthtoolCmd {
+        cmd: ETHTOOL_GSET,
+        ..Default::default()
+    };
+    if !ethtool_ioctl(fd, interface_name, &mut cmd) {
+        return None;
+    }
+    Some(cmd.autoneg == AUTONEG_ENABLE)
+}
+
+fn wol_modes(flags: u32) -> Vec<WolMode> {
+    WOL_MODES
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, mode)| *mode)
+        .collect()
+}
+
+/// Returns the Wake-on-LAN configuration of the interface, using the socket `fd`.
+fn read_wake_on_lan(fd: libc::c_int, interface_name: &str) -> Option<WolConfig> {
+    let mut wol = EthtoolWolInfo {
+        cmd: ETHTOOL_GWOL,
+        ..Default::default()
+    };
+    if !ethtool_ioctl(fd, interface_name, &mut wol) || wol.supported == 0 {
+        return None;
+    }
+    Some(WolConfig {
+        supported: wol_modes(wol.supported),
+        enabled: wol_modes(wol.wolopts),
+    })
+}
+
+// Parses `/proc/net/vlan/config` and returns the VLAN ID and the parent interface of each VLAN
+// interface.
+fn parse_vlan_config(data: &str) -> HashMap<String, (u16, String)> {
+    data.lines()
+        // The first two lines are a header.
+        .skip(2)
+        .filter_map(|line| {
+            let mut fields = line.split('|').map(str::trim);
+            let name = fields.next()?;
+            let vlan_id = fields.next()?.parse().ok()?;
+            let parent = fields.next()?;
+            Some((name.to_owned(), (vlan_id, parent.to_owned())))
+        })
+        .collect()
+}
+
+// Value of the `st` column of `/proc/net/tcp` for established connections.
+const TCP_ESTABLISHED: &str = "01";
+
+// Parses an address of `/proc/net/tcp{,6}`, which is made of 32-bit words printed in
+// hexadecimal, in host byte order.
+fn parse_proc_net_address(hex: &str) -> Option<IpAddr> {
+    let mut bytes = [0; 16];
+    let words = hex.len() / 8;
+    if hex.len() % 8 != 0 || (words != 1 && words != 4) {
+        return None;
+    }
+    for index in 0..words {
+        let word = u32::from_str_radix(hex.get(index * 8..index * 8 + 8)?, 16).ok()?;
+        bytes[index * 4..index * 4 + 4].copy_from_slice(&word.to_ne_bytes());
+    }
+    if words == 1 {
+        return Some(IpAddr::V4(Ipv4Addr::new(
+            bytes[0], bytes[1], bytes[2], bytes[3],
+        )));
+    }
+    let addr = Ipv6Addr::from(bytes);
+    // IPv4 connections on IPv6 sockets.
+    Some(match addr.to_ipv4_mapped() {
+        Some(addr) => IpAddr::V4(addr),
+        None => IpAddr::V6(addr),
+    })
+}
+
+// Adds the local addresses of the established connections of a `/proc/net/tcp{,6}` file into
+// `addresses`.
+fn parse_established_local_addresses(data: &str, addresses: &mut Vec<IpAddr>) {
+    // The first line is a header.
+    for line in data.lines().skip(1) {
+        let mut fields = line.split_whitespace().skip(1);
+        let (Some(local), Some(state)) = (fields.next(), fields.nth(1)) else {
+            continue;
+        };
+        if state != TCP_ESTABLISHED {
+            continue;
+        }
+        if let Some(addr) = local
+            .split_once(':')
+            .and_then(|(addr, _)| parse_proc_net_address(addr))
+        {
+            addresses.push(addr);
+        }
+    }
+}
+
+/// Returns `true` if interfaces were added or removed.
 fn refresh_networks_list_from_sysfs(
     interfaces: &mut HashMap<String, NetworkData>,
     remove_not_listed_interfaces: bool,
     sysfs_net: &Path,
-) {
-    if let Ok(dir) = std::fs::read_dir(sysfs_net) {
+) -> bool {
+    let mut list_changed = false;
+    if let Ok(entries) = read_dir_paths(sysfs_net) {
         let mut data = vec![0; 30];
 
         for stats in interfaces.values_mut() {
             stats.inner.updated = false;
         }
 
-        for entry in dir.flatten() {
-            let parent = &entry.path().join("statistics");
-            let entry_path = &entry.path();
-            let entry = match entry.file_name().into_string() {
-                Ok(entry) => entry,
-                Err(_) => continue,
+        for entry_path in &entries {
+            let parent = &entry_path.join("statistics");
+            let entry = match entry_path.file_name().and_then(|name| name.to_str()) {
+                Some(entry) => entry.to_owned(),
+                None => continue,
             };
             let rx_bytes = read(parent, "rx_bytes", &mut data);
             let tx_bytes = read(parent, "tx_bytes", &mut data);
@@ -63,9 +377,13 @@ fn refresh_networks_list_from_sysfs(
             let tx_packets = read(parent, "tx_packets", &mut data);
             let rx_errors = read(parent, "rx_errors", &mut data);
             let tx_errors = read(parent, "tx_errors", &mut data);
+            let rx_dropped = read(parent, "rx_dropped", &mut data);
+            let tx_dropped = read(parent, "tx_dropped", &mut data);
             // let rx_compressed = read(parent, "rx_compressed", &mut data);
             // let tx_compressed = read(parent, "tx_compressed", &mut data);
             let mtu = read(entry_path, "mtu", &mut data);
+            let carrier_changes = read_optional(entry_path, "carrier_changes", &mut data);
+            let duplex = read_duplex(entry_path);
 
             match interfaces.entry(entry) {
                 hash_map::Entry::Occupied(mut e) => {
@@ -78,16 +396,24 @@ fn refresh_networks_list_from_sysfs(
                     old_and_new!(interface, tx_packets, old_tx_packets);
                     old_and_new!(interface, rx_errors, old_rx_errors);
                     old_and_new!(interface, tx_errors, old_tx_errors);
+                    old_and_new!(interface, rx_dropped, old_rx_dropped);
+                    old_and_new!(interface, tx_dropped, old_tx_dropped);
                     // old_and_new!(e, rx_compressed, old_rx_compressed);
                     // old_and_new!(e, tx_compressed, old_tx_compressed);
                     if interface.mtu != mtu {
                         interface.mtu = mtu;
                     }
+                    interface.carrier_changes = carrier_changes;
+                    interface.duplex = duplex;
+                    interface.refresh_timer.update();
                     interface.updated = true;
                 }
                 hash_map::Entry::Vacant(e) => {
+                    list_changed = true;
+                    let name = e.key().clone();
                     e.insert(NetworkData {
                         inner: NetworkDataInner {
+                            name,
                             rx_bytes,
                             old_rx_bytes: rx_bytes,
                             tx_bytes,
@@ -100,13 +426,23 @@ fn refresh_networks_list_from_sysfs(
                             old_rx_errors: rx_errors,
                             tx_errors,
                             old_tx_errors: tx_errors,
+                            rx_dropped,
+                            old_rx_dropped: rx_dropped,
+                            tx_dropped,
+                            old_tx_dropped: tx_dropped,
                             mac_addr: MacAddr::UNSPECIFIED,
                             ip_networks: vec![],
+                            addresses_changed: false,
                             // rx_compressed,
                             // old_rx_compressed: rx_compressed,
                             // tx_compressed,
                             // old_tx_compressed: tx_compressed,
                             mtu,
+                            max_mtu: None,
+                            carrier_changes,
+                            duplex,
+                            vlan: None,
+                            refresh_timer: RefreshTimer::new(),
                             updated: true,
                         },
                     });
@@ -118,14 +454,11 @@ fn refresh_networks_list_from_sysfs(
     // this is working as expected.
     if remove_not_listed_interfaces {
         // Remove interfaces which are gone.
-        interfaces.retain(|_, i| {
-            if !i.inner.updated {
-                return false;
-            }
-            i.inner.updated = false;
-            true
-        });
+        let nb_interfaces = interfaces.len();
+        interfaces.retain(|_, i| i.inner.updated);
+        list_changed |= interfaces.len() != nb_interfaces;
     }
+    list_changed
 }
 
 pub(crate) struct NetworksInner {
@@ -143,13 +476,48 @@ impl NetworksInner {
         &self.interfaces
     }
 
+    pub(crate) fn established_connections_local_addresses(&self) -> Vec<IpAddr> {
+        let mut addresses = Vec::new();
+        for path in ["/proc/net/tcp", "/proc/net/tcp6"] {
+            let mut data = String::new();
+            if open_file(path)
+                .and_then(|mut f| f.read_to_string(&mut data))
+                .is_ok()
+            {
+                parse_established_local_addresses(&data, &mut addresses);
+            }
+        }
+        addresses
+    }
+
     pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
-        refresh_networks_list_from_sysfs(
+        let list_changed = refresh_networks_list_from_sysfs(
             &mut self.interfaces,
             remove_not_listed_interfaces,
             Path::new("/sys/class/net/"),
         );
         refresh_networks_addresses(&mut self.interfaces);
+        // The maximum MTU of an interface doesn't change and a VLAN can't be moved to another
+        // parent or change its ID, so we only retrieve them when interfaces are added.
+        if list_changed {
+            // If the netlink request failed, we keep the previous values.
+            if let Ok(max_mtus) = get_max_mtus() {
+                for (name, interface) in self.interfaces.iter_mut() {
+                    interface.inner.max_mtu = max_mtus.get(name).copied();
+                }
+            }
+            // This file only exists if the `8021q` module (needed for VLANs) is loaded.
+            let mut data = String::new();
+            let vlans = match open_file("/proc/net/vlan/config")
+                .and_then(|mut f| f.read_to_string(&mut data))
+            {
+                Ok(_) => parse_vlan_config(&data),
+                Err(_) => HashMap::new(),
+            };
+            for (name, interface) in self.interfaces.iter_mut() {
+                interface.inner.vlan = vlans.get(name).cloned();
+            }
+        }
     }
 }
 
@@ -174,11 +542,30 @@ pub(crate) struct NetworkDataInner {
     /// similar to `rx_errors`
     tx_errors: u64,
     old_tx_errors: u64,
+    /// Total number of packets received which were dropped (because of a lack of resources
+    /// for example).
+    rx_dropped: u64,
+    old_rx_dropped: u64,
+    /// similar to `rx_dropped`
+    tx_dropped: u64,
+    old_tx_dropped: u64,
+    /// Name of the interface.
+    name: String,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
+    /// Whether the IP networks changed during the last refresh.
+    pub(crate) addresses_changed: bool,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    /// Maximum MTU supported by the interface.
+    max_mtu: Option<u64>,
+    /// Number of times the link went up or down.
+    carrier_changes: Option<u64>,
+    /// Duplex mode of the link.
+    duplex: Option<Duplex>,
+    /// VLAN ID and parent interface if this is a VLAN interface.
+    vlan: Option<(u16, String)>,
     // /// Indicates the number of compressed packets received by this
     // /// network device. This value might only be relevant for interfaces
     // /// that support packet compression (e.g: PPP).
@@ -189,11 +576,37 @@ pub(crate) struct NetworkDataInner {
     // /// compression (e.g: PPP).
     // tx_compressed: usize,
     // old_tx_compressed: usize,
+    /// Time elapsed between the two last refreshes.
+    refresh_timer: RefreshTimer,
     /// Whether or not the above data has been updated during refresh
     updated: bool,
 }
 
 impl NetworkDataInner {
+    pub(crate) fn restore_counters(&mut self, counters: &NetworkCounters) {
+        self.old_rx_bytes = counters.received;
+        self.rx_bytes = counters.received;
+        self.old_tx_bytes = counters.transmitted;
+        self.tx_bytes = counters.transmitted;
+        self.old_rx_packets = counters.packets_received;
+        self.rx_packets = counters.packets_received;
+        self.old_tx_packets = counters.packets_transmitted;
+        self.tx_packets = counters.packets_transmitted;
+        self.old_rx_errors = counters.errors_on_received;
+        self.rx_errors = counters.errors_on_received;
+        self.old_tx_errors = counters.errors_on_transmitted;
+        self.tx_errors = counters.errors_on_transmitted;
+        self.old_rx_dropped = counters.packets_dropped_on_received;
+        self.rx_dropped = counters.packets_dropped_on_received;
+        self.old_tx_dropped = counters.packets_dropped_on_transmitted;
+        self.tx_dropped = counters.packets_dropped_on_transmitted;
+        self.refresh_timer.restore(counters.last_refresh);
+    }
+
+    pub(crate) fn last_refresh(&self) -> Duration {
+        self.refresh_timer.last_refresh()
+    }
+
     pub(crate) fn received(&self) -> u64 {
         self.rx_bytes.saturating_sub(self.old_rx_bytes)
     }
@@ -242,6 +655,22 @@ impl NetworkDataInner {
         self.tx_errors
     }
 
+    pub(crate) fn packets_dropped_on_received(&self) -> u64 {
+        self.rx_dropped.saturating_sub(self.old_rx_dropped)
+    }
+
+    pub(crate) fn total_packets_dropped_on_received(&self) -> u64 {
+        self.rx_dropped
+    }
+
+    pub(crate) fn packets_dropped_on_transmitted(&self) -> u64 {
+        self.tx_dropped.saturating_sub(self.old_tx_dropped)
+    }
+
+    pub(crate) fn total_packets_dropped_on_transmitted(&self) -> u64 {
+        self.tx_dropped
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -250,17 +679,135 @@ impl NetworkDataInner {
         &self.ip_networks
     }
 
+    pub(crate) fn addresses_changed(&self) -> bool {
+        self.addresses_changed
+    }
+
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn max_mtu(&self) -> Option<u64> {
+        self.max_mtu
+    }
+
+    pub(crate) fn carrier_changes(&self) -> Option<u64> {
+        self.carrier_changes
+    }
+
+    pub(crate) fn duplex(&self) -> Option<Duplex> {
+        self.duplex
+    }
+
+    pub(crate) fn autonegotiation(&self) -> Option<bool> {
+        read_autonegotiation(ethtool_socket()?.as_raw_fd(), &self.name)
+    }
+
+    pub(crate) fn vlan_id(&self) -> Option<u16> {
+        self.vlan.as_ref().map(|(vlan_id, _)| *vlan_id)
+    }
+
+    pub(crate) fn parent_interface(&self) -> Option<String> {
+        self.vlan.as_ref().map(|(_, parent)| parent.clone())
+    }
+
+    pub(crate) fn wake_on_lan(&self) -> Option<WolConfig> {
+        read_wake_on_lan(ethtool_socket()?.as_raw_fd(), &self.name)
+    }
+
+    pub(crate) fn is_updated(&self) -> bool {
+        self.updated
+    }
+
+    pub(crate) fn elapsed_since_last_refresh(&self) -> Option<Duration> {
+        self.refresh_timer.elapsed()
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::refresh_networks_list_from_sysfs;
+    use super::{
+        parse_established_local_addresses, parse_link_messages, parse_proc_net_address,
+        parse_vlan_config, refresh_networks_list_from_sysfs, wol_modes,
+    };
+    use crate::Duplex;
     use std::collections::HashMap;
     use std::fs;
 
+    #[test]
+    fn test_wol_modes() {
+        use crate::WolMode;
+
+        assert!(wol_modes(0).is_empty());
+        // `pumbg` in ethtool.
+        assert_eq!(
+            wol_modes(0b10_1111),
+            [
+                WolMode::Phy,
+                WolMode::Unicast,
+                WolMode::Multicast,
+                WolMode::Broadcast,
+                WolMode::MagicPacket
+            ]
+        );
+        assert_eq!(wol_modes(1 << 7), [WolMode::Filter]);
+    }
+
+    #[test]
+    fn test_parse_vlan_config() {
+        let content = "\
+VLAN Dev name	 | VLAN ID
+Name-Type: VLAN_NAME_TYPE_RAW_PLUS_VID_NO_PAD
+eth0.100       | 100  | eth0
+vlan42         | 42  | enp3s0
+";
+        let vlans = parse_vlan_config(content);
+        assert_eq!(vlans.len(), 2);
+        assert_eq!(vlans.get("eth0.100"), Some(&(100, "eth0".to_owned())));
+        assert_eq!(vlans.get("vlan42"), Some(&(42, "enp3s0".to_owned())));
+        assert!(parse_vlan_config("").is_empty());
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_proc_net_address() {
+        assert_eq!(
+            parse_proc_net_address("0100007F"),
+            Some("127.0.0.1".parse().unwrap())
+        );
+        assert_eq!(
+            parse_proc_net_address("00000000000000000000000001000000"),
+            Some("::1".parse().unwrap())
+        );
+        assert_eq!(
+            parse_proc_net_address("0000000000000000FFFF00000501A8C0"),
+            Some("192.168.1.5".parse().unwrap())
+        );
+        assert_eq!(parse_proc_net_address(""), None);
+        assert_eq!(parse_proc_net_address("0100007"), None);
+        assert_eq!(parse_proc_net_address("ZZ00007F"), None);
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_established_local_addresses() {
+        let content = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 3500007F:0035 00000000:0000 0A 00000000:00000000 00:00000000 00000000   101        0 20948
+   1: 0501A8C0:0016 0A01A8C0:C350 01 00000000:00000000 02:000A7D1F 00000000     0        0 34562
+   2: 0100007F:1F90 0100007F:9C40 01 00000000:00000000 00:00000000 00000000  1000        0 45567
+";
+        let mut addresses = Vec::new();
+        parse_established_local_addresses(content, &mut addresses);
+        assert_eq!(
+            addresses,
+            [
+                "192.168.1.5".parse::<std::net::IpAddr>().unwrap(),
+                "127.0.0.1".parse().unwrap()
+            ]
+        );
+    }
+
     #[test]
     fn refresh_networks_list_add_interface() {
         let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
@@ -269,12 +816,26 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert!(refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path()
+        ));
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf1"]);
+        // Nothing was added.
+        assert!(!refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path()
+        ));
 
         fs::create_dir(sys_net_dir.path().join("itf2")).expect("failed to create subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert!(refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path()
+        ));
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
@@ -296,9 +857,106 @@ mod test {
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
 
+        assert!(interfaces["itf1"].inner.is_updated());
+        assert!(interfaces["itf2"].inner.is_updated());
+
         fs::remove_dir(&itf1_dir).expect("failed to remove subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, true, sys_net_dir.path());
+        // The removed interface is kept but isn't updated anymore.
+        assert!(!refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path()
+        ));
+        assert_eq!(interfaces.len(), 2);
+        assert!(!interfaces["itf1"].inner.is_updated());
+        assert!(interfaces["itf2"].inner.is_updated());
+
+        assert!(refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            true,
+            sys_net_dir.path()
+        ));
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf2"]);
+        assert!(interfaces["itf2"].inner.is_updated());
+    }
+
+    #[test]
+    fn refresh_networks_list_carrier_changes() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let itf1_dir = sys_net_dir.path().join("itf1");
+        fs::create_dir(&itf1_dir).expect("failed to create subdirectory");
+        fs::create_dir(sys_net_dir.path().join("itf2")).expect("failed to create subdirectory");
+        fs::write(itf1_dir.join("carrier_changes"), "3\n").expect("failed to write file");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["itf1"].inner.carrier_changes(), Some(3));
+        assert_eq!(interfaces["itf2"].inner.carrier_changes(), None);
+
+        fs::write(itf1_dir.join("carrier_changes"), "5\n").expect("failed to write file");
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["itf1"].inner.carrier_changes(), Some(5));
+    }
+
+    #[test]
+    fn refresh_networks_list_duplex() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        for (itf, duplex) in [
+            ("itf1", "full\n"),
+            ("itf2", "half\n"),
+            ("itf3", "unknown\n"),
+        ] {
+            let itf_dir = sys_net_dir.path().join(itf);
+            fs::create_dir(&itf_dir).expect("failed to create subdirectory");
+            fs::write(itf_dir.join("duplex"), duplex).expect("failed to write file");
+        }
+        fs::create_dir(sys_net_dir.path().join("itf4")).expect("failed to create subdirectory");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["itf1"].inner.duplex(), Some(Duplex::Full));
+        assert_eq!(interfaces["itf2"].inner.duplex(), Some(Duplex::Half));
+        assert_eq!(interfaces["itf3"].inner.duplex(), None);
+        assert_eq!(interfaces["itf4"].inner.duplex(), None);
+    }
+
+    fn link_message(attributes: &[(u16, &[u8])]) -> Vec<u8> {
+        // `nlmsghdr` and `ifinfomsg`.
+        let mut message = vec![0; 32];
+        message[4..6].copy_from_slice(&16u16.to_ne_bytes());
+        for (kind, payload) in attributes {
+            message.extend_from_slice(&(4 + payload.len() as u16).to_ne_bytes());
+            message.extend_from_slice(&kind.to_ne_bytes());
+            message.extend_from_slice(payload);
+            message.resize((message.len() + 3) & !3, 0);
+        }
+        let len = message.len() as u32;
+        message[0..4].copy_from_slice(&len.to_ne_bytes());
+        message
+    }
+
+    #[test]
+    fn parse_link_messages_max_mtu() {
+        let mut buffer = link_message(&[(3, b"eth0\0"), (51, &9000u32.to_ne_bytes())]);
+        // An interface without `IFLA_MAX_MTU`.
+        buffer.extend(link_message(&[(3, b"lo\0"), (4, &65536u32.to_ne_bytes())]));
+        buffer.extend(link_message(&[(3, b"ifb0\0"), (51, &0u32.to_ne_bytes())]));
+
+        let mut max_mtus = HashMap::new();
+        assert!(!parse_link_messages(&buffer, &mut max_mtus));
+        assert_eq!(max_mtus.len(), 1);
+        assert_eq!(max_mtus.get("eth0"), Some(&9000));
+
+        // `NLMSG_DONE`.
+        let mut done = vec![0; 20];
+        done[0..4].copy_from_slice(&20u32.to_ne_bytes());
+        done[4..6].copy_from_slice(&3u16.to_ne_bytes());
+        assert!(parse_link_messages(&done, &mut max_mtus));
     }
 }
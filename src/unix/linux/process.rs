@@ -1,7 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::cell::UnsafeCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::fs::{self, DirEntry, File};
@@ -11,6 +11,7 @@ use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::str::{self, FromStr};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 use libc::{c_ulong, gid_t, uid_t};
 
@@ -19,8 +20,9 @@ use crate::sys::utils::{
     get_all_data_from_file, get_all_utf8_data, realpath, PathHandler, PathPush,
 };
 use crate::{
-    DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal,
-    ThreadKind, Uid,
+    DiskUsage, FdBreakdown, Gid, GpuUsage, NetworkUsage, Pid, Process, ProcessCpuCounters,
+    ProcessEvent, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal, SignalInfo,
+    SysinfoError, TaskDelays, ThreadKind, Uid,
 };
 
 use crate::sys::system::remaining_files;
@@ -90,6 +92,21 @@ enum ProcIndex {
     StartTime,
     VirtualSize,
     ResidentSetSize,
+    ResidentSetSizeLimit,
+    StartCode,
+    EndCode,
+    StartStack,
+    KernelStackPointer,
+    KernelInstructionPointer,
+    PendingSignals,
+    BlockedSignals,
+    IgnoredSignals,
+    CaughtSignals,
+    WaitChannel,
+    SwappedPages,
+    ChildrenSwappedPages,
+    ExitSignal,
+    Processor,
     // More exist but we only use the listed ones. For more, take a look at `man proc`.
 }
 
@@ -110,6 +127,7 @@ pub(crate) struct ProcessInner {
     old_stime: u64,
     start_time_without_boot_time: u64,
     start_time: u64,
+    start_time_ticks: u64,
     run_time: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
@@ -120,11 +138,20 @@ pub(crate) struct ProcessInner {
     pub(crate) status: ProcessStatus,
     pub(crate) tasks: Option<HashSet<Pid>>,
     stat_file: Option<FileCounter>,
+    #[cfg(feature = "raw-access")]
+    raw_stat: Option<String>,
     old_read_bytes: u64,
     old_written_bytes: u64,
     read_bytes: u64,
     written_bytes: u64,
+    old_syscall_counts: Option<(u64, u64)>,
+    syscall_counts: Option<(u64, u64)>,
+    network_usage: Option<NetworkUsage>,
+    old_scheduling_latency: Option<u64>,
+    scheduling_latency: Option<u64>,
+    memory_samples: VecDeque<(Instant, u64)>,
     thread_kind: Option<ThreadKind>,
+    last_cpu: Option<usize>,
     proc_path: PathBuf,
     accumulated_cpu_time: u64,
     exists: bool,
@@ -151,6 +178,7 @@ impl ProcessInner {
             updated: true,
             start_time_without_boot_time: 0,
             start_time: 0,
+            start_time_ticks: 0,
             run_time: 0,
             user_id: None,
             effective_user_id: None,
@@ -159,17 +187,31 @@ impl ProcessInner {
             status: ProcessStatus::Unknown(0),
             tasks: None,
             stat_file: None,
+            #[cfg(feature = "raw-access")]
+            raw_stat: None,
             old_read_bytes: 0,
             old_written_bytes: 0,
             read_bytes: 0,
             written_bytes: 0,
+            old_syscall_counts: None,
+            syscall_counts: None,
+            network_usage: None,
+            old_scheduling_latency: None,
+            scheduling_latency: None,
+            memory_samples: VecDeque::new(),
             thread_kind: None,
+            last_cpu: None,
             proc_path,
             accumulated_cpu_time: 0,
             exists: true,
         }
     }
 
+    #[cfg(feature = "raw-access")]
+    pub(crate) fn raw_stat(&self) -> Option<&str> {
+        self.raw_stat.as_deref()
+    }
+
     pub(crate) fn kill_with(&self, signal: Signal) -> Option<bool> {
         let c_signal = crate::sys::system::convert_signal(signal)?;
         unsafe { Some(libc::kill(self.pid.0, c_signal) == 0) }
@@ -187,6 +229,15 @@ impl ProcessInner {
         self.exe.as_deref()
     }
 
+    pub(crate) fn exe_identity(&self) -> Option<(u64, u64)> {
+        use std::os::unix::fs::MetadataExt;
+
+        // Unlike `exe`, following this link works even if the file isn't accessible from the
+        // current mount namespace.
+        let metadata = fs::metadata(Path::join(&self.proc_path, "exe")).ok()?;
+        Some((metadata.dev(), metadata.ino()))
+    }
+
     pub(crate) fn pid(&self) -> Pid {
         self.pid
     }
@@ -195,6 +246,23 @@ impl ProcessInner {
         &self.environ
     }
 
+    pub(crate) fn try_environ(&self) -> Result<Vec<OsString>, SysinfoError> {
+        match fs::read(Path::join(&self.proc_path, "environ")) {
+            Ok(data) => Ok(split_content(&data)),
+            // Kernel threads and zombie processes have no environment, in which case the file
+            // is empty or (on recent kernels) can't be opened. It is also returned if the
+            // process is gone, so we check if it's still there.
+            Err(error) if error.raw_os_error() == Some(libc::ESRCH) => {
+                if Path::join(&self.proc_path, "stat").exists() {
+                    Ok(Vec::new())
+                } else {
+                    Err(SysinfoError::NotFound)
+                }
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
     pub(crate) fn cwd(&self) -> Option<&Path> {
         self.cwd.as_deref()
     }
@@ -223,6 +291,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_ticks(&self) -> Option<u64> {
+        Some(self.start_time_ticks)
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -244,6 +316,60 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn restore_disk_usage(&mut self, usage: &DiskUsage) {
+        self.old_read_bytes = usage.total_read_bytes;
+        self.read_bytes = usage.total_read_bytes;
+        self.old_written_bytes = usage.total_written_bytes;
+        self.written_bytes = usage.total_written_bytes;
+    }
+
+    pub(crate) fn cpu_counters(&self) -> ProcessCpuCounters {
+        ProcessCpuCounters {
+            user_time: self.utime,
+            system_time: self.stime,
+            ..Default::default()
+        }
+    }
+
+    // The global reference is restored with the CPUs, see `CpusWrapper::restore_counters`.
+    pub(crate) fn restore_cpu_counters(&mut self, counters: &ProcessCpuCounters) {
+        self.old_utime = counters.user_time;
+        self.utime = counters.user_time;
+        self.old_stime = counters.system_time;
+        self.stime = counters.system_time;
+    }
+
+    pub(crate) fn syscall_counts(&self) -> Option<(u64, u64)> {
+        self.syscall_counts
+    }
+
+    pub(crate) fn syscall_counts_delta(&self) -> Option<(u64, u64)> {
+        let (reads, writes) = self.syscall_counts?;
+        let (old_reads, old_writes) = self.old_syscall_counts?;
+        Some((
+            reads.saturating_sub(old_reads),
+            writes.saturating_sub(old_writes),
+        ))
+    }
+
+    pub(crate) fn network_usage(&self) -> Option<NetworkUsage> {
+        self.network_usage
+    }
+
+    pub(crate) fn scheduling_latency(&self) -> Option<Duration> {
+        self.scheduling_latency.map(Duration::from_nanos)
+    }
+
+    pub(crate) fn scheduling_latency_delta(&self) -> Option<Duration> {
+        let latency = self.scheduling_latency?;
+        let old_latency = self.old_scheduling_latency?;
+        Some(Duration::from_nanos(latency.saturating_sub(old_latency)))
+    }
+
+    pub(crate) fn memory_growth_rate(&self) -> Option<f64> {
+        compute_memory_growth_rate(&self.memory_samples)
+    }
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         self.user_id.as_ref()
     }
@@ -275,10 +401,169 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn is_foreground(&self) -> Option<bool> {
+        let data = fs::read(Path::join(&self.proc_path, "stat")).ok()?;
+        is_foreground_from_stat(&parse_stat_file(&data)?.str_parts)
+    }
+
     pub(crate) fn thread_kind(&self) -> Option<ThreadKind> {
         self.thread_kind
     }
 
+    pub(crate) fn last_cpu(&self) -> Option<usize> {
+        self.last_cpu
+    }
+
+    pub(crate) fn cpus_used_recently(&self) -> Option<usize> {
+        let cpus = fs::read_dir(Path::join(&self.proc_path, "task"))
+            .ok()?
+            .flatten()
+            .filter_map(|entry| {
+                // The thread might have exited in the meantime.
+                let data = fs::read(entry.path().join("stat")).ok()?;
+                let parts = parse_stat_file(&data)?;
+                usize::from_str(parts.str_parts.get(ProcIndex::Processor as usize)?).ok()
+            })
+            .collect::<HashSet<_>>();
+        if cpus.is_empty() {
+            None
+        } else {
+            Some(cpus.len())
+        }
+    }
+
+    pub(crate) fn wait_channel(&self) -> Option<String> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "wchan"), 64).ok()?;
+        let data = data.trim();
+        // "0" means that the process isn't waiting on anything.
+        if data.is_empty() || data == "0" {
+            None
+        } else {
+            Some(data.to_owned())
+        }
+    }
+
+    pub(crate) fn socket_count(&self) -> Option<(usize, usize)> {
+        let inodes = fs::read_dir(Path::join(&self.proc_path, "fd"))
+            .ok()?
+            .filter_map(|entry| {
+                let link = fs::read_link(entry.ok()?.path()).ok()?;
+                link.to_str()?
+                    .strip_prefix("socket:[")?
+                    .strip_suffix(']')?
+                    .parse::<u64>()
+                    .ok()
+            })
+            .collect::<HashSet<_>>();
+        if inodes.is_empty() {
+            return Some((0, 0));
+        }
+        // The sockets are listed in the files of the network namespace of the process.
+        let count = |files: &[&str]| {
+            files
+                .iter()
+                .filter_map(|file| {
+                    get_all_utf8_data(Path::join(&self.proc_path, file), 16_384).ok()
+                })
+                .map(|data| count_socket_inodes(&data, &inodes))
+                .sum()
+        };
+        Some((
+            count(&["net/tcp", "net/tcp6"]),
+            count(&["net/udp", "net/udp6"]),
+        ))
+    }
+
+    pub(crate) fn fd_breakdown(&self) -> Option<FdBreakdown> {
+        let mut breakdown = FdBreakdown::default();
+        for entry in fs::read_dir(Path::join(&self.proc_path, "fd")).ok()? {
+            // The file descriptor might have been closed in the meantime.
+            if let Ok(link) = entry.and_then(|entry| fs::read_link(entry.path())) {
+                add_fd_to_breakdown(&mut breakdown, link.as_os_str().as_bytes());
+            }
+        }
+        Some(breakdown)
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<GpuUsage> {
+        let mut clients = HashSet::new();
+        let mut engines = HashMap::new();
+        let mut usage = GpuUsage::default();
+        let mut found = false;
+        for entry in fs::read_dir(Path::join(&self.proc_path, "fd"))
+            .ok()?
+            .flatten()
+        {
+            // The file descriptor might have been closed in the meantime.
+            let Ok(link) = fs::read_link(entry.path()) else {
+                continue;
+            };
+            if !link.starts_with("/dev/dri") {
+                continue;
+            }
+            let mut path = Path::join(&self.proc_path, "fdinfo");
+            path.push(entry.file_name());
+            let Some(client) = get_all_utf8_data(path, 4_096)
+                .ok()
+                .and_then(|data| parse_drm_fdinfo(&data))
+            else {
+                continue;
+            };
+            // Several file descriptors can refer to the same DRM client.
+            if !clients.insert(client.id) {
+                continue;
+            }
+            found = true;
+            usage.memory_vram += client.memory_vram;
+            for (engine, time) in client.engines {
+                *engines.entry(engine).or_insert(0) += time;
+            }
+        }
+        if !found {
+            return None;
+        }
+        usage.engines = engines
+            .into_iter()
+            .map(|(engine, time)| (engine, Duration::from_nanos(time)))
+            .collect();
+        usage.engines.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        Some(usage)
+    }
+
+    pub(crate) fn page_table_memory(&self) -> Option<u64> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 2_048).ok()?;
+        status_field_bytes(&data, "VmPTE")
+    }
+
+    pub(crate) fn stack_memory(&self) -> Option<u64> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 2_048).ok()?;
+        status_field_bytes(&data, "VmStk")
+    }
+
+    pub(crate) fn locked_memory(&self) -> Option<u64> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 2_048).ok()?;
+        status_field_bytes(&data, "VmLck")
+    }
+
+    pub(crate) fn delays(&self) -> Option<TaskDelays> {
+        crate::sys::taskstats::task_delays(self.pid)
+    }
+
+    pub(crate) fn umask(&self) -> Option<u32> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 2_048).ok()?;
+        status_umask(&data)
+    }
+
+    pub(crate) fn signal_info(&self) -> Option<SignalInfo> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 2_048).ok()?;
+        status_signal_info(&data)
+    }
+
+    pub(crate) fn systemd_unit(&self) -> Option<String> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "cgroup"), 1_024).ok()?;
+        systemd_unit_from_cgroup(&data)
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         std::mem::replace(&mut self.updated, false)
     }
@@ -317,15 +602,281 @@ pub(crate) fn set_time(p: &mut ProcessInner, utime: u64, stime: u64) {
     p.updated = true;
 }
 
+// `link` is the target of a `/proc/<pid>/fd/<fd>` link.
+fn add_fd_to_breakdown(breakdown: &mut FdBreakdown, link: &[u8]) {
+    if link.starts_with(b"/") {
+        breakdown.files += 1;
+    } else if link.starts_with(b"socket:[") {
+        breakdown.sockets += 1;
+    } else if link.starts_with(b"pipe:[") {
+        breakdown.pipes += 1;
+    } else if link.starts_with(b"anon_inode:") {
+        breakdown.anon_inodes += 1;
+    } else {
+        breakdown.others += 1;
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct DrmClient {
+    id: u64,
+    memory_vram: u64,
+    // Time (in nanoseconds) spent by each engine.
+    engines: Vec<(String, u64)>,
+}
+
+// Parses the `/proc/<pid>/fdinfo/<fd>` file of a DRM file descriptor. The format is described
+// in `Documentation/gpu/drm-usage-stats.rst` in the kernel sources.
+fn parse_drm_fdinfo(data: &str) -> Option<DrmClient> {
+    let mut id = None;
+    let mut memory_vram = None;
+    let mut total_vram = None;
+    let mut engines = Vec::new();
+    for line in data.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "drm-client-id" => id = u64::from_str(value).ok(),
+            "drm-memory-vram" => memory_vram = parse_drm_memory(value),
+            "drm-total-vram" => total_vram = parse_drm_memory(value),
+            _ => {
+                let Some(engine) = key.strip_prefix("drm-engine-") else {
+                    continue;
+                };
+                // `drm-engine-capacity-<engine>` is the number of engines, not a time.
+                if engine.starts_with("capacity-") {
+                    continue;
+                }
+                if let Some(time) = value
+                    .strip_suffix(" ns")
+                    .and_then(|time| u64::from_str(time.trim()).ok())
+                {
+                    engines.push((engine.to_owned(), time));
+                }
+            }
+        }
+    }
+    Some(DrmClient {
+        id: id?,
+        // `drm-memory-<region>` is the legacy name of `drm-total-<region>`.
+        memory_vram: memory_vram.or(total_vram).unwrap_or(0),
+        engines,
+    })
+}
+
+// Parses a DRM memory value (like `1024 KiB`) and returns it in bytes.
+fn parse_drm_memory(value: &str) -> Option<u64> {
+    let (value, unit) = match value.split_once(' ') {
+        Some((value, unit)) => (value, unit.trim()),
+        None => (value, ""),
+    };
+    let multiplier = match unit {
+        "" => 1,
+        "KiB" => 1_024,
+        "MiB" => 1_024 * 1_024,
+        "GiB" => 1_024 * 1_024 * 1_024,
+        _ => return None,
+    };
+    u64::from_str(value).ok()?.checked_mul(multiplier)
+}
+
+// Returns how many of the sockets listed in a `/proc/<pid>/net/{tcp,udp}{,6}` file have their
+// inode in `inodes`.
+fn count_socket_inodes(data: &str, inodes: &HashSet<u64>) -> usize {
+    data.lines()
+        // The first line is a header.
+        .skip(1)
+        .filter_map(|line| line.split_whitespace().nth(9))
+        .filter_map(|inode| u64::from_str(inode).ok())
+        .filter(|inode| inodes.contains(inode))
+        .count()
+}
+
+// Returns the value (converted to bytes) of the `key` field of a `/proc/<pid>/status` file.
+fn status_field_bytes(data: &str, key: &str) -> Option<u64> {
+    data.lines().find_map(|line| {
+        let value = line.strip_prefix(key)?.strip_prefix(':')?;
+        // The values are in KiB, though it says "kB".
+        let value_kib = value.trim().strip_suffix("kB")?.trim();
+        u64::from_str(value_kib).ok()?.checked_mul(1_024)
+    })
+}
+
+// Returns the `Umask` field of a `/proc/<pid>/status` file. It's only there since Linux 4.7.
+fn status_umask(data: &str) -> Option<u32> {
+    data.lines().find_map(|line| {
+        let value = line.strip_prefix("Umask:")?.trim();
+        u32::from_str_radix(value, 8).ok()
+    })
+}
+
+// Returns the signal masks of a `/proc/<pid>/status` file. `SigPnd` contains the signals pending
+// for the thread and `ShdPnd` the ones pending for the whole process.
+fn status_signal_info(data: &str) -> Option<SignalInfo> {
+    let mask = |key: &str| {
+        data.lines().find_map(|line| {
+            let value = line.strip_prefix(key)?.strip_prefix(':')?.trim();
+            u64::from_str_radix(value, 16).ok()
+        })
+    };
+    Some(SignalInfo {
+        blocked: mask("SigBlk")?,
+        pending: mask("SigPnd")? | mask("ShdPnd").unwrap_or(0),
+        ignored: mask("SigIgn")?,
+        caught: mask("SigCgt")?,
+    })
+}
+
+// Returns the systemd unit from the content of a `/proc/<pid>/cgroup` file.
+//
+// The unified hierarchy (cgroup v2) is used if available, otherwise the `name=systemd` one. Like
+// systemd does, the unit is the first component of the path which isn't a slice.
+fn systemd_unit_from_cgroup(data: &str) -> Option<String> {
+    let path = data
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .or_else(|| {
+            data.lines()
+                .find_map(|line| line.split_once(":name=systemd:").map(|(_, path)| path))
+        })?;
+    let unit = path
+        .split('/')
+        .find(|component| !component.is_empty() && !component.ends_with(".slice"))?;
+    const UNIT_SUFFIXES: &[&str] = &[
+        ".service",
+        ".scope",
+        ".socket",
+        ".mount",
+        ".swap",
+        ".device",
+        ".target",
+        ".timer",
+        ".path",
+        ".automount",
+    ];
+    if UNIT_SUFFIXES.iter().any(|suffix| {
+        unit.strip_suffix(suffix)
+            .is_some_and(|name| !name.is_empty())
+    }) {
+        Some(unit.to_owned())
+    } else {
+        None
+    }
+}
+
+// Returns the sum of the received and transmitted bytes of all the interfaces listed in a
+// `/proc/<pid>/net/dev` file, except the loopback one.
+fn parse_net_dev(data: &str) -> Option<(u64, u64)> {
+    let mut received = 0u64;
+    let mut transmitted = 0u64;
+    let mut found = false;
+
+    // The first two lines are headers.
+    for line in data.lines().skip(2) {
+        let Some((interface, values)) = line.split_once(':') else {
+            continue;
+        };
+        if interface.trim() == "lo" {
+            continue;
+        }
+        let mut values = values.split_whitespace();
+        // The 8 "receive" columns come before the "transmit" ones.
+        if let (Some(rx), Some(tx)) = (values.next(), values.nth(7)) {
+            if let (Ok(rx), Ok(tx)) = (u64::from_str(rx), u64::from_str(tx)) {
+                received = received.saturating_add(rx);
+                transmitted = transmitted.saturating_add(tx);
+                found = true;
+            }
+        }
+    }
+    found.then_some((received, transmitted))
+}
+
+fn update_process_network_activity(p: &mut ProcessInner) {
+    // We don't use `PathHandler` here because `net/dev` has more than one component.
+    let Some((received, transmitted)) =
+        get_all_utf8_data(Path::join(&p.proc_path, "net/dev"), 4_096)
+            .ok()
+            .and_then(|data| parse_net_dev(&data))
+    else {
+        return;
+    };
+    let old = p.network_usage.unwrap_or_default();
+    p.network_usage = Some(NetworkUsage {
+        total_received_bytes: received,
+        received_bytes: received.saturating_sub(old.total_received_bytes),
+        total_transmitted_bytes: transmitted,
+        transmitted_bytes: transmitted.saturating_sub(old.total_transmitted_bytes),
+    });
+}
+
+// Returns the time spent waiting on a run queue (in nanoseconds) from a `/proc/<pid>/schedstat`
+// file, which contains the time spent on the CPU, the time spent waiting on a run queue and the
+// number of time slices run.
+fn parse_schedstat(data: &str) -> Option<u64> {
+    data.split_whitespace().nth(1)?.parse().ok()
+}
+
+// Number of memory samples kept for `Process::memory_growth_rate`.
+const MEMORY_SAMPLES_LEN: usize = 32;
+
+fn add_memory_sample(p: &mut ProcessInner, now: Instant) {
+    if p.memory_samples.len() == MEMORY_SAMPLES_LEN {
+        p.memory_samples.pop_front();
+    }
+    p.memory_samples.push_back((now, p.memory));
+}
+
+// Computes the slope of the least squares regression line of the samples, which is less
+// sensitive to short spikes than only comparing the first and the last ones.
+fn compute_memory_growth_rate(samples: &VecDeque<(Instant, u64)>) -> Option<f64> {
+    let (start, _) = samples.front()?;
+    if samples.len() < 2 {
+        return None;
+    }
+    let count = samples.len() as f64;
+    let points = || {
+        samples
+            .iter()
+            .map(|(at, memory)| (at.duration_since(*start).as_secs_f64(), *memory as f64))
+    };
+    let (sum_x, sum_y) = points().fold((0., 0.), |(x, y), (px, py)| (x + px, y + py));
+    let (mean_x, mean_y) = (sum_x / count, sum_y / count);
+    let (covariance, variance) = points().fold((0., 0.), |(c, v), (x, y)| {
+        (
+            c + (x - mean_x) * (y - mean_y),
+            v + (x - mean_x) * (x - mean_x),
+        )
+    });
+    if variance == 0. {
+        // All samples were taken at the same time.
+        return None;
+    }
+    Some(covariance / variance)
+}
+
+fn update_process_scheduling_latency(p: &mut ProcessInner, path: &mut PathHandler) {
+    p.old_scheduling_latency = p.scheduling_latency;
+    p.scheduling_latency = get_all_utf8_data(path.join("schedstat"), 64)
+        .ok()
+        .and_then(|data| parse_schedstat(&data));
+}
+
 pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut PathHandler) {
     let data = match get_all_utf8_data(path.join("io"), 16_384) {
         Ok(d) => d,
         Err(_) => return,
     };
     let mut done = 0;
+    let mut syscr = None;
+    let mut syscw = None;
     for line in data.split('\n') {
         let mut parts = line.split(": ");
         match parts.next() {
+            Some("syscr") => syscr = parts.next().and_then(|x| x.parse::<u64>().ok()),
+            Some("syscw") => syscw = parts.next().and_then(|x| x.parse::<u64>().ok()),
             Some("read_bytes") => {
                 p.old_read_bytes = p.read_bytes;
                 p.read_bytes = parts
@@ -343,11 +894,13 @@ pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut Path
             _ => continue,
         }
         done += 1;
-        if done > 1 {
+        if done > 3 {
             // No need to continue the reading.
             break;
         }
     }
+    p.old_syscall_counts = p.syscall_counts;
+    p.syscall_counts = syscr.zip(syscw);
 }
 
 struct Wrap<'a, T>(UnsafeCell<&'a mut T>);
@@ -417,6 +970,9 @@ fn update_proc_info(
     update_parent_pid(p, parent_pid, str_parts);
 
     get_status(p, str_parts[ProcIndex::State as usize]);
+    p.last_cpu = str_parts
+        .get(ProcIndex::Processor as usize)
+        .and_then(|s| usize::from_str(s).ok());
     refresh_user_group_ids(p, proc_path, refresh_kind);
 
     if refresh_kind.exe().needs_update(|| p.exe.is_none()) {
@@ -442,6 +998,12 @@ fn update_proc_info(
     if refresh_kind.disk_usage() {
         update_process_disk_activity(p, proc_path);
     }
+    if refresh_kind.network_usage() {
+        update_process_network_activity(p);
+    }
+    if refresh_kind.scheduling_latency() {
+        update_process_scheduling_latency(p, proc_path);
+    }
     // Needs to be after `update_time_and_memory`.
     if refresh_kind.cpu() {
         // The external values for CPU times are in "ticks", which are
@@ -474,6 +1036,7 @@ fn retrieve_all_new_process_info(
     let mut proc_path = PathHandler::new(path);
     let name = parts.short_exe;
 
+    p.start_time_ticks = u64::from_str(parts.str_parts[ProcIndex::StartTime as usize]).unwrap_or(0);
     p.start_time_without_boot_time = compute_start_time_without_boot_time(parts, info);
     p.start_time = p
         .start_time_without_boot_time
@@ -549,6 +1112,8 @@ fn update_existing_process(
         );
 
         refresh_user_group_ids(entry, &mut proc_path, refresh_kind);
+        #[cfg(feature = "raw-access")]
+        set_raw_stat(entry, &data);
         return Ok(None);
     }
     // If we're here, it means that the PID still exists but it's a different process.
@@ -562,6 +1127,8 @@ fn update_existing_process(
         uptime,
     );
     *proc = p;
+    #[cfg(feature = "raw-access")]
+    set_raw_stat(&mut proc.inner, &data);
     // Since this PID is already in the HashMap, no need to add it again.
     Ok(None)
 }
@@ -588,9 +1155,17 @@ pub(crate) fn _get_process_data(
         retrieve_all_new_process_info(pid, parent_pid, &parts, path, info, refresh_kind, uptime);
     new_process.inner.stat_file = stat_file;
     new_process.inner.tasks = tasks;
+    #[cfg(feature = "raw-access")]
+    set_raw_stat(&mut new_process.inner, &data);
     Ok(Some(new_process))
 }
 
+#[cfg(feature = "raw-access")]
+fn set_raw_stat(p: &mut ProcessInner, data: &[u8]) {
+    let data = String::from_utf8_lossy(data);
+    p.raw_stat = Some(data.trim_end().to_owned());
+}
+
 fn old_get_memory(entry: &mut ProcessInner, str_parts: &[&str], info: &SystemInfo) {
     // rss
     entry.memory = u64::from_str(str_parts[ProcIndex::ResidentSetSize as usize])
@@ -654,11 +1229,14 @@ fn update_time_and_memory(
 ) {
     {
         #[allow(clippy::collapsible_if)]
-        if refresh_kind.memory() {
+        if refresh_kind.memory() || refresh_kind.memory_history() {
             // Keeping this nested level for readability reasons.
             if !get_memory(path.join("statm"), entry, info) {
                 old_get_memory(entry, str_parts, info);
             }
+            if refresh_kind.memory_history() {
+                add_memory_sample(entry, Instant::now());
+            }
         }
         set_time(
             entry,
@@ -753,6 +1331,7 @@ pub(crate) fn refresh_procs(
     info: &SystemInfo,
     processes_to_update: ProcessesToUpdate<'_>,
     refresh_kind: ProcessRefreshKind,
+    on_event: &mut dyn FnMut(ProcessEvent),
 ) -> usize {
     #[cfg(feature = "multithread")]
     use rayon::iter::ParallelIterator;
@@ -823,7 +1402,12 @@ pub(crate) fn refresh_procs(
             .collect::<Vec<_>>()
     };
     for proc_ in procs {
-        proc_list.insert(proc_.pid(), proc_);
+        let pid = proc_.pid();
+        // If there was already a process with this PID, it was replaced by a new one.
+        if proc_list.insert(pid, proc_).is_some() {
+            on_event(ProcessEvent::Exited(pid));
+        }
+        on_event(ProcessEvent::Started(pid));
     }
     nb_updated.into_inner()
 }
@@ -960,6 +1544,20 @@ fn parse_stat_file(data: &[u8]) -> Option<Parts<'_>> {
     })
 }
 
+// Returns `None` if the process has no controlling terminal, otherwise if its process group is
+// the foreground process group of the terminal.
+fn is_foreground_from_stat(str_parts: &[&str]) -> Option<bool> {
+    let tty = i32::from_str(str_parts.get(ProcIndex::Tty as usize)?).ok()?;
+    if tty == 0 {
+        return None;
+    }
+    let group_id = i32::from_str(str_parts.get(ProcIndex::GroupId as usize)?).ok()?;
+    // It's `-1` if the terminal has no foreground process group.
+    let foreground_group_id =
+        i32::from_str(str_parts.get(ProcIndex::ForegroundProcessGroupId as usize)?).ok()?;
+    Some(group_id == foreground_group_id)
+}
+
 /// Type used to correctly handle the `REMAINING_FILES` global.
 struct FileCounter(File);
 
@@ -1000,7 +1598,14 @@ impl Drop for FileCounter {
 
 #[cfg(test)]
 mod tests {
-    use super::split_content;
+    use super::{
+        add_fd_to_breakdown, compute_memory_growth_rate, count_socket_inodes,
+        is_foreground_from_stat, parse_drm_fdinfo, parse_drm_memory, parse_net_dev,
+        parse_schedstat, parse_stat_file, split_content, status_field_bytes, status_signal_info,
+        status_umask, systemd_unit_from_cgroup, DrmClient,
+    };
+    use crate::Signal;
+    use std::collections::{HashSet, VecDeque};
     use std::ffi::OsString;
 
     // This test ensures that all the parts of the data are split.
@@ -1017,4 +1622,242 @@ mod tests {
             vec![OsString::from("hello"), "b".into()]
         );
     }
+
+    #[test]
+    fn test_parse_net_dev() {
+        let content = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:  123456     100    0    0    0     0          0         0   123456     100    0    0    0     0       0          0
+  eth0: 1000000    2000    0    0    0     0          0         0   500000    1000    0    0    0     0       0          0
+ wlan0:     200       2    0    0    0     0          0         0       30       1    0    0    0     0       0          0
+";
+        assert_eq!(parse_net_dev(content), Some((1_000_200, 500_030)));
+        // Only the loopback interface.
+        assert_eq!(
+            parse_net_dev(&content.lines().take(3).collect::<Vec<_>>().join("\n")),
+            None
+        );
+        assert_eq!(parse_net_dev(""), None);
+    }
+
+    #[test]
+    fn test_compute_memory_growth_rate() {
+        let start = std::time::Instant::now();
+        let at = |secs| start + std::time::Duration::from_secs(secs);
+
+        let mut samples = VecDeque::new();
+        assert_eq!(compute_memory_growth_rate(&samples), None);
+        samples.push_back((at(0), 1_000));
+        assert_eq!(compute_memory_growth_rate(&samples), None);
+        // Samples taken at the same time.
+        samples.push_back((at(0), 2_000));
+        assert_eq!(compute_memory_growth_rate(&samples), None);
+
+        let samples = VecDeque::from([(at(0), 1_000), (at(1), 1_100), (at(2), 1_200)]);
+        assert_eq!(compute_memory_growth_rate(&samples), Some(100.));
+        let samples = VecDeque::from([(at(0), 1_200), (at(2), 1_000)]);
+        assert_eq!(compute_memory_growth_rate(&samples), Some(-100.));
+    }
+
+    #[test]
+    fn test_parse_schedstat() {
+        assert_eq!(parse_schedstat("73473143 1408437 52\n"), Some(1_408_437));
+        assert_eq!(parse_schedstat("73473143"), None);
+        assert_eq!(parse_schedstat(""), None);
+    }
+
+    #[test]
+    fn test_count_socket_inodes() {
+        let content = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 3500007F:0035 00000000:0000 0A 00000000:00000000 00:00000000 00000000   101        0 20948 1 0000000000000000 100 0 0 10 5
+   1: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 31520 1 0000000000000000 100 0 0 10 0
+   2: 0F02000A:A1B2 22D8B85D:01BB 01 00000000:00000000 02:000009E5 00000000  1000        0 98765 2 0000000000000000 20 4 30 10 -1
+";
+        let inodes = HashSet::from([20948, 98765, 1]);
+        assert_eq!(count_socket_inodes(content, &inodes), 2);
+        assert_eq!(count_socket_inodes(content, &HashSet::new()), 0);
+        assert_eq!(count_socket_inodes("", &inodes), 0);
+    }
+
+    #[test]
+    fn test_add_fd_to_breakdown() {
+        let mut breakdown = super::FdBreakdown::default();
+        for link in [
+            "/dev/null",
+            "/home/user/file (deleted)",
+            "socket:[12345]",
+            "pipe:[6789]",
+            "pipe:[6790]",
+            "anon_inode:[eventpoll]",
+            "anon_inode:[eventfd]",
+            "net:[4026531840]",
+        ] {
+            add_fd_to_breakdown(&mut breakdown, link.as_bytes());
+        }
+        assert_eq!(breakdown.files, 2);
+        assert_eq!(breakdown.sockets, 1);
+        assert_eq!(breakdown.pipes, 2);
+        assert_eq!(breakdown.anon_inodes, 2);
+        assert_eq!(breakdown.others, 1);
+        assert_eq!(breakdown.total(), 8);
+    }
+
+    #[test]
+    fn test_parse_drm_fdinfo() {
+        let content = "\
+pos:\t0
+flags:\t02100002
+mnt_id:\t26
+ino:\t1043
+drm-driver:\tamdgpu
+drm-pdev:\t0000:03:00.0
+drm-client-id:\t42
+drm-memory-vram:\t20480 KiB
+drm-memory-gtt:\t2048 KiB
+drm-total-vram:\t20484 KiB
+drm-engine-gfx:\t1234567 ns
+drm-engine-compute:\t0 ns
+drm-engine-capacity-gfx:\t2
+";
+        assert_eq!(
+            parse_drm_fdinfo(content),
+            Some(DrmClient {
+                id: 42,
+                memory_vram: 20_480 * 1_024,
+                engines: vec![("gfx".to_owned(), 1_234_567), ("compute".to_owned(), 0)],
+            })
+        );
+        // Not a DRM file descriptor.
+        assert_eq!(parse_drm_fdinfo("pos:\t0\nflags:\t02\nmnt_id:\t26\n"), None);
+        // No VRAM (like on integrated GPUs).
+        assert_eq!(
+            parse_drm_fdinfo("drm-client-id:\t3\ndrm-engine-render:\t10 ns\n"),
+            Some(DrmClient {
+                id: 3,
+                memory_vram: 0,
+                engines: vec![("render".to_owned(), 10)],
+            })
+        );
+
+        assert_eq!(parse_drm_memory("12"), Some(12));
+        assert_eq!(parse_drm_memory("12 KiB"), Some(12 * 1_024));
+        assert_eq!(parse_drm_memory("3 MiB"), Some(3 * 1_024 * 1_024));
+        assert_eq!(parse_drm_memory("3 kB"), None);
+    }
+
+    #[test]
+    fn test_is_foreground_from_stat() {
+        let is_foreground = |content: &str| {
+            is_foreground_from_stat(&parse_stat_file(content.as_bytes()).unwrap().str_parts)
+        };
+        // `vim` started from a shell, in the foreground.
+        assert_eq!(
+            is_foreground("4242 (vim) S 4200 4242 4200 34816 4242 4194304 9 0 0 0"),
+            Some(true)
+        );
+        // `sleep` started in the background from the same shell.
+        assert_eq!(
+            is_foreground("4250 (sleep) S 4200 4250 4200 34816 4242 4194304 9 0 0 0"),
+            Some(false)
+        );
+        // No controlling terminal.
+        assert_eq!(
+            is_foreground("1 (systemd) S 0 1 1 0 -1 4194560 9 0 0 0"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_status_field_bytes() {
+        let content = "\
+Name:\tbash
+VmLck:\t      16 kB
+VmRSS:\t    5120 kB
+VmStk:\t     132 kB
+VmPTE:\t      56 kB
+Threads:\t1
+";
+        assert_eq!(status_field_bytes(content, "VmPTE"), Some(56 * 1_024));
+        assert_eq!(status_field_bytes(content, "VmStk"), Some(132 * 1_024));
+        assert_eq!(status_field_bytes(content, "VmLck"), Some(16 * 1_024));
+        // Not a memory field.
+        assert_eq!(status_field_bytes(content, "Threads"), None);
+        // Kernel threads don't have these fields.
+        assert_eq!(status_field_bytes("Name:\tkthreadd\n", "VmPTE"), None);
+    }
+
+    #[test]
+    fn test_status_signal_info() {
+        let content = "\
+Name:\tbash
+SigQ:\t0/63448
+SigPnd:\t0000000000000002
+ShdPnd:\t0000000000004000
+SigBlk:\t0000000000010000
+SigIgn:\t0000000000380004
+SigCgt:\t000000004b817efb
+CapInh:\t0000000000000000
+";
+        let info = status_signal_info(content).unwrap();
+        assert_eq!(info.pending, 0x4002);
+        assert_eq!(info.blocked, 0x10000);
+        assert_eq!(info.ignored, 0x380004);
+        assert_eq!(info.caught, 0x4b817efb);
+        // SIGINT and SIGTERM are pending, SIGCHLD is blocked and SIGQUIT is ignored.
+        assert!(info.is_pending(Signal::Interrupt));
+        assert!(info.is_pending(Signal::Term));
+        assert!(!info.is_pending(Signal::Kill));
+        assert!(info.is_blocking(Signal::Child));
+        assert!(info.is_ignoring(Signal::Quit));
+        assert!(info.is_catching(Signal::Hangup));
+        assert!(!info.is_catching(Signal::Quit));
+
+        assert_eq!(status_signal_info("Name:\tbash\nSigPnd:\t0\n"), None);
+    }
+
+    #[test]
+    fn test_status_umask() {
+        assert_eq!(
+            status_umask("Name:\tbash\nUmask:\t0022\nState:\tS\n"),
+            Some(0o022)
+        );
+        assert_eq!(status_umask("Umask:\t0077\n"), Some(0o077));
+        // Before Linux 4.7.
+        assert_eq!(status_umask("Name:\tbash\nState:\tS\n"), None);
+    }
+
+    #[test]
+    fn test_systemd_unit_from_cgroup() {
+        assert_eq!(
+            systemd_unit_from_cgroup("0::/system.slice/nginx.service\n").as_deref(),
+            Some("nginx.service"),
+        );
+        assert_eq!(
+            systemd_unit_from_cgroup("0::/init.scope\n").as_deref(),
+            Some("init.scope"),
+        );
+        // The user manager is the unit, not the applications it started.
+        assert_eq!(
+            systemd_unit_from_cgroup(
+                "0::/user.slice/user-1000.slice/user@1000.service/app.slice/app-foo.scope\n"
+            )
+            .as_deref(),
+            Some("user@1000.service"),
+        );
+        // cgroup v1.
+        assert_eq!(
+            systemd_unit_from_cgroup(
+                "12:memory:/system.slice/sshd.service\n1:name=systemd:/system.slice/sshd.service\n"
+            )
+            .as_deref(),
+            Some("sshd.service"),
+        );
+        // Not managed by systemd.
+        assert_eq!(systemd_unit_from_cgroup("0::/\n"), None);
+        assert_eq!(systemd_unit_from_cgroup("0::/docker/abcdef\n"), None);
+        assert_eq!(systemd_unit_from_cgroup("0::/user.slice\n"), None);
+        assert_eq!(systemd_unit_from_cgroup(""), None);
+    }
 }
@@ -0,0 +1,239 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Pid, ProcessLifecycleEvent, SysinfoError};
+
+use std::io;
+use std::mem::{size_of, zeroed};
+
+// Values from `linux/connector.h` and `linux/cn_proc.h`.
+const NETLINK_CONNECTOR: libc::c_int = 11;
+const CN_IDX_PROC: u32 = 1;
+const CN_VAL_PROC: u32 = 1;
+const PROC_CN_MCAST_LISTEN: u32 = 1;
+const PROC_CN_MCAST_IGNORE: u32 = 2;
+
+const PROC_EVENT_FORK: u32 = 0x0000_0001;
+const PROC_EVENT_EXEC: u32 = 0x0000_0002;
+const PROC_EVENT_EXIT: u32 = 0x8000_0000;
+
+const NLMSG_HDR_LEN: usize = 16;
+// `idx`, `val`, `seq`, `ack`, `len` and `flags`.
+const CN_MSG_LEN: usize = 20;
+// `what`, `cpu` and `timestamp_ns`.
+const PROC_EVENT_HDR_LEN: usize = 16;
+
+pub(crate) struct ProcessEventListenerInner {
+    fd: libc::c_int,
+    buffer: Vec<u8>,
+}
+
+impl ProcessEventListenerInner {
+    pub(crate) fn new() -> Result<Self, SysinfoError> {
+        unsafe {
+            let fd = libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+                NETLINK_CONNECTOR,
+            );
+            if fd < 0 {
+                return Err(io::Error::last_os_error().into());
+            }
+            // From now on, `drop` takes care of closing the socket.
+            let listener = Self {
+                fd,
+                buffer: vec![0; 4096],
+            };
+
+            let mut addr: libc::sockaddr_nl = zeroed();
+            addr.nl_family = libc::AF_NETLINK as _;
+            addr.nl_pid = 0;
+            addr.nl_groups = CN_IDX_PROC;
+            if libc::bind(
+                fd,
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                size_of::<libc::sockaddr_nl>() as _,
+            ) < 0
+            {
+                return Err(io::Error::last_os_error().into());
+            }
+            listener.send_multicast_op(PROC_CN_MCAST_LISTEN)?;
+            Ok(listener)
+        }
+    }
+
+    fn send_multicast_op(&self, op: u32) -> Result<(), SysinfoError> {
+        let message = multicast_op_message(op);
+        let ret = unsafe {
+            libc::send(
+                self.fd,
+                message.as_ptr() as *const libc::c_void,
+                message.len(),
+                0,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+
+    pub(crate) fn next_event(&mut self) -> Result<ProcessLifecycleEvent, SysinfoError> {
+        loop {
+            let ret = unsafe {
+                libc::recv(
+                    self.fd,
+                    self.buffer.as_mut_ptr() as *mut libc::c_void,
+                    self.buffer.len(),
+                    0,
+                )
+            };
+            if ret < 0 {
+                let error = io::Error::last_os_error();
+                if error.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(error.into());
+            }
+            if let Some(event) = parse_proc_event(&self.buffer[..ret as usize]) {
+                return Ok(event);
+            }
+        }
+    }
+}
+
+impl Drop for ProcessEventListenerInner {
+    fn drop(&mut self) {
+        let _ = self.send_multicast_op(PROC_CN_MCAST_IGNORE);
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
+
+const MULTICAST_OP_MESSAGE_LEN: usize = NLMSG_HDR_LEN + CN_MSG_LEN + 4;
+
+fn multicast_op_message(op: u32) -> [u8; MULTICAST_OP_MESSAGE_LEN] {
+    let mut message = [0; MULTICAST_OP_MESSAGE_LEN];
+    // `nlmsghdr`.
+    message[0..4].copy_from_slice(&(MULTICAST_OP_MESSAGE_LEN as u32).to_ne_bytes());
+    message[4..6].copy_from_slice(&(libc::NLMSG_DONE as u16).to_ne_bytes());
+    message[12..16].copy_from_slice(&std::process::id().to_ne_bytes());
+    // `cn_msg`.
+    message[16..20].copy_from_slice(&CN_IDX_PROC.to_ne_bytes());
+    message[20..24].copy_from_slice(&CN_VAL_PROC.to_ne_bytes());
+    message[32..34].copy_from_slice(&4u16.to_ne_bytes());
+    // The operation.
+    message[36..40].copy_from_slice(&op.to_ne_bytes());
+    message
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_pid(data: &[u8], offset: usize) -> Option<Pid> {
+    read_u32(data, offset).map(Pid::from_u32)
+}
+
+/// Parses a netlink message containing a `proc_event`. Threads events and events we don't
+/// handle are ignored.
+fn parse_proc_event(buffer: &[u8]) -> Option<ProcessLifecycleEvent> {
+    let event = buffer.get(NLMSG_HDR_LEN + CN_MSG_LEN..)?;
+    let data = event.get(PROC_EVENT_HDR_LEN..)?;
+    match read_u32(event, 0)? {
+        PROC_EVENT_FORK => {
+            let parent = read_pid(data, 4)?;
+            let child_pid = read_u32(data, 8)?;
+            let child_tgid = read_u32(data, 12)?;
+            if child_pid != child_tgid {
+                return None;
+            }
+            Some(ProcessLifecycleEvent::Fork {
+                parent,
+                child: Pid::from_u32(child_tgid),
+            })
+        }
+        PROC_EVENT_EXEC => Some(ProcessLifecycleEvent::Exec {
+            pid: read_pid(data, 4)?,
+        }),
+        PROC_EVENT_EXIT => {
+            let pid = read_u32(data, 0)?;
+            let tgid = read_u32(data, 4)?;
+            if pid != tgid {
+                return None;
+            }
+            Some(ProcessLifecycleEvent::Exit {
+                pid: Pid::from_u32(tgid),
+                exit_code: read_u32(data, 8)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn build_event(what: u32, data: &[u32]) -> Vec<u8> {
+        let mut buffer = vec![0; NLMSG_HDR_LEN + CN_MSG_LEN];
+        buffer.extend_from_slice(&what.to_ne_bytes());
+        // `cpu` and `timestamp_ns`.
+        buffer.extend_from_slice(&[0; 12]);
+        for value in data {
+            buffer.extend_from_slice(&value.to_ne_bytes());
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_parse_proc_event() {
+        assert_eq!(
+            parse_proc_event(&build_event(PROC_EVENT_FORK, &[10, 10, 12, 12])),
+            Some(ProcessLifecycleEvent::Fork {
+                parent: Pid::from_u32(10),
+                child: Pid::from_u32(12),
+            })
+        );
+        // A new thread.
+        assert_eq!(
+            parse_proc_event(&build_event(PROC_EVENT_FORK, &[10, 10, 13, 12])),
+            None
+        );
+        assert_eq!(
+            parse_proc_event(&build_event(PROC_EVENT_EXEC, &[12, 12])),
+            Some(ProcessLifecycleEvent::Exec {
+                pid: Pid::from_u32(12)
+            })
+        );
+        assert_eq!(
+            parse_proc_event(&build_event(PROC_EVENT_EXIT, &[12, 12, 256, 17])),
+            Some(ProcessLifecycleEvent::Exit {
+                pid: Pid::from_u32(12),
+                exit_code: 256,
+            })
+        );
+        // A thread exited.
+        assert_eq!(
+            parse_proc_event(&build_event(PROC_EVENT_EXIT, &[13, 12, 0, 0])),
+            None
+        );
+        // Unhandled event (`PROC_EVENT_UID`).
+        assert_eq!(parse_proc_event(&build_event(4, &[12, 12, 0, 0])), None);
+        // Truncated message.
+        assert_eq!(
+            parse_proc_event(&build_event(PROC_EVENT_EXIT, &[12, 12])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_multicast_op_message() {
+        let message = multicast_op_message(PROC_CN_MCAST_LISTEN);
+        assert_eq!(read_u32(&message, 0), Some(message.len() as u32));
+        assert_eq!(read_u32(&message, 16), Some(CN_IDX_PROC));
+        assert_eq!(read_u32(&message, 20), Some(CN_VAL_PROC));
+        assert_eq!(read_u32(&message, 36), Some(PROC_CN_MCAST_LISTEN));
+    }
+}
@@ -0,0 +1,79 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError, RwLock};
+
+// Files used instead of the real ones while `replay` is running. It's not thread-local because
+// some information (like the CPUs frequency) is retrieved from multiple threads.
+static REPLAYED_FILES: RwLock<Option<HashMap<PathBuf, String>>> = RwLock::new(None);
+// Prevents multiple `replay` to run at the same time.
+static REPLAY_LOCK: Mutex<()> = Mutex::new(());
+
+pub(crate) fn replay<R>(files: HashMap<PathBuf, String>, f: impl FnOnce() -> R) -> R {
+    // Resets the replayed files even if `f` panics.
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            *REPLAYED_FILES
+                .write()
+                .unwrap_or_else(PoisonError::into_inner) = None;
+        }
+    }
+
+    let _lock = REPLAY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    *REPLAYED_FILES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(files);
+    let _guard = Guard;
+    f()
+}
+
+#[cfg(feature = "system")]
+pub(crate) fn is_replaying() -> bool {
+    REPLAYED_FILES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .is_some()
+}
+
+/// Returns `None` if not replaying, otherwise the content of the replayed file.
+#[cfg(any(
+    feature = "disk",
+    feature = "gpu",
+    feature = "system",
+    feature = "network"
+))]
+pub(crate) fn replayed_file(path: &std::path::Path) -> Option<std::io::Result<String>> {
+    let files = REPLAYED_FILES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    let files = files.as_ref()?;
+    Some(files.get(path).cloned().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("{path:?} isn't replayed"),
+        )
+    }))
+}
+
+/// Returns `None` if not replaying, otherwise the paths of the replayed entries of the `path`
+/// folder (the folders of the replayed files included).
+#[cfg(feature = "network")]
+pub(crate) fn replayed_dir(path: &std::path::Path) -> Option<Vec<PathBuf>> {
+    let files = REPLAYED_FILES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    let mut entries = files
+        .as_ref()?
+        .keys()
+        .filter_map(|file| {
+            let name = file.strip_prefix(path).ok()?.components().next()?;
+            Some(path.join(name))
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries.dedup();
+    Some(entries)
+}
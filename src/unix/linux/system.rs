@@ -1,11 +1,16 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::cpu::{get_physical_core_count, CpusWrapper};
+use crate::sys::cpu::{
+    cpu_list_count, get_physical_core_count, get_physical_socket_count, smt_enabled,
+    threads_per_core, CpusWrapper, PackageEnergy,
+};
 use crate::sys::process::{compute_cpu_usage, refresh_procs};
 use crate::sys::utils::{get_all_utf8_data, to_u64};
+use crate::unix::utils::{get_locale, get_timezone, is_elevated, page_size};
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    Cpu, CpuCounters, CpuRefreshKind, Feature, Interrupt, InterruptStats, KernelModule, LoadAvg,
+    MemoryControllerErrors, MemoryErrors, MemoryRefreshKind, Pid, Process, ProcessEvent,
+    ProcessRefreshKind, ProcessesToUpdate, TcpMemoryStats, ThpMode,
 };
 
 use libc::{self, c_char, sysconf, _SC_CLK_TCK, _SC_HOST_NAME_MAX, _SC_PAGESIZE};
@@ -18,7 +23,10 @@ use std::io::Read;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::{atomic::AtomicIsize, OnceLock};
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
+
+// GUID of the EFI global variables namespace (where `SecureBoot` lives).
+const EFI_GLOBAL_GUID: &str = "8be4df61-93ca-11d2-aa0d-00e098032b8c";
 
 // This whole thing is to prevent having too many files open at once. It could be problematic
 // for processes using a lot of files and using sysinfo at the same time.
@@ -164,6 +172,26 @@ pub(crate) struct SystemInner {
     swap_free: u64,
     info: SystemInfo,
     cpus: CpusWrapper,
+    package_energy: PackageEnergy,
+    swap_rates: SwapRates,
+}
+
+// Counters of `/proc/vmstat` used to compute the swap and page fault rates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct VmCounters {
+    // In pages.
+    swap_in: u64,
+    // In pages.
+    swap_out: u64,
+    major_faults: u64,
+}
+
+#[derive(Default)]
+struct SwapRates {
+    last_refresh: Option<(Instant, VmCounters)>,
+    swap_in_per_second: f64,
+    swap_out_per_second: f64,
+    major_faults_per_second: f64,
 }
 
 impl SystemInner {
@@ -216,8 +244,44 @@ impl SystemInner {
             swap_total: 0,
             swap_free: 0,
             cpus: CpusWrapper::new(),
+            package_energy: PackageEnergy::new(),
             info: SystemInfo::new(),
+            swap_rates: SwapRates::default(),
+        }
+    }
+
+    pub(crate) fn refresh_swap_rates(&mut self) {
+        let Some(counters) = get_all_utf8_data("/proc/vmstat", 16_384)
+            .ok()
+            .and_then(|data| parse_vm_counters(&data))
+        else {
+            return;
+        };
+        let now = Instant::now();
+        let rates = &mut self.swap_rates;
+        if let Some((last_time, last)) = rates.last_refresh {
+            let elapsed = now.duration_since(last_time).as_secs_f64();
+            if elapsed > 0. {
+                let page_size = self.info.page_size_b as f64;
+                let rate = |new: u64, old: u64| new.saturating_sub(old) as f64 / elapsed;
+                rates.swap_in_per_second = rate(counters.swap_in, last.swap_in) * page_size;
+                rates.swap_out_per_second = rate(counters.swap_out, last.swap_out) * page_size;
+                rates.major_faults_per_second = rate(counters.major_faults, last.major_faults);
+            }
         }
+        rates.last_refresh = Some((now, counters));
+    }
+
+    pub(crate) fn swap_in_per_second(&self) -> f64 {
+        self.swap_rates.swap_in_per_second
+    }
+
+    pub(crate) fn swap_out_per_second(&self) -> f64 {
+        self.swap_rates.swap_out_per_second
+    }
+
+    pub(crate) fn major_page_faults_per_second(&self) -> f64 {
+        self.swap_rates.major_faults_per_second
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
@@ -264,12 +328,16 @@ impl SystemInner {
 
     pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
         self.refresh_cpus(false, refresh_kind);
+        if refresh_kind.energy() {
+            self.package_energy.refresh();
+        }
     }
 
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
         refresh_kind: ProcessRefreshKind,
+        on_event: &mut dyn FnMut(ProcessEvent),
     ) -> usize {
         let uptime = Self::uptime();
         let nb_updated = refresh_procs(
@@ -279,6 +347,7 @@ impl SystemInner {
             &self.info,
             processes_to_update,
             refresh_kind,
+            on_event,
         );
         self.update_procs_cpu(refresh_kind);
         nb_updated
@@ -304,10 +373,26 @@ impl SystemInner {
         self.cpus.global_cpu.usage()
     }
 
+    pub(crate) fn global_steal_time_percent(&self) -> f32 {
+        self.cpus.global_cpu.steal_time_percent()
+    }
+
+    pub(crate) fn package_power(&self) -> Option<f64> {
+        self.package_energy.power()
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpu_counters(&self) -> CpuCounters {
+        self.cpus.counters()
+    }
+
+    pub(crate) fn restore_cpu_counters(&mut self, counters: &CpuCounters) {
+        self.cpus.restore_counters(counters);
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -350,6 +435,21 @@ impl SystemInner {
         boot_time()
     }
 
+    // Returns the number of clock ticks between the boot and `time` (a duration since the UNIX
+    // epoch), to compare it with the start time of processes. Contrary to `btime`, the uptime
+    // isn't rounded to the second.
+    pub(crate) fn clock_ticks_since_boot(time: Duration) -> Option<u64> {
+        let uptime = get_all_utf8_data("/proc/uptime", 50).ok()?;
+        let uptime = f64::from_str(uptime.split_whitespace().next()?).ok()?;
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .ok()?
+            .as_secs_f64();
+        let since_boot = time.as_secs_f64() - (now - uptime);
+        let clock_ticks = unsafe { sysconf(_SC_CLK_TCK) } as f64;
+        Some((since_boot.max(0.) * clock_ticks) as u64)
+    }
+
     pub(crate) fn load_average() -> LoadAvg {
         let mut s = String::new();
         if File::open("/proc/loadavg")
@@ -551,12 +651,316 @@ impl SystemInner {
         get_physical_core_count()
     }
 
+    pub(crate) fn physical_socket_count() -> Option<usize> {
+        get_physical_socket_count()
+    }
+
+    pub(crate) fn available_entropy() -> Option<u32> {
+        get_all_utf8_data("/proc/sys/kernel/random/entropy_avail", 16)
+            .ok()
+            .and_then(|d| u32::from_str(d.trim()).ok())
+    }
+
+    pub(crate) fn transparent_huge_pages() -> Option<ThpMode> {
+        get_all_utf8_data("/sys/kernel/mm/transparent_hugepage/enabled", 64)
+            .ok()
+            .and_then(|data| parse_thp_mode(&data))
+    }
+
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        // The first 4 bytes of an efivars file are the variable attributes, the value follows.
+        if let Ok(data) = std::fs::read(format!(
+            "/sys/firmware/efi/efivars/SecureBoot-{EFI_GLOBAL_GUID}"
+        )) {
+            return data.get(4).map(|value| *value != 0);
+        }
+        // Older kernels only provide the deprecated `vars` interface which has no attributes.
+        std::fs::read(format!(
+            "/sys/firmware/efi/vars/SecureBoot-{EFI_GLOBAL_GUID}/data"
+        ))
+        .ok()
+        .and_then(|data| data.first().map(|value| *value != 0))
+    }
+
+    pub(crate) fn smt_enabled() -> Option<bool> {
+        smt_enabled()
+    }
+
+    pub(crate) fn threads_per_core() -> Option<usize> {
+        threads_per_core()
+    }
+
+    pub(crate) fn cpus_possible() -> Option<usize> {
+        cpu_list_count("possible")
+    }
+
+    pub(crate) fn cpus_present() -> Option<usize> {
+        cpu_list_count("present")
+    }
+
+    pub(crate) fn cpus_online() -> Option<usize> {
+        cpu_list_count("online")
+    }
+
+    pub(crate) fn kernel_modules() -> Vec<KernelModule> {
+        get_all_utf8_data("/proc/modules", 16_384)
+            .map(|data| parse_kernel_modules(&data))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        get_timezone().or_else(|| {
+            // Debian-based distributions also store the name of the timezone in this file.
+            get_all_utf8_data("/etc/timezone", 64)
+                .ok()
+                .map(|name| name.trim().to_owned())
+                .filter(|name| !name.is_empty())
+        })
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        get_locale().or_else(|| {
+            ["/etc/locale.conf", "/etc/default/locale"]
+                .into_iter()
+                .find_map(|path| {
+                    let data = get_all_utf8_data(path, 256).ok()?;
+                    data.lines()
+                        .find_map(|line| line.strip_prefix("LANG="))
+                        .map(|lang| lang.replace('"', ""))
+                        .filter(|lang| !lang.is_empty())
+                })
+        })
+    }
+
+    pub(crate) fn is_elevated() -> bool {
+        is_elevated()
+    }
+
+    pub(crate) fn page_size() -> u64 {
+        page_size()
+    }
+
+    pub(crate) fn logical_cpu_count() -> usize {
+        crate::unix::utils::logical_cpu_count()
+    }
+
+    pub(crate) fn balloon_memory() -> Option<u64> {
+        let pages = get_all_utf8_data("/proc/vmstat", 16_384)
+            .ok()
+            .and_then(|data| parse_balloon_pages(&data))?;
+        Some(pages.saturating_mul(page_size()))
+    }
+
+    pub(crate) fn conntrack_count() -> Option<u64> {
+        get_all_utf8_data("/proc/sys/net/netfilter/nf_conntrack_count", 32)
+            .ok()
+            .and_then(|d| u64::from_str(d.trim()).ok())
+    }
+
+    pub(crate) fn conntrack_max() -> Option<u64> {
+        get_all_utf8_data("/proc/sys/net/netfilter/nf_conntrack_max", 32)
+            .ok()
+            .and_then(|d| u64::from_str(d.trim()).ok())
+    }
+
+    pub(crate) fn vm_setting(name: &str) -> Option<i64> {
+        // We don't want to read files outside of `/proc/sys/vm`.
+        if name.is_empty() || name.contains('/') || name.starts_with('.') {
+            return None;
+        }
+        get_all_utf8_data(Path::new("/proc/sys/vm").join(name), 64)
+            .ok()
+            .and_then(|d| i64::from_str(d.trim()).ok())
+    }
+
+    pub(crate) fn supports(feature: Feature) -> bool {
+        match feature {
+            Feature::AvailableMemory
+            | Feature::LoadAverage
+            | Feature::ProcessCpuUsage
+            | Feature::ProcessEnvironment
+            | Feature::ProcessTasks
+            | Feature::SwapRates
+            | Feature::ProcessSignalInfo => true,
+            Feature::CGroupLimits => {
+                read_u64("/sys/fs/cgroup/memory.current").is_some()
+                    || read_u64("/sys/fs/cgroup/memory/memory.usage_in_bytes").is_some()
+            }
+            // `/proc/<pid>/io` is only there if the kernel has `CONFIG_TASK_IO_ACCOUNTING`.
+            Feature::ProcessDiskUsage | Feature::ProcessSyscallCounts => {
+                File::open("/proc/self/io").is_ok()
+            }
+            Feature::PackagePower => PackageEnergy::is_available(),
+            Feature::MemoryPressureStall => Self::memory_pressure_avg10().is_some(),
+            Feature::ProcessDelays => {
+                crate::sys::taskstats::task_delays(Pid(std::process::id() as _)).is_some()
+            }
+            // `/proc/<pid>/schedstat` is only there if the kernel has `CONFIG_SCHED_INFO`.
+            Feature::ProcessSchedulingLatency => Path::new("/proc/self/schedstat").exists(),
+            Feature::ProcessUmask => get_all_utf8_data("/proc/self/status", 16_384)
+                .is_ok_and(|status| status.lines().any(|line| line.starts_with("Umask:"))),
+        }
+    }
+
+    pub(crate) fn tcp_memory() -> Option<TcpMemoryStats> {
+        let sockstat = get_all_utf8_data("/proc/net/sockstat", 1_024).ok()?;
+        let tcp_mem = get_all_utf8_data("/proc/sys/net/ipv4/tcp_mem", 128).ok()?;
+        parse_tcp_memory(&sockstat, &tcp_mem, page_size())
+    }
+
+    pub(crate) fn memory_pressure_avg10() -> Option<f32> {
+        get_all_utf8_data("/proc/pressure/memory", 256)
+            .ok()
+            .and_then(|data| parse_psi_some_avg10(&data))
+    }
+
+    pub(crate) fn pid_max() -> Option<Pid> {
+        get_all_utf8_data("/proc/sys/kernel/pid_max", 16)
+            .ok()
+            .and_then(|d| Pid::from_str(d.trim()).ok())
+    }
+
+    pub(crate) fn os_install_time() -> Option<SystemTime> {
+        // `/lost+found` is created with the filesystem but might not exist (or not be
+        // readable), so we fall back to `/`.
+        ["/lost+found", "/"]
+            .iter()
+            .find_map(|path| std::fs::metadata(path).and_then(|m| m.created()).ok())
+    }
+
+    pub(crate) fn current_memory_usage() -> Option<u64> {
+        let data = get_all_utf8_data("/proc/self/statm", 128).ok()?;
+        // The second field is the number of resident pages.
+        let resident_pages = u64::from_str(data.split_whitespace().nth(1)?).ok()?;
+        let page_size = unsafe { sysconf(_SC_PAGESIZE) };
+        if page_size <= 0 {
+            return None;
+        }
+        resident_pages.checked_mul(page_size as u64)
+    }
+
+    pub(crate) fn memory_errors() -> Option<MemoryErrors> {
+        read_memory_errors(Path::new("/sys/devices/system/edac/mc"))
+    }
+
+    pub(crate) fn interrupts_per_cpu() -> Option<InterruptStats> {
+        get_all_utf8_data("/proc/interrupts", 16_384)
+            .ok()
+            .and_then(|data| parse_interrupts(&data))
+    }
+
+    pub(crate) fn process_from_pidfd(fd: std::os::unix::io::RawFd) -> Option<Pid> {
+        if fd < 0 {
+            return None;
+        }
+        let data = get_all_utf8_data(format!("/proc/self/fdinfo/{fd}"), 256).ok()?;
+        parse_pidfd_fdinfo(&data)
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus = CpusWrapper::new();
         self.refresh_cpu_specifics(refresh_kind);
     }
 }
 
+fn parse_kernel_modules(data: &str) -> Vec<KernelModule> {
+    // Each line looks like: `name size use_count dependencies state address`.
+    data.lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let name = parts.next()?;
+            Some(KernelModule {
+                name: name.to_owned(),
+                size: parts.next().and_then(|s| u64::from_str(s).ok()),
+                // The use count is `-` if the kernel doesn't support module unloading.
+                use_count: parts.next().and_then(|s| u32::from_str(s).ok()),
+            })
+        })
+        .collect()
+}
+
+// The `Pid` field is only present for pidfds. It is `-1` if the process exited and `0` if it
+// isn't in the PID namespace of the current process.
+fn parse_pidfd_fdinfo(data: &str) -> Option<Pid> {
+    let pid = data
+        .lines()
+        .find_map(|line| line.strip_prefix("Pid:"))
+        .and_then(|pid| i32::from_str(pid.trim()).ok())?;
+    (pid > 0).then_some(Pid(pid))
+}
+
+// Each memory controller handled by EDAC has a `mcN` folder in `edac_mc`.
+fn read_memory_errors(edac_mc: &Path) -> Option<MemoryErrors> {
+    let mut controllers = std::fs::read_dir(edac_mc)
+        .ok()?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let index = usize::from_str(name.strip_prefix("mc")?).ok()?;
+            let count = |file| {
+                get_all_utf8_data(path.join(file), 32)
+                    .ok()
+                    .and_then(|data| u64::from_str(data.trim()).ok())
+            };
+            Some((
+                index,
+                MemoryControllerErrors {
+                    name: name.to_owned(),
+                    corrected: count("ce_count")?,
+                    uncorrected: count("ue_count")?,
+                },
+            ))
+        })
+        .collect::<Vec<_>>();
+    if controllers.is_empty() {
+        return None;
+    }
+    controllers.sort_unstable_by_key(|(index, _)| *index);
+    Some(MemoryErrors {
+        controllers: controllers
+            .into_iter()
+            .map(|(_, controller)| controller)
+            .collect(),
+    })
+}
+
+fn parse_interrupts(data: &str) -> Option<InterruptStats> {
+    let mut lines = data.lines();
+    // The first line lists the online CPUs: `CPU0 CPU1 ...`.
+    let cpus = lines
+        .next()?
+        .split_whitespace()
+        .map(|cpu| {
+            cpu.strip_prefix("CPU")
+                .and_then(|nb| usize::from_str(nb).ok())
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if cpus.is_empty() {
+        return None;
+    }
+    // Then each line looks like: `irq: count_cpu0 count_cpu1 ... description`.
+    let interrupts = lines
+        .filter_map(|line| {
+            let (irq, rest) = line.split_once(':')?;
+            let mut parts = rest.split_whitespace().peekable();
+            let mut counts = Vec::with_capacity(cpus.len());
+            while counts.len() < cpus.len() {
+                match parts.peek().and_then(|s| u64::from_str(s).ok()) {
+                    Some(count) => counts.push(count),
+                    None => break,
+                }
+                parts.next();
+            }
+            Some(Interrupt {
+                irq: irq.trim().to_owned(),
+                counts,
+                description: parts.collect::<Vec<_>>().join(" "),
+            })
+        })
+        .collect();
+    Some(InterruptStats { cpus, interrupts })
+}
+
 fn read_u64(filename: &str) -> Option<u64> {
     get_all_utf8_data(filename, 16_635)
         .ok()
@@ -762,16 +1166,131 @@ fn get_system_info_android(info: InfoType) -> Option<String> {
     }
 }
 
+// The active mode is between brackets, like `always [madvise] never`.
+fn parse_thp_mode(data: &str) -> Option<ThpMode> {
+    let mode = data
+        .split_whitespace()
+        .find_map(|mode| mode.strip_prefix('[')?.strip_suffix(']'))?;
+    match mode {
+        "always" => Some(ThpMode::Always),
+        "madvise" => Some(ThpMode::Madvise),
+        "never" => Some(ThpMode::Never),
+        _ => None,
+    }
+}
+
+// Returns the swap-in, swap-out and major page fault counters from `/proc/vmstat`.
+fn parse_vm_counters(data: &str) -> Option<VmCounters> {
+    let mut swap_in = None;
+    let mut swap_out = None;
+    let mut major_faults = None;
+    for line in data.lines() {
+        let Some((key, value)) = line.split_once(' ') else {
+            continue;
+        };
+        let field = match key {
+            "pswpin" => &mut swap_in,
+            "pswpout" => &mut swap_out,
+            "pgmajfault" => &mut major_faults,
+            _ => continue,
+        };
+        *field = u64::from_str(value.trim()).ok();
+    }
+    Some(VmCounters {
+        swap_in: swap_in?,
+        swap_out: swap_out?,
+        major_faults: major_faults?,
+    })
+}
+
+// Returns the number of pages currently in the memory balloon from `/proc/vmstat`. Recent
+// kernels have it in `nr_balloon_pages`, otherwise we compute it from the counters of pages
+// inflated (taken by the hypervisor) and deflated (given back) since boot. These entries only
+// exist if the kernel was built with the memory balloon support.
+fn parse_balloon_pages(data: &str) -> Option<u64> {
+    let mut inflated = None;
+    let mut deflated = None;
+    for line in data.lines() {
+        match line.split_once(' ') {
+            Some(("nr_balloon_pages", value)) => return value.trim().parse().ok(),
+            Some(("balloon_inflate", value)) => inflated = value.trim().parse::<u64>().ok(),
+            Some(("balloon_deflate", value)) => deflated = value.trim().parse::<u64>().ok(),
+            _ => {}
+        }
+    }
+    Some(inflated?.saturating_sub(deflated?))
+}
+
+// Returns the `avg10` value of the `some` line of a pressure stall information (PSI) file, which
+// is the percentage of time during the last 10 seconds where at least one task was stalled.
+fn parse_psi_some_avg10(data: &str) -> Option<f32> {
+    data.lines()
+        .find_map(|line| line.strip_prefix("some "))?
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("avg10="))?
+        .parse()
+        .ok()
+}
+
+// Parses the `/proc/net/sockstat` and `/proc/sys/net/ipv4/tcp_mem` files. Memory values are
+// in pages in both files.
+fn parse_tcp_memory(sockstat: &str, tcp_mem: &str, page_size: u64) -> Option<TcpMemoryStats> {
+    let mut stats = TcpMemoryStats::default();
+    let mut has_tcp = false;
+    for line in sockstat.lines() {
+        let Some((protocol, fields)) = line.split_once(':') else {
+            continue;
+        };
+        let mut fields = fields.split_whitespace();
+        while let (Some(key), Some(value)) = (fields.next(), fields.next()) {
+            let Ok(value) = u64::from_str(value) else {
+                continue;
+            };
+            match (protocol, key) {
+                ("sockets", "used") => stats.sockets_used = value,
+                ("TCP", "inuse") => stats.tcp_in_use = value,
+                ("TCP", "orphan") => stats.orphans = value,
+                ("TCP", "tw") => stats.time_wait = value,
+                ("TCP", "mem") => {
+                    stats.memory = value.saturating_mul(page_size);
+                    has_tcp = true;
+                }
+                _ => {}
+            }
+        }
+    }
+    if !has_tcp {
+        return None;
+    }
+    let mut tcp_mem = tcp_mem
+        .split_whitespace()
+        .map(|value| u64::from_str(value).map(|value| value.saturating_mul(page_size)));
+    stats.memory_min = tcp_mem.next()?.ok()?;
+    stats.memory_pressure = tcp_mem.next()?.ok()?;
+    stats.memory_max = tcp_mem.next()?.ok()?;
+    Some(stats)
+}
+
 #[cfg(test)]
 mod test {
     #[cfg(target_os = "android")]
     use super::get_system_info_android;
     #[cfg(not(target_os = "android"))]
     use super::get_system_info_linux;
+    use super::parse_balloon_pages;
+    use super::parse_interrupts;
+    use super::parse_kernel_modules;
+    use super::parse_pidfd_fdinfo;
+    use super::parse_psi_some_avg10;
+    use super::parse_tcp_memory;
+    use super::parse_thp_mode;
+    use super::parse_vm_counters;
+    use super::read_memory_errors;
     use super::read_table;
     use super::read_table_key;
     use super::system_info_as_list;
     use super::InfoType;
+    use crate::ThpMode;
     use std::collections::HashMap;
     use std::io::Write;
     use tempfile::NamedTempFile;
@@ -978,4 +1497,199 @@ DISTRIB_DESCRIPTION="Ubuntu 20.10"
             vec!["rhel".to_string(), "fedora".to_string()],
         );
     }
+
+    #[test]
+    fn test_parse_kernel_modules() {
+        let content = "\
+nvidia_uvm 1523712 2 - Live 0x0000000000000000 (PO)
+snd_hda_intel 61440 4 - Live 0x0000000000000000
+mbcache 16384 1 ext4, Live 0x0000000000000000
+no_unload 4096 - - Live 0x0000000000000000
+";
+        let modules = parse_kernel_modules(content);
+        assert_eq!(modules.len(), 4);
+        assert_eq!(modules[0].name, "nvidia_uvm");
+        assert_eq!(modules[0].size, Some(1_523_712));
+        assert_eq!(modules[0].use_count, Some(2));
+        assert_eq!(modules[2].name, "mbcache");
+        assert_eq!(modules[2].use_count, Some(1));
+        assert_eq!(modules[3].use_count, None);
+        assert!(parse_kernel_modules("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_pidfd_fdinfo() {
+        use crate::Pid;
+
+        let content =
+            "pos:\t0\nflags:\t02000002\nmnt_id:\t15\nino:\t1057\nPid:\t1234\nNSpid:\t1234\n";
+        assert_eq!(parse_pidfd_fdinfo(content), Some(Pid(1234)));
+        assert_eq!(parse_pidfd_fdinfo("pos:\t0\nPid:\t-1\nNSpid:\t-1\n"), None);
+        assert_eq!(parse_pidfd_fdinfo("pos:\t0\nPid:\t0\n"), None);
+        // Not a pidfd.
+        assert_eq!(
+            parse_pidfd_fdinfo("pos:\t0\nflags:\t0100000\nmnt_id:\t25\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_interrupts() {
+        let content = "\
+           CPU0       CPU2       
+  0:         44          0   IO-APIC   2-edge      timer
+ 24:       1523         12  PCI-MSI 327680-edge      xhci_hcd
+NMI:          3          1   Non-maskable interrupts
+LOC:     918273     817263   Local timer interrupts
+ERR:          0
+";
+        let stats = parse_interrupts(content).expect("failed to parse");
+        assert_eq!(stats.cpus, vec![0, 2]);
+        assert_eq!(stats.interrupts.len(), 5);
+        assert_eq!(stats.interrupts[0].irq, "0");
+        assert_eq!(stats.interrupts[0].counts, vec![44, 0]);
+        assert_eq!(stats.interrupts[0].description, "IO-APIC 2-edge timer");
+        assert_eq!(stats.interrupts[1].irq, "24");
+        assert_eq!(
+            stats.interrupts[1].description,
+            "PCI-MSI 327680-edge xhci_hcd"
+        );
+        assert_eq!(stats.interrupts[2].irq, "NMI");
+        assert_eq!(stats.interrupts[2].counts, vec![3, 1]);
+        assert_eq!(stats.interrupts[4].irq, "ERR");
+        assert_eq!(stats.interrupts[4].counts, vec![0]);
+        assert_eq!(stats.interrupts[4].description, "");
+        assert_eq!(stats.total_per_cpu(), vec![919_843, 817_276]);
+        assert!(parse_interrupts("").is_none());
+    }
+
+    #[test]
+    fn test_read_memory_errors() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let edac_mc = dir.path();
+        assert!(read_memory_errors(edac_mc).is_none());
+
+        for (mc, ce_count, ue_count) in [("mc10", "1\n", "0\n"), ("mc2", "42\n", "3\n")] {
+            std::fs::create_dir(edac_mc.join(mc)).unwrap();
+            std::fs::write(edac_mc.join(mc).join("ce_count"), ce_count).unwrap();
+            std::fs::write(edac_mc.join(mc).join("ue_count"), ue_count).unwrap();
+        }
+        // Not a memory controller.
+        std::fs::create_dir(edac_mc.join("power")).unwrap();
+
+        let errors = read_memory_errors(edac_mc).expect("failed to read memory errors");
+        let controllers = errors
+            .controllers
+            .iter()
+            .map(|c| (c.name.as_str(), c.corrected, c.uncorrected))
+            .collect::<Vec<_>>();
+        assert_eq!(controllers, [("mc2", 42, 3), ("mc10", 1, 0)]);
+        assert_eq!(errors.total_corrected(), 43);
+        assert_eq!(errors.total_uncorrected(), 3);
+        assert!(read_memory_errors(&edac_mc.join("missing")).is_none());
+    }
+
+    #[test]
+    fn test_parse_thp_mode() {
+        assert_eq!(
+            parse_thp_mode("always [madvise] never\n"),
+            Some(ThpMode::Madvise)
+        );
+        assert_eq!(
+            parse_thp_mode("[always] madvise never\n"),
+            Some(ThpMode::Always)
+        );
+        assert_eq!(
+            parse_thp_mode("always madvise [never]\n"),
+            Some(ThpMode::Never)
+        );
+        assert_eq!(parse_thp_mode("always madvise never\n"), None);
+        assert_eq!(parse_thp_mode(""), None);
+    }
+
+    #[test]
+    fn test_parse_balloon_pages() {
+        assert_eq!(
+            parse_balloon_pages("nr_free_pages 123\nballoon_inflate 300\nballoon_deflate 100\n"),
+            Some(200)
+        );
+        assert_eq!(
+            parse_balloon_pages("balloon_inflate 0\nballoon_deflate 0\nballoon_migrate 0\n"),
+            Some(0)
+        );
+        assert_eq!(
+            parse_balloon_pages("nr_balloon_pages 50\nballoon_inflate 300\nballoon_deflate 100\n"),
+            Some(50)
+        );
+        // The kernel doesn't support memory ballooning.
+        assert_eq!(
+            parse_balloon_pages("nr_free_pages 123\npgfault 456\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_psi_some_avg10() {
+        let content = "\
+some avg10=12.50 avg60=3.00 avg300=0.64 total=4055910
+full avg10=1.25 avg60=0.50 avg300=0.10 total=1892550
+";
+        assert_eq!(parse_psi_some_avg10(content), Some(12.5));
+        assert_eq!(
+            parse_psi_some_avg10("full avg10=1.25 avg60=0.50 avg300=0.10 total=1892550\n"),
+            None
+        );
+        assert_eq!(parse_psi_some_avg10(""), None);
+    }
+
+    #[test]
+    fn test_parse_vm_counters() {
+        let content = "\
+nr_free_pages 123
+pgpgin 4567
+pswpin 12
+pswpout 34
+pgfault 456
+pgmajfault 78
+";
+        assert_eq!(
+            parse_vm_counters(content),
+            Some(super::VmCounters {
+                swap_in: 12,
+                swap_out: 34,
+                major_faults: 78,
+            })
+        );
+        assert_eq!(parse_vm_counters("nr_free_pages 123\npgfault 456\n"), None);
+    }
+
+    #[test]
+    fn test_parse_tcp_memory() {
+        let sockstat = "\
+sockets: used 294
+TCP: inuse 10 orphan 1 tw 2 alloc 12 mem 3
+UDP: inuse 3 mem 2
+UDPLITE: inuse 0
+RAW: inuse 0
+FRAG: inuse 0 memory 0
+";
+        assert_eq!(
+            parse_tcp_memory(sockstat, "188760\t251683\t377520\n", 4_096),
+            Some(crate::TcpMemoryStats {
+                sockets_used: 294,
+                tcp_in_use: 10,
+                orphans: 1,
+                time_wait: 2,
+                memory: 3 * 4_096,
+                memory_min: 188_760 * 4_096,
+                memory_pressure: 251_683 * 4_096,
+                memory_max: 377_520 * 4_096,
+            })
+        );
+        assert_eq!(parse_tcp_memory(sockstat, "188760\t251683\n", 4_096), None);
+        assert_eq!(
+            parse_tcp_memory("sockets: used 294\n", "188760\t251683\t377520\n", 4_096),
+            None
+        );
+    }
 }
@@ -0,0 +1,295 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Delay accounting, retrieved with the taskstats generic netlink interface. It is described in
+// `Documentation/accounting/taskstats.rst` and `include/uapi/linux/taskstats.h` in the kernel
+// sources.
+
+use crate::{Pid, TaskDelays};
+
+use std::io;
+use std::mem::{size_of, zeroed};
+use std::time::Duration;
+
+// Values from `linux/genetlink.h`.
+const GENL_ID_CTRL: u16 = 0x10;
+const CTRL_CMD_GETFAMILY: u8 = 3;
+const CTRL_ATTR_FAMILY_ID: u16 = 1;
+const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+
+// Values from `linux/taskstats.h`.
+const TASKSTATS_GENL_NAME: &[u8] = b"TASKSTATS\0";
+const TASKSTATS_GENL_VERSION: u8 = 1;
+const TASKSTATS_CMD_GET: u8 = 1;
+const TASKSTATS_CMD_ATTR_TGID: u16 = 2;
+const TASKSTATS_TYPE_STATS: u16 = 3;
+const TASKSTATS_TYPE_AGGR_TGID: u16 = 5;
+
+const NLMSG_HDR_LEN: usize = 16;
+// `cmd`, `version` and `reserved`.
+const GENL_HDR_LEN: usize = 4;
+const NLA_HDR_LEN: usize = 4;
+// Removes the `NLA_F_NESTED` and `NLA_F_NET_BYTEORDER` flags from the attribute type.
+const NLA_TYPE_MASK: u16 = 0x3fff;
+
+// Offsets of the fields we use in `struct taskstats`.
+const CPU_DELAY_TOTAL: usize = 24;
+const BLKIO_DELAY_TOTAL: usize = 40;
+const SWAPIN_DELAY_TOTAL: usize = 56;
+// Since version 5.
+const FREEPAGES_DELAY_TOTAL: usize = 320;
+// Since version 7.
+const THRASHING_DELAY_TOTAL: usize = 336;
+// Since version 11.
+const COMPACT_DELAY_TOTAL: usize = 360;
+
+struct GenericNetlinkSocket(libc::c_int);
+
+impl GenericNetlinkSocket {
+    fn new() -> Option<Self> {
+        unsafe {
+            let fd = libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                libc::NETLINK_GENERIC,
+            );
+            if fd < 0 {
+                return None;
+            }
+            // From now on, `drop` takes care of closing the socket.
+            let socket = Self(fd);
+
+            let mut addr: libc::sockaddr_nl = zeroed();
+            addr.nl_family = libc::AF_NETLINK as _;
+            if libc::bind(
+                fd,
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                size_of::<libc::sockaddr_nl>() as _,
+            ) < 0
+            {
+                return None;
+            }
+            Some(socket)
+        }
+    }
+
+    // Sends `message` and returns the attributes of the answer.
+    fn request<'a>(&self, message: &[u8], buffer: &'a mut [u8]) -> Option<&'a [u8]> {
+        let ret = unsafe {
+            libc::send(
+                self.0,
+                message.as_ptr() as *const libc::c_void,
+                message.len(),
+                0,
+            )
+        };
+        if ret < 0 {
+            return None;
+        }
+        loop {
+            let ret = unsafe {
+                libc::recv(
+                    self.0,
+                    buffer.as_mut_ptr() as *mut libc::c_void,
+                    buffer.len(),
+                    0,
+                )
+            };
+            if ret < 0 {
+                if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return None;
+            }
+            return parse_answer(&buffer[..ret as usize]);
+        }
+    }
+}
+
+impl Drop for GenericNetlinkSocket {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.0);
+        }
+    }
+}
+
+pub(crate) fn task_delays(pid: Pid) -> Option<TaskDelays> {
+    let socket = GenericNetlinkSocket::new()?;
+    let mut buffer = vec![0; 4096];
+
+    let message = build_message(
+        GENL_ID_CTRL,
+        CTRL_CMD_GETFAMILY,
+        1,
+        CTRL_ATTR_FAMILY_NAME,
+        TASKSTATS_GENL_NAME,
+    );
+    let family_id = attributes(socket.request(&message, &mut buffer)?)
+        .find(|(kind, _)| *kind == CTRL_ATTR_FAMILY_ID)
+        .and_then(|(_, value)| read_u16(value, 0))?;
+
+    let message = build_message(
+        family_id,
+        TASKSTATS_CMD_GET,
+        TASKSTATS_GENL_VERSION,
+        TASKSTATS_CMD_ATTR_TGID,
+        &(pid.0 as u32).to_ne_bytes(),
+    );
+    let (_, aggregated) = attributes(socket.request(&message, &mut buffer)?)
+        .find(|(kind, _)| *kind == TASKSTATS_TYPE_AGGR_TGID)?;
+    let (_, stats) = attributes(aggregated).find(|(kind, _)| *kind == TASKSTATS_TYPE_STATS)?;
+    parse_taskstats(stats)
+}
+
+fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+// Builds a generic netlink request with one attribute.
+fn build_message(family: u16, cmd: u8, version: u8, attr_type: u16, attr: &[u8]) -> Vec<u8> {
+    let attr_len = NLA_HDR_LEN + attr.len();
+    let len = NLMSG_HDR_LEN + GENL_HDR_LEN + align(attr_len);
+    let mut message = Vec::with_capacity(len);
+    // `nlmsghdr`.
+    message.extend_from_slice(&(len as u32).to_ne_bytes());
+    message.extend_from_slice(&family.to_ne_bytes());
+    message.extend_from_slice(&(libc::NLM_F_REQUEST as u16).to_ne_bytes());
+    // `nlmsg_seq` and `nlmsg_pid`.
+    message.extend_from_slice(&[0; 8]);
+    // `genlmsghdr`.
+    message.extend_from_slice(&[cmd, version, 0, 0]);
+    // `nlattr`.
+    message.extend_from_slice(&(attr_len as u16).to_ne_bytes());
+    message.extend_from_slice(&attr_type.to_ne_bytes());
+    message.extend_from_slice(attr);
+    message.resize(len, 0);
+    message
+}
+
+// Returns the attributes of a generic netlink answer, or `None` if it's an error.
+fn parse_answer(data: &[u8]) -> Option<&[u8]> {
+    let len = read_u32(data, 0)? as usize;
+    if read_u16(data, 4)? == libc::NLMSG_ERROR as u16 {
+        return None;
+    }
+    data.get(NLMSG_HDR_LEN + GENL_HDR_LEN..len)
+}
+
+// Returns the type and the payload of each netlink attribute in `data`.
+fn attributes(mut data: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+    std::iter::from_fn(move || {
+        let len = read_u16(data, 0)? as usize;
+        let kind = read_u16(data, 2)? & NLA_TYPE_MASK;
+        let payload = data.get(NLA_HDR_LEN..len)?;
+        data = data.get(align(len)..).unwrap_or_default();
+        Some((kind, payload))
+    })
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_ne_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    data.get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_ne_bytes)
+}
+
+// Parses a `struct taskstats`. The fields added by later versions are `0` if they are missing.
+fn parse_taskstats(data: &[u8]) -> Option<TaskDelays> {
+    let delay = |offset| Duration::from_nanos(read_u64(data, offset).unwrap_or(0));
+    Some(TaskDelays {
+        cpu: Duration::from_nanos(read_u64(data, CPU_DELAY_TOTAL)?),
+        block_io: Duration::from_nanos(read_u64(data, BLKIO_DELAY_TOTAL)?),
+        swap_in: Duration::from_nanos(read_u64(data, SWAPIN_DELAY_TOTAL)?),
+        memory_reclaim: delay(FREEPAGES_DELAY_TOTAL),
+        thrashing: delay(THRASHING_DELAY_TOTAL),
+        compaction: delay(COMPACT_DELAY_TOTAL),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_build_message() {
+        let message = build_message(0x1a, TASKSTATS_CMD_GET, 1, TASKSTATS_CMD_ATTR_TGID, &[1; 4]);
+        assert_eq!(
+            message.len(),
+            NLMSG_HDR_LEN + GENL_HDR_LEN + NLA_HDR_LEN + 4
+        );
+        assert_eq!(read_u32(&message, 0), Some(message.len() as u32));
+        assert_eq!(read_u16(&message, 4), Some(0x1a));
+        assert_eq!(message[NLMSG_HDR_LEN], TASKSTATS_CMD_GET);
+
+        // The attribute is padded.
+        let message = build_message(GENL_ID_CTRL, CTRL_CMD_GETFAMILY, 1, 2, TASKSTATS_GENL_NAME);
+        assert_eq!(
+            message.len(),
+            NLMSG_HDR_LEN + GENL_HDR_LEN + NLA_HDR_LEN + 12
+        );
+        let answer = parse_answer(&message).unwrap();
+        assert_eq!(
+            attributes(answer).collect::<Vec<_>>(),
+            [(CTRL_ATTR_FAMILY_NAME, TASKSTATS_GENL_NAME)]
+        );
+    }
+
+    #[test]
+    fn test_attributes() {
+        let mut data = Vec::new();
+        for (kind, payload) in [(1u16, &[1u8, 2][..]), (0x8004, &[3, 4, 5, 6][..])] {
+            data.extend_from_slice(&(NLA_HDR_LEN as u16 + payload.len() as u16).to_ne_bytes());
+            data.extend_from_slice(&kind.to_ne_bytes());
+            data.extend_from_slice(payload);
+            data.resize(align(data.len()), 0);
+        }
+        // Truncated attribute.
+        data.extend_from_slice(&[12, 0, 5, 0]);
+        assert_eq!(
+            attributes(&data).collect::<Vec<_>>(),
+            [(1, &[1, 2][..]), (4, &[3, 4, 5, 6][..])]
+        );
+    }
+
+    #[test]
+    fn test_parse_taskstats() {
+        let mut data = vec![0; COMPACT_DELAY_TOTAL + 8];
+        for (offset, value) in [
+            (CPU_DELAY_TOTAL, 1_000u64),
+            (BLKIO_DELAY_TOTAL, 2_000),
+            (SWAPIN_DELAY_TOTAL, 3_000),
+            (FREEPAGES_DELAY_TOTAL, 4_000),
+            (THRASHING_DELAY_TOTAL, 5_000),
+            (COMPACT_DELAY_TOTAL, 6_000),
+        ] {
+            data[offset..offset + 8].copy_from_slice(&value.to_ne_bytes());
+        }
+        assert_eq!(
+            parse_taskstats(&data),
+            Some(TaskDelays {
+                cpu: Duration::from_micros(1),
+                block_io: Duration::from_micros(2),
+                swap_in: Duration::from_micros(3),
+                memory_reclaim: Duration::from_micros(4),
+                thrashing: Duration::from_micros(5),
+                compaction: Duration::from_micros(6),
+            })
+        );
+
+        // Version 4 doesn't have the memory reclaim delays.
+        data.truncate(FREEPAGES_DELAY_TOTAL - 8);
+        let delays = parse_taskstats(&data).unwrap();
+        assert_eq!(delays.swap_in, Duration::from_micros(3));
+        assert_eq!(delays.memory_reclaim, Duration::ZERO);
+        assert_eq!(parse_taskstats(&data[..SWAPIN_DELAY_TOTAL]), None);
+    }
+}
@@ -1,10 +1,27 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-#[cfg(any(feature = "disk", feature = "system"))]
+#[cfg(any(
+    feature = "disk",
+    feature = "gpu",
+    feature = "system",
+    feature = "network"
+))]
 use std::fs::File;
-#[cfg(any(feature = "disk", feature = "system"))]
-use std::io::{self, Read, Seek};
-#[cfg(any(feature = "disk", feature = "system"))]
+#[cfg(any(
+    feature = "disk",
+    feature = "gpu",
+    feature = "system",
+    feature = "network"
+))]
+use std::io;
+#[cfg(any(feature = "disk", feature = "gpu", feature = "system"))]
+use std::io::{Read, Seek};
+#[cfg(any(
+    feature = "disk",
+    feature = "gpu",
+    feature = "system",
+    feature = "network"
+))]
 use std::path::Path;
 
 #[cfg(feature = "system")]
@@ -15,7 +32,7 @@ pub(crate) fn get_all_data_from_file(file: &mut File, size: usize) -> io::Result
     Ok(buf)
 }
 
-#[cfg(any(feature = "disk", feature = "system"))]
+#[cfg(any(feature = "disk", feature = "gpu", feature = "system"))]
 pub(crate) fn get_all_utf8_data_from_file(file: &mut File, size: usize) -> io::Result<String> {
     let mut buf = String::with_capacity(size);
     file.rewind()?;
@@ -23,12 +40,47 @@ pub(crate) fn get_all_utf8_data_from_file(file: &mut File, size: usize) -> io::R
     Ok(buf)
 }
 
-#[cfg(any(feature = "disk", feature = "system"))]
+#[cfg(any(feature = "disk", feature = "gpu", feature = "system"))]
 pub(crate) fn get_all_utf8_data<P: AsRef<Path>>(file_path: P, size: usize) -> io::Result<String> {
+    #[cfg(feature = "linux-replay")]
+    if let Some(content) = crate::sys::replay::replayed_file(file_path.as_ref()) {
+        return content;
+    }
     let mut file = File::open(file_path.as_ref())?;
     get_all_utf8_data_from_file(&mut file, size)
 }
 
+/// Opens the file for reading. Only needed for files which can be replayed.
+#[cfg(all(
+    any(feature = "system", feature = "network"),
+    not(feature = "linux-replay")
+))]
+pub(crate) fn open_file<P: AsRef<Path>>(path: P) -> io::Result<File> {
+    File::open(path)
+}
+
+/// Opens the file for reading. Only needed for files which can be replayed.
+#[cfg(all(any(feature = "system", feature = "network"), feature = "linux-replay"))]
+pub(crate) fn open_file<P: AsRef<Path>>(path: P) -> io::Result<Box<dyn io::Read>> {
+    match crate::sys::replay::replayed_file(path.as_ref()) {
+        Some(content) => Ok(Box::new(io::Cursor::new(content?.into_bytes()))),
+        None => Ok(Box::new(File::open(path)?)),
+    }
+}
+
+/// Returns the paths of the entries of the `path` folder.
+#[cfg(feature = "network")]
+pub(crate) fn read_dir_paths(path: &Path) -> io::Result<Vec<std::path::PathBuf>> {
+    #[cfg(feature = "linux-replay")]
+    if let Some(entries) = crate::sys::replay::replayed_dir(path) {
+        return Ok(entries);
+    }
+    Ok(std::fs::read_dir(path)?
+        .flatten()
+        .map(|entry| entry.path())
+        .collect())
+}
+
 #[cfg(feature = "system")]
 #[allow(clippy::useless_conversion)]
 pub(crate) fn realpath(path: &Path) -> Option<std::path::PathBuf> {
@@ -30,11 +30,18 @@ cfg_if! {
     if #[cfg(feature = "disk")] {
         pub(crate) struct DisksInner {
             pub(crate) disks: Vec<crate::Disk>,
+            // The `/proc/<pid>` folder of the process whose mount namespace is used.
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            pub(crate) namespace_proc_path: Option<std::path::PathBuf>,
         }
 
         impl DisksInner {
             pub(crate) fn from_vec(disks: Vec<crate::Disk>) -> Self {
-                Self { disks }
+                Self {
+                    disks,
+                    #[cfg(any(target_os = "linux", target_os = "android"))]
+                    namespace_proc_path: None,
+                }
             }
 
             pub(crate) fn into_vec(self) -> Vec<crate::Disk> {
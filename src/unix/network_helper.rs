@@ -125,14 +125,14 @@ pub(crate) unsafe fn get_interface_address() -> Result<InterfaceAddressIterator,
     }
 }
 
-pub(crate) unsafe fn get_interface_ip_networks() -> HashMap<String, HashSet<IpNetwork>> {
+pub(crate) unsafe fn get_interface_ip_networks() -> Option<HashMap<String, HashSet<IpNetwork>>> {
     let mut ifaces: HashMap<String, HashSet<IpNetwork>> = HashMap::new();
     let mut addrs: MaybeUninit<*mut libc::ifaddrs> = MaybeUninit::uninit();
 
     // Safety: addrs.as_mut_ptr() is valid, it points to addrs.
     if libc::getifaddrs(addrs.as_mut_ptr()) != 0 {
         sysinfo_debug!("Failed to operate libc::getifaddrs as ifaddrs Uninitialized");
-        return ifaces;
+        return None;
     }
 
     // Safety: If there was an error, we would have already returned.
@@ -181,7 +181,7 @@ pub(crate) unsafe fn get_interface_ip_networks() -> HashMap<String, HashSet<IpNe
 
     // Safety: addrs has been previously allocated through getifaddrs
     libc::freeifaddrs(addrs);
-    ifaces
+    Some(ifaces)
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
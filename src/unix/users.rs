@@ -8,7 +8,7 @@ use crate::{
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
 use crate::User;
 
-use libc::{getgrgid_r, getgrouplist};
+use libc::{getgrgid_r, getgrouplist, getpwuid_r};
 
 pub(crate) struct UserInner {
     pub(crate) uid: Uid,
@@ -78,6 +78,42 @@ pub(crate) unsafe fn get_group_name(
     super::utils::cstr_to_rust(g.gr_name)
 }
 
+pub(crate) fn get_user_name(id: &Uid) -> Option<String> {
+    let mut buffer: Vec<libc::c_char> = Vec::with_capacity(2048);
+    let mut p = std::mem::MaybeUninit::<libc::passwd>::uninit();
+    let mut tmp_ptr: *mut libc::passwd = std::ptr::null_mut();
+    let mut last_errno = 0;
+    unsafe {
+        loop {
+            if retry_eintr!(set_to_0 => last_errno => getpwuid_r(
+                id.0 as _,
+                p.as_mut_ptr() as _,
+                buffer.as_mut_ptr(),
+                buffer.capacity() as _,
+                &mut tmp_ptr as _
+            )) != 0
+            {
+                // If there was not enough memory, we give it more.
+                if last_errno == libc::ERANGE as _ {
+                    // Needs to be updated for `Vec::reserve` to actually add additional capacity.
+                    // In here it's "fine" since we never read from `buffer`.
+                    buffer.set_len(buffer.capacity());
+                    buffer.reserve(2048);
+                    continue;
+                }
+                return None;
+            }
+            break;
+        }
+        // No entry was found for this user ID.
+        if tmp_ptr.is_null() {
+            return None;
+        }
+        let p = p.assume_init();
+        super::utils::cstr_to_rust(p.pw_name)
+    }
+}
+
 pub(crate) unsafe fn get_user_groups(
     name: *const libc::c_char,
     group_id: libc::gid_t,
@@ -35,6 +35,65 @@ pub(crate) fn cstr_to_rust_with_size(
     }
 }
 
+// Returns the name of a timezone from a path like `/usr/share/zoneinfo/Europe/Paris`.
+#[cfg(feature = "system")]
+fn timezone_from_path(path: &str) -> Option<String> {
+    let name = match path.rfind("zoneinfo/") {
+        Some(pos) => &path[pos + "zoneinfo/".len()..],
+        None => path,
+    };
+    (!name.is_empty()).then(|| name.to_owned())
+}
+
+/// Returns the timezone from the `TZ` environment variable or, if it isn't set, from the
+/// `/etc/localtime` link.
+#[cfg(feature = "system")]
+pub(crate) fn get_timezone() -> Option<String> {
+    if let Ok(tz) = std::env::var("TZ") {
+        // `TZ` can be prefixed with `:` to tell it is a file.
+        if let Some(tz) = timezone_from_path(tz.strip_prefix(':').unwrap_or(&tz)) {
+            return Some(tz);
+        }
+    }
+    let target = std::fs::read_link("/etc/localtime").ok()?;
+    timezone_from_path(target.to_str()?)
+}
+
+/// Returns the locale from the `LC_ALL` or `LANG` environment variables.
+#[cfg(feature = "system")]
+pub(crate) fn get_locale() -> Option<String> {
+    ["LC_ALL", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
+/// Returns `true` if the current process runs as root.
+#[cfg(feature = "system")]
+pub(crate) fn is_elevated() -> bool {
+    unsafe { libc::geteuid() == 0 }
+}
+
+/// Returns the size of a memory page (in bytes), or `0` if it couldn't be retrieved.
+#[cfg(feature = "system")]
+pub(crate) fn page_size() -> u64 {
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if page_size <= 0 {
+        return 0;
+    }
+    page_size as u64
+}
+
+/// Returns the number of online logical CPUs, or `0` if it couldn't be retrieved.
+#[cfg(feature = "system")]
+pub(crate) fn logical_cpu_count() -> usize {
+    let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
+    if count <= 0 {
+        return 0;
+    }
+    count as usize
+}
+
 #[cfg(all(
     feature = "system",
     not(any(
@@ -58,3 +117,27 @@ pub(crate) fn wait_process(pid: crate::Pid) -> Option<std::process::ExitStatus>
         Some(std::process::ExitStatus::from_raw(status))
     }
 }
+
+#[cfg(all(test, feature = "system"))]
+mod test {
+    use super::timezone_from_path;
+
+    #[test]
+    fn test_timezone_from_path() {
+        assert_eq!(
+            timezone_from_path("/usr/share/zoneinfo/America/New_York").as_deref(),
+            Some("America/New_York"),
+        );
+        assert_eq!(
+            timezone_from_path("../usr/share/zoneinfo/UTC").as_deref(),
+            Some("UTC")
+        );
+        assert_eq!(
+            timezone_from_path("/var/db/timezone/zoneinfo/Europe/Paris").as_deref(),
+            Some("Europe/Paris"),
+        );
+        assert_eq!(timezone_from_path("EST5EDT").as_deref(), Some("EST5EDT"));
+        assert_eq!(timezone_from_path("/usr/share/zoneinfo/"), None);
+        assert_eq!(timezone_from_path(""), None);
+    }
+}
@@ -46,6 +46,8 @@ impl DiskInner {
     pub(crate) fn usage(&self) -> DiskUsage {
         DiskUsage::default()
     }
+
+    pub(crate) fn restore_usage(&mut self, _usage: &DiskUsage) {}
 }
 
 pub(crate) struct DisksInner {
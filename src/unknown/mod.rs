@@ -24,6 +24,10 @@ cfg_if! {
         pub(crate) use self::component::{ComponentInner, ComponentsInner};
     }
 
+    if #[cfg(feature = "gpu")] {
+        pub(crate) use crate::gpu::{GpuInner, GpusInner};
+    }
+
     if #[cfg(feature = "network")] {
         pub mod network;
 
@@ -35,7 +39,7 @@ cfg_if! {
         pub mod users;
 
         pub(crate) use self::groups::get_groups;
-        pub(crate) use self::users::{get_users, UserInner};
+        pub(crate) use self::users::{get_user_name, get_users, UserInner};
     }
 }
 
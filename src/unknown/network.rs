@@ -1,8 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::{IpNetwork, MacAddr, NetworkCounters, NetworkData};
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 pub(crate) struct NetworksInner {
     pub(crate) interfaces: HashMap<String, NetworkData>,
@@ -25,6 +26,12 @@ impl NetworksInner {
 pub(crate) struct NetworkDataInner;
 
 impl NetworkDataInner {
+    pub(crate) fn restore_counters(&mut self, _counters: &NetworkCounters) {}
+
+    pub(crate) fn last_refresh(&self) -> Duration {
+        Duration::ZERO
+    }
+
     pub(crate) fn received(&self) -> u64 {
         0
     }
@@ -73,6 +80,22 @@ impl NetworkDataInner {
         0
     }
 
+    pub(crate) fn packets_dropped_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_packets_dropped_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn packets_dropped_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_packets_dropped_on_transmitted(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         MacAddr::UNSPECIFIED
     }
@@ -81,7 +104,19 @@ impl NetworkDataInner {
         &[]
     }
 
+    pub(crate) fn addresses_changed(&self) -> bool {
+        false
+    }
+
     pub(crate) fn mtu(&self) -> u64 {
         0
     }
+
+    pub(crate) fn is_updated(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn elapsed_since_last_refresh(&self) -> Option<Duration> {
+        None
+    }
 }
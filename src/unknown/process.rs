@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{DiskUsage, Gid, Pid, ProcessCpuCounters, ProcessStatus, Signal, Uid};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -87,6 +87,14 @@ impl ProcessInner {
         DiskUsage::default()
     }
 
+    pub(crate) fn restore_disk_usage(&mut self, _usage: &DiskUsage) {}
+
+    pub(crate) fn cpu_counters(&self) -> ProcessCpuCounters {
+        ProcessCpuCounters::default()
+    }
+
+    pub(crate) fn restore_cpu_counters(&mut self, _counters: &ProcessCpuCounters) {}
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         None
     }
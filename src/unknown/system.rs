@@ -1,12 +1,12 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    Cpu, CpuCounters, CpuRefreshKind, InterruptStats, KernelModule, LoadAvg, MemoryErrors,
+    MemoryRefreshKind, Pid, Process, ProcessEvent, ProcessRefreshKind, ProcessesToUpdate,
 };
 
 use std::collections::HashMap;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 declare_signals! {
     (),
@@ -31,6 +31,20 @@ impl SystemInner {
 
     pub(crate) fn refresh_memory_specifics(&mut self, _refresh_kind: MemoryRefreshKind) {}
 
+    pub(crate) fn refresh_swap_rates(&mut self) {}
+
+    pub(crate) fn swap_in_per_second(&self) -> f64 {
+        0.
+    }
+
+    pub(crate) fn swap_out_per_second(&self) -> f64 {
+        0.
+    }
+
+    pub(crate) fn major_page_faults_per_second(&self) -> f64 {
+        0.
+    }
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
@@ -43,6 +57,7 @@ impl SystemInner {
         &mut self,
         _processes_to_update: ProcessesToUpdate<'_>,
         _refresh_kind: ProcessRefreshKind,
+        _on_event: &mut dyn FnMut(ProcessEvent),
     ) -> usize {
         0
     }
@@ -71,6 +86,12 @@ impl SystemInner {
         &[]
     }
 
+    pub(crate) fn cpu_counters(&self) -> CpuCounters {
+        CpuCounters::default()
+    }
+
+    pub(crate) fn restore_cpu_counters(&mut self, _counters: &CpuCounters) {}
+
     pub(crate) fn total_memory(&self) -> u64 {
         0
     }
@@ -153,4 +174,117 @@ impl SystemInner {
     pub(crate) fn physical_core_count() -> Option<usize> {
         None
     }
+
+    pub(crate) fn physical_socket_count() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn available_entropy() -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn transparent_huge_pages() -> Option<crate::ThpMode> {
+        None
+    }
+
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn smt_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn threads_per_core() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_possible() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_present() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_online() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn kernel_modules() -> Vec<KernelModule> {
+        Vec::new()
+    }
+
+    pub(crate) fn current_memory_usage() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn memory_errors() -> Option<MemoryErrors> {
+        None
+    }
+
+    pub(crate) fn interrupts_per_cpu() -> Option<InterruptStats> {
+        None
+    }
+
+    #[cfg(unix)]
+    pub(crate) fn process_from_pidfd(_fd: std::os::unix::io::RawFd) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn is_elevated() -> bool {
+        false
+    }
+
+    pub(crate) fn page_size() -> u64 {
+        0
+    }
+
+    pub(crate) fn logical_cpu_count() -> usize {
+        0
+    }
+
+    pub(crate) fn balloon_memory() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn conntrack_count() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn conntrack_max() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn vm_setting(_name: &str) -> Option<i64> {
+        None
+    }
+
+    pub(crate) fn supports(_feature: crate::Feature) -> bool {
+        false
+    }
+
+    pub(crate) fn tcp_memory() -> Option<crate::TcpMemoryStats> {
+        None
+    }
+
+    pub(crate) fn memory_pressure_avg10() -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn os_install_time() -> Option<SystemTime> {
+        None
+    }
+
+    pub(crate) fn pid_max() -> Option<Pid> {
+        None
+    }
 }
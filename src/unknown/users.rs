@@ -23,3 +23,7 @@ impl UserInner {
 }
 
 pub(crate) fn get_users(_: &mut Vec<User>) {}
+
+pub(crate) fn get_user_name(_: &Uid) -> Option<String> {
+    None
+}
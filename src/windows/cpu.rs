@@ -23,11 +23,12 @@ use windows::Win32::System::Power::{
 };
 use windows::Win32::System::SystemInformation::{self, GetSystemInfo};
 use windows::Win32::System::SystemInformation::{
-    GetLogicalProcessorInformationEx, RelationAll, RelationProcessorCore, SYSTEM_INFO,
-    SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+    GetLogicalProcessorInformationEx, RelationAll, RelationProcessorCore, RelationProcessorPackage,
+    LOGICAL_PROCESSOR_RELATIONSHIP, SYSTEM_INFO, SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
 };
 use windows::Win32::System::Threading::{
-    CreateEventA, RegisterWaitForSingleObject, INFINITE, WT_EXECUTEDEFAULT,
+    CreateEventA, GetActiveProcessorCount, RegisterWaitForSingleObject, ALL_PROCESSOR_GROUPS,
+    INFINITE, WT_EXECUTEDEFAULT,
 };
 
 // This formula comes from Linux's include/linux/sched/loadavg.h
@@ -507,6 +508,15 @@ pub(crate) fn get_frequencies(nb_cpus: usize) -> Vec<u64> {
 }
 
 pub(crate) fn get_physical_core_count() -> Option<usize> {
+    count_processor_relations(RelationProcessorCore)
+}
+
+pub(crate) fn get_physical_socket_count() -> Option<usize> {
+    count_processor_relations(RelationProcessorPackage)
+}
+
+// Returns the number of `relationship` items returned by `GetLogicalProcessorInformationEx`.
+fn count_processor_relations(relationship: LOGICAL_PROCESSOR_RELATIONSHIP) -> Option<usize> {
     // We cannot use the number of cpus here to pre calculate the buf size.
     // `GetLogicalCpuInformationEx` with `RelationProcessorCore` passed to it not only returns
     // the logical cores but also numa nodes.
@@ -541,7 +551,7 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
                     Some(value) if value == ERROR_INSUFFICIENT_BUFFER.0 as i32 => {}
                     _ => {
                         sysinfo_debug!(
-                            "get_physical_core_count: GetLogicalCpuInformationEx failed"
+                            "count_processor_relations: GetLogicalCpuInformationEx failed"
                         );
                         return None;
                     }
@@ -556,7 +566,7 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
                 Some(new_size) => new_size,
                 None => {
                     sysinfo_debug!(
-                        "get_physical_core_count: buffer size is too big ({} + {})",
+                        "count_processor_relations: buffer size is too big ({} + {})",
                         needed_size,
                         reserve,
                     );
@@ -574,8 +584,7 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
         while i < buf.len() {
             let p = &*(raw_buf.add(i) as *const SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX);
             i += p.Size as usize;
-            if p.Relationship == RelationProcessorCore {
-                // Only count the physical cores.
+            if p.Relationship == relationship {
                 count += 1;
             }
         }
@@ -583,6 +592,18 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
     }
 }
 
+pub(crate) fn threads_per_core() -> Option<usize> {
+    // Contrary to `GetSystemInfo`, it counts the logical processors of all processor groups.
+    let logical_core_count = unsafe { GetActiveProcessorCount(ALL_PROCESSOR_GROUPS) } as usize;
+    if logical_core_count == 0 {
+        return None;
+    }
+    match get_physical_core_count()? {
+        0 => None,
+        physical_core_count => Some((logical_core_count / physical_core_count).max(1)),
+    }
+}
+
 fn init_cpus(refresh_kind: CpuRefreshKind) -> Vec<Cpu> {
     unsafe {
         let mut sys_info = SYSTEM_INFO::default();
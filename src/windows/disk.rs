@@ -211,6 +211,13 @@ impl DiskInner {
             total_written_bytes: self.written_bytes,
         }
     }
+
+    pub(crate) fn restore_usage(&mut self, usage: &DiskUsage) {
+        self.old_read_bytes = usage.total_read_bytes;
+        self.read_bytes = usage.total_read_bytes;
+        self.old_written_bytes = usage.total_written_bytes;
+        self.written_bytes = usage.total_written_bytes;
+    }
 }
 
 pub(crate) struct DisksInner {
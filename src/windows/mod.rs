@@ -25,6 +25,10 @@ cfg_if! {
         pub(crate) use self::component::{ComponentInner, ComponentsInner};
     }
 
+    if #[cfg(feature = "gpu")] {
+        pub(crate) use crate::gpu::{GpuInner, GpusInner};
+    }
+
     if #[cfg(feature = "network")] {
         mod network;
         pub(crate) mod network_helper;
@@ -37,10 +41,17 @@ cfg_if! {
         mod users;
 
         pub(crate) use self::groups::get_groups;
+        pub(crate) use self::users::get_user_name;
         pub(crate) use self::users::get_users;
         pub(crate) use self::users::UserInner;
     }
 
+    if #[cfg(feature = "windows-services")] {
+        mod service;
+
+        pub(crate) use self::service::{ServiceInner, ServicesInner};
+    }
+
     if #[cfg(any(feature = "user", feature = "system"))] {
         mod sid;
 
@@ -67,6 +78,8 @@ mod network_helper;
 #[cfg(any())]
 mod process;
 #[cfg(any())]
+mod service;
+#[cfg(any())]
 mod sid;
 #[cfg(any())]
 mod system;
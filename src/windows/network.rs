@@ -1,12 +1,17 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::network::RefreshTimer;
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::{Duplex, IpNetwork, MacAddr, NetworkCounters, NetworkData};
 
 use std::collections::{hash_map, HashMap};
+use std::time::Duration;
 
 use windows::Win32::NetworkManagement::IpHelper::{FreeMibTable, GetIfTable2, MIB_IF_TABLE2};
-use windows::Win32::NetworkManagement::Ndis::MediaConnectStateDisconnected;
+use windows::Win32::NetworkManagement::Ndis::{
+    MediaConnectStateDisconnected, MediaDuplexStateFull, MediaDuplexStateHalf,
+    NET_IF_MEDIA_DUPLEX_STATE,
+};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $new_val:expr) => {{
@@ -94,6 +99,7 @@ impl NetworksInner {
                 };
 
                 let mtu = ptr.Mtu as u64;
+                let duplex = duplex_from_state(ptr.MediaDuplexState);
                 match self.interfaces.entry(interface_name) {
                     hash_map::Entry::Occupied(mut e) => {
                         let interface = e.get_mut();
@@ -114,9 +120,13 @@ impl NetworksInner {
                         );
                         old_and_new!(interface, errors_in, old_errors_in, ptr.InErrors);
                         old_and_new!(interface, errors_out, old_errors_out, ptr.OutErrors);
+                        old_and_new!(interface, dropped_in, old_dropped_in, ptr.InDiscards);
+                        old_and_new!(interface, dropped_out, old_dropped_out, ptr.OutDiscards);
                         if interface.mtu != mtu {
                             interface.mtu = mtu;
                         }
+                        interface.duplex = duplex;
+                        interface.refresh_timer.update();
                         interface.updated = true;
                     }
                     hash_map::Entry::Vacant(e) => {
@@ -137,9 +147,16 @@ impl NetworksInner {
                                 old_errors_in: ptr.InErrors,
                                 errors_out: ptr.OutErrors,
                                 old_errors_out: ptr.OutErrors,
+                                dropped_in: ptr.InDiscards,
+                                old_dropped_in: ptr.InDiscards,
+                                dropped_out: ptr.OutDiscards,
+                                old_dropped_out: ptr.OutDiscards,
                                 mac_addr: MacAddr::UNSPECIFIED,
                                 ip_networks: vec![],
+                                addresses_changed: false,
                                 mtu,
+                                duplex,
+                                refresh_timer: RefreshTimer::new(),
                                 updated: true,
                             },
                         });
@@ -150,13 +167,7 @@ impl NetworksInner {
         }
         if remove_not_listed_interfaces {
             // Remove interfaces which are gone.
-            self.interfaces.retain(|_, i| {
-                if !i.inner.updated {
-                    return false;
-                }
-                i.inner.updated = false;
-                true
-            });
+            self.interfaces.retain(|_, i| i.inner.updated);
         }
         // Refresh all interfaces' addresses.
         refresh_networks_addresses(&mut self.interfaces);
@@ -176,14 +187,47 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    dropped_in: u64,
+    old_dropped_in: u64,
+    dropped_out: u64,
+    old_dropped_out: u64,
     updated: bool,
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
+    /// Whether the IP networks changed during the last refresh.
+    pub(crate) addresses_changed: bool,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    duplex: Option<Duplex>,
+    /// Time elapsed between the two last refreshes.
+    refresh_timer: RefreshTimer,
 }
 
 impl NetworkDataInner {
+    pub(crate) fn restore_counters(&mut self, counters: &NetworkCounters) {
+        self.old_in = counters.received;
+        self.current_in = counters.received;
+        self.old_out = counters.transmitted;
+        self.current_out = counters.transmitted;
+        self.old_packets_in = counters.packets_received;
+        self.packets_in = counters.packets_received;
+        self.old_packets_out = counters.packets_transmitted;
+        self.packets_out = counters.packets_transmitted;
+        self.old_errors_in = counters.errors_on_received;
+        self.errors_in = counters.errors_on_received;
+        self.old_errors_out = counters.errors_on_transmitted;
+        self.errors_out = counters.errors_on_transmitted;
+        self.old_dropped_in = counters.packets_dropped_on_received;
+        self.dropped_in = counters.packets_dropped_on_received;
+        self.old_dropped_out = counters.packets_dropped_on_transmitted;
+        self.dropped_out = counters.packets_dropped_on_transmitted;
+        self.refresh_timer.restore(counters.last_refresh);
+    }
+
+    pub(crate) fn last_refresh(&self) -> Duration {
+        self.refresh_timer.last_refresh()
+    }
+
     pub(crate) fn received(&self) -> u64 {
         self.current_in.saturating_sub(self.old_in)
     }
@@ -232,6 +276,22 @@ impl NetworkDataInner {
         self.errors_out
     }
 
+    pub(crate) fn packets_dropped_on_received(&self) -> u64 {
+        self.dropped_in.saturating_sub(self.old_dropped_in)
+    }
+
+    pub(crate) fn total_packets_dropped_on_received(&self) -> u64 {
+        self.dropped_in
+    }
+
+    pub(crate) fn packets_dropped_on_transmitted(&self) -> u64 {
+        self.dropped_out.saturating_sub(self.old_dropped_out)
+    }
+
+    pub(crate) fn total_packets_dropped_on_transmitted(&self) -> u64 {
+        self.dropped_out
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -240,7 +300,31 @@ impl NetworkDataInner {
         &self.ip_networks
     }
 
+    pub(crate) fn addresses_changed(&self) -> bool {
+        self.addresses_changed
+    }
+
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn duplex(&self) -> Option<Duplex> {
+        self.duplex
+    }
+
+    pub(crate) fn is_updated(&self) -> bool {
+        self.updated
+    }
+
+    pub(crate) fn elapsed_since_last_refresh(&self) -> Option<Duration> {
+        self.refresh_timer.elapsed()
+    }
+}
+
+fn duplex_from_state(state: NET_IF_MEDIA_DUPLEX_STATE) -> Option<Duplex> {
+    match state {
+        MediaDuplexStateFull => Some(Duplex::Full),
+        MediaDuplexStateHalf => Some(Duplex::Half),
+        _ => None,
+    }
 }
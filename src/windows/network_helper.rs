@@ -86,10 +86,10 @@ impl InterfaceAddressIterator {
     }
 }
 
-pub(crate) unsafe fn get_interface_ip_networks() -> HashMap<String, HashSet<IpNetwork>> {
+pub(crate) unsafe fn get_interface_ip_networks() -> Option<HashMap<String, HashSet<IpNetwork>>> {
     match get_interface_address() {
-        Ok(mut interface_iter) => interface_iter.generate_ip_networks(),
-        _ => HashMap::new(),
+        Ok(mut interface_iter) => Some(interface_iter.generate_ip_networks()),
+        _ => None,
     }
 }
 
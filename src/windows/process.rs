@@ -3,7 +3,10 @@
 use crate::sys::system::is_proc_running;
 use crate::sys::utils::HandleWrapper;
 use crate::windows::Sid;
-use crate::{DiskUsage, Gid, Pid, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    DiskUsage, Gid, IntegrityLevel, Pid, ProcessCpuCounters, ProcessRefreshKind, ProcessStatus,
+    Signal, Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -32,7 +35,10 @@ use windows::Win32::Foundation::{
     LocalFree, ERROR_INSUFFICIENT_BUFFER, FILETIME, HANDLE, HINSTANCE, HLOCAL, MAX_PATH,
     STATUS_BUFFER_OVERFLOW, STATUS_BUFFER_TOO_SMALL, STATUS_INFO_LENGTH_MISMATCH, UNICODE_STRING,
 };
-use windows::Win32::Security::{GetTokenInformation, TokenUser, TOKEN_QUERY, TOKEN_USER};
+use windows::Win32::Security::{
+    GetSidSubAuthority, GetSidSubAuthorityCount, GetTokenInformation, TokenIntegrityLevel,
+    TokenUser, TOKEN_MANDATORY_LABEL, TOKEN_QUERY, TOKEN_USER,
+};
 use windows::Win32::System::Diagnostics::Debug::ReadProcessMemory;
 use windows::Win32::System::Diagnostics::ToolHelp::PROCESSENTRY32W;
 use windows::Win32::System::Memory::{
@@ -169,6 +175,74 @@ unsafe fn get_process_user_id(process: &mut ProcessInner, refresh_kind: ProcessR
     }
 }
 
+// Relative identifiers of the mandatory labels, from `winnt.h`.
+const SECURITY_MANDATORY_LOW_RID: u32 = 0x1000;
+const SECURITY_MANDATORY_MEDIUM_RID: u32 = 0x2000;
+const SECURITY_MANDATORY_HIGH_RID: u32 = 0x3000;
+const SECURITY_MANDATORY_SYSTEM_RID: u32 = 0x4000;
+const SECURITY_MANDATORY_PROTECTED_PROCESS_RID: u32 = 0x5000;
+
+fn integrity_level_from_rid(rid: u32) -> IntegrityLevel {
+    // There are levels in between the well-known ones (like "medium plus"), so we use ranges.
+    if rid < SECURITY_MANDATORY_LOW_RID {
+        IntegrityLevel::Untrusted
+    } else if rid < SECURITY_MANDATORY_MEDIUM_RID {
+        IntegrityLevel::Low
+    } else if rid < SECURITY_MANDATORY_HIGH_RID {
+        IntegrityLevel::Medium
+    } else if rid < SECURITY_MANDATORY_SYSTEM_RID {
+        IntegrityLevel::High
+    } else if rid < SECURITY_MANDATORY_PROTECTED_PROCESS_RID {
+        IntegrityLevel::System
+    } else {
+        IntegrityLevel::Protected
+    }
+}
+
+unsafe fn get_integrity_level(handle: HANDLE) -> Option<IntegrityLevel> {
+    let mut token = Default::default();
+
+    if OpenProcessToken(handle, TOKEN_QUERY, &mut token).is_err() {
+        sysinfo_debug!("OpenProcessToken failed");
+        return None;
+    }
+
+    let token = HandleWrapper::new(token)?;
+    let mut size = 0;
+
+    if let Err(err) = GetTokenInformation(*token, TokenIntegrityLevel, None, 0, &mut size) {
+        if err.code() != ERROR_INSUFFICIENT_BUFFER.to_hresult() {
+            sysinfo_debug!("GetTokenInformation failed, error: {:?}", err);
+            return None;
+        }
+    }
+
+    // We use `u64` to get an alignment compatible with `TOKEN_MANDATORY_LABEL`.
+    let mut buffer = vec![0u64; (size as usize).div_ceil(size_of::<u64>())];
+    if let Err(_err) = GetTokenInformation(
+        *token,
+        TokenIntegrityLevel,
+        Some(buffer.as_mut_ptr().cast()),
+        size,
+        &mut size,
+    ) {
+        sysinfo_debug!("GetTokenInformation failed, error: {:?}", _err);
+        return None;
+    }
+
+    // The integrity level is the last sub-authority of the label SID.
+    let sid = (*buffer.as_ptr().cast::<TOKEN_MANDATORY_LABEL>()).Label.Sid;
+    let count = GetSidSubAuthorityCount(sid);
+    if count.is_null() || *count == 0 {
+        return None;
+    }
+    let rid = GetSidSubAuthority(sid, *count as u32 - 1);
+    if rid.is_null() {
+        return None;
+    }
+    Some(integrity_level_from_rid(*rid))
+}
+
 #[allow(clippy::non_send_fields_in_send_ty)]
 unsafe impl Send for HandleWrapper {}
 unsafe impl Sync for HandleWrapper {}
@@ -433,6 +507,29 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn restore_disk_usage(&mut self, usage: &DiskUsage) {
+        self.old_read_bytes = usage.total_read_bytes;
+        self.read_bytes = usage.total_read_bytes;
+        self.old_written_bytes = usage.total_written_bytes;
+        self.written_bytes = usage.total_written_bytes;
+    }
+
+    pub(crate) fn cpu_counters(&self) -> ProcessCpuCounters {
+        ProcessCpuCounters {
+            user_time: self.cpu_calc_values.old_process_user_cpu,
+            system_time: self.cpu_calc_values.old_process_sys_cpu,
+            global_user_time: self.cpu_calc_values.old_system_user_cpu,
+            global_system_time: self.cpu_calc_values.old_system_sys_cpu,
+        }
+    }
+
+    pub(crate) fn restore_cpu_counters(&mut self, counters: &ProcessCpuCounters) {
+        self.cpu_calc_values.old_process_user_cpu = counters.user_time;
+        self.cpu_calc_values.old_process_sys_cpu = counters.system_time;
+        self.cpu_calc_values.old_system_user_cpu = counters.global_user_time;
+        self.cpu_calc_values.old_system_sys_cpu = counters.global_system_time;
+    }
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         self.user_id.as_ref()
     }
@@ -476,6 +573,10 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn integrity_level(&self) -> Option<IntegrityLevel> {
+        unsafe { get_integrity_level(self.get_handle()?) }
+    }
+
     pub(crate) fn session_id(&self) -> Option<Pid> {
         unsafe {
             let mut out = 0;
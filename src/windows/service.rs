@@ -0,0 +1,271 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::windows::utils::to_utf8_str;
+use crate::{Pid, Service, ServiceStartType, ServiceState};
+
+use std::mem::size_of;
+
+use windows::core::{HRESULT, PCWSTR};
+use windows::Win32::System::Services::{
+    CloseServiceHandle, EnumServicesStatusExW, OpenSCManagerW, OpenServiceW, QueryServiceConfigW,
+    ENUM_SERVICE_STATUS_PROCESSW, QUERY_SERVICE_CONFIGW, SC_ENUM_PROCESS_INFO, SC_HANDLE,
+    SC_MANAGER_CONNECT, SC_MANAGER_ENUMERATE_SERVICE, SERVICE_AUTO_START, SERVICE_BOOT_START,
+    SERVICE_CONTINUE_PENDING, SERVICE_DEMAND_START, SERVICE_DISABLED, SERVICE_PAUSED,
+    SERVICE_PAUSE_PENDING, SERVICE_QUERY_CONFIG, SERVICE_RUNNING, SERVICE_START_PENDING,
+    SERVICE_START_TYPE, SERVICE_STATE_ALL, SERVICE_STATUS_CURRENT_STATE, SERVICE_STOPPED,
+    SERVICE_STOP_PENDING, SERVICE_SYSTEM_START, SERVICE_WIN32,
+};
+
+const ERROR_MORE_DATA: HRESULT = windows::Win32::Foundation::ERROR_MORE_DATA.to_hresult();
+const ERROR_INSUFFICIENT_BUFFER: HRESULT =
+    windows::Win32::Foundation::ERROR_INSUFFICIENT_BUFFER.to_hresult();
+
+pub(crate) struct ServiceInner {
+    name: String,
+    display_name: String,
+    state: ServiceState,
+    start_type: Option<ServiceStartType>,
+    pid: Option<Pid>,
+}
+
+impl ServiceInner {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub(crate) fn state(&self) -> ServiceState {
+        self.state
+    }
+
+    pub(crate) fn start_type(&self) -> Option<ServiceStartType> {
+        self.start_type
+    }
+
+    pub(crate) fn pid(&self) -> Option<Pid> {
+        self.pid
+    }
+}
+
+pub(crate) struct ServicesInner {
+    services: Vec<Service>,
+}
+
+impl ServicesInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            services: Vec::new(),
+        }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Service> {
+        self.services
+    }
+
+    pub(crate) fn list(&self) -> &[Service] {
+        &self.services
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.services = unsafe { get_services() }.unwrap_or_default();
+    }
+}
+
+struct ScHandle(SC_HANDLE);
+
+impl Drop for ScHandle {
+    fn drop(&mut self) {
+        let _err = unsafe { CloseServiceHandle(self.0) };
+    }
+}
+
+fn service_state(state: SERVICE_STATUS_CURRENT_STATE) -> ServiceState {
+    match state {
+        SERVICE_STOPPED => ServiceState::Stopped,
+        SERVICE_START_PENDING => ServiceState::StartPending,
+        SERVICE_STOP_PENDING => ServiceState::StopPending,
+        SERVICE_RUNNING => ServiceState::Running,
+        SERVICE_CONTINUE_PENDING => ServiceState::ContinuePending,
+        SERVICE_PAUSE_PENDING => ServiceState::PausePending,
+        SERVICE_PAUSED => ServiceState::Paused,
+        state => ServiceState::Unknown(state.0),
+    }
+}
+
+fn service_start_type(start_type: SERVICE_START_TYPE) -> ServiceStartType {
+    match start_type {
+        SERVICE_BOOT_START => ServiceStartType::Boot,
+        SERVICE_SYSTEM_START => ServiceStartType::System,
+        SERVICE_AUTO_START => ServiceStartType::Automatic,
+        SERVICE_DEMAND_START => ServiceStartType::Manual,
+        SERVICE_DISABLED => ServiceStartType::Disabled,
+        start_type => ServiceStartType::Unknown(start_type.0),
+    }
+}
+
+// `QUERY_SERVICE_CONFIGW` and `ENUM_SERVICE_STATUS_PROCESSW` contain pointers, so the buffers
+// are allocated as `u64` to get a suitable alignment.
+fn as_bytes(buffer: &mut [u64]) -> &mut [u8] {
+    unsafe {
+        std::slice::from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<u8>(),
+            std::mem::size_of_val(buffer),
+        )
+    }
+}
+
+fn resize_buffer(buffer: &mut Vec<u64>, bytes_needed: u32) {
+    let len = (bytes_needed as usize).div_ceil(size_of::<u64>());
+    if len > buffer.len() {
+        buffer.resize(len, 0);
+    }
+}
+
+unsafe fn get_start_type(manager: &ScHandle, name: PCWSTR) -> Option<ServiceStartType> {
+    let service = match OpenServiceW(manager.0, name, SERVICE_QUERY_CONFIG) {
+        Ok(service) => ScHandle(service),
+        Err(_e) => {
+            sysinfo_debug!("OpenServiceW failed: {_e:?}");
+            return None;
+        }
+    };
+    let mut buffer: Vec<u64> = Vec::new();
+    loop {
+        let mut bytes_needed = 0;
+        let config = buffer.as_mut_ptr().cast::<QUERY_SERVICE_CONFIGW>();
+        match QueryServiceConfigW(
+            service.0,
+            (!buffer.is_empty()).then_some(config),
+            (buffer.len() * size_of::<u64>()) as u32,
+            &mut bytes_needed,
+        ) {
+            Ok(()) => return Some(service_start_type((*config).dwStartType)),
+            Err(e) if e.code() == ERROR_INSUFFICIENT_BUFFER && bytes_needed > 0 => {
+                resize_buffer(&mut buffer, bytes_needed);
+            }
+            Err(_e) => {
+                sysinfo_debug!("QueryServiceConfigW failed: {_e:?}");
+                return None;
+            }
+        }
+    }
+}
+
+unsafe fn get_services() -> Option<Vec<Service>> {
+    let manager = match OpenSCManagerW(
+        PCWSTR::null(),
+        PCWSTR::null(),
+        SC_MANAGER_CONNECT | SC_MANAGER_ENUMERATE_SERVICE,
+    ) {
+        Ok(manager) => ScHandle(manager),
+        Err(_e) => {
+            sysinfo_debug!("OpenSCManagerW failed: {_e:?}");
+            return None;
+        }
+    };
+
+    let mut services = Vec::new();
+    let mut buffer: Vec<u64> = Vec::new();
+    let mut resume_handle = 0;
+    loop {
+        let mut bytes_needed = 0;
+        let mut services_returned = 0;
+        let ret = EnumServicesStatusExW(
+            manager.0,
+            SC_ENUM_PROCESS_INFO,
+            SERVICE_WIN32,
+            SERVICE_STATE_ALL,
+            Some(as_bytes(&mut buffer)),
+            &mut bytes_needed,
+            &mut services_returned,
+            Some(&mut resume_handle),
+            PCWSTR::null(),
+        );
+        if services_returned > 0 {
+            let entries = std::slice::from_raw_parts(
+                buffer.as_ptr().cast::<ENUM_SERVICE_STATUS_PROCESSW>(),
+                services_returned as usize,
+            );
+            for entry in entries {
+                let status = &entry.ServiceStatusProcess;
+                let state = service_state(status.dwCurrentState);
+                services.push(Service {
+                    inner: ServiceInner {
+                        name: to_utf8_str(entry.lpServiceName),
+                        display_name: to_utf8_str(entry.lpDisplayName),
+                        state,
+                        start_type: get_start_type(&manager, PCWSTR(entry.lpServiceName.0)),
+                        pid: (status.dwProcessId != 0).then(|| Pid::from_u32(status.dwProcessId)),
+                    },
+                });
+            }
+        }
+        match ret {
+            Ok(()) => break,
+            // More entries are available: they'll be returned by the next call thanks to
+            // `resume_handle`.
+            Err(e) if e.code() == ERROR_MORE_DATA => resize_buffer(&mut buffer, bytes_needed),
+            Err(_e) => {
+                sysinfo_debug!("EnumServicesStatusExW failed: {_e:?}");
+                return None;
+            }
+        }
+    }
+    Some(services)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{as_bytes, resize_buffer, service_start_type, service_state};
+    use crate::{ServiceStartType, ServiceState};
+
+    use windows::Win32::System::Services::{
+        SERVICE_AUTO_START, SERVICE_DEMAND_START, SERVICE_DISABLED, SERVICE_PAUSED,
+        SERVICE_RUNNING, SERVICE_START_TYPE, SERVICE_STATUS_CURRENT_STATE, SERVICE_STOPPED,
+    };
+
+    #[test]
+    fn test_service_state() {
+        assert_eq!(service_state(SERVICE_STOPPED), ServiceState::Stopped);
+        assert_eq!(service_state(SERVICE_RUNNING), ServiceState::Running);
+        assert_eq!(service_state(SERVICE_PAUSED), ServiceState::Paused);
+        assert_eq!(
+            service_state(SERVICE_STATUS_CURRENT_STATE(42)),
+            ServiceState::Unknown(42)
+        );
+    }
+
+    #[test]
+    fn test_service_start_type() {
+        assert_eq!(
+            service_start_type(SERVICE_AUTO_START),
+            ServiceStartType::Automatic
+        );
+        assert_eq!(
+            service_start_type(SERVICE_DEMAND_START),
+            ServiceStartType::Manual
+        );
+        assert_eq!(
+            service_start_type(SERVICE_DISABLED),
+            ServiceStartType::Disabled
+        );
+        assert_eq!(
+            service_start_type(SERVICE_START_TYPE(42)),
+            ServiceStartType::Unknown(42)
+        );
+    }
+
+    #[test]
+    fn test_resize_buffer() {
+        let mut buffer = Vec::new();
+        resize_buffer(&mut buffer, 17);
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(as_bytes(&mut buffer).len(), 24);
+        // The buffer is never shrunk.
+        resize_buffer(&mut buffer, 8);
+        assert_eq!(buffer.len(), 3);
+    }
+}
@@ -1,24 +1,32 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, ProcessRefreshKind, ProcessesToUpdate,
+    Cpu, CpuCounters, CpuRefreshKind, InterruptStats, KernelModule, LoadAvg, MemoryErrors,
+    MemoryRefreshKind, Pid, ProcessEvent, ProcessRefreshKind, ProcessesToUpdate,
 };
 
 use crate::sys::cpu::*;
+use crate::sys::utils::HandleWrapper;
 use crate::{Process, ProcessInner};
 
 use std::collections::HashMap;
-use std::ffi::OsStr;
+use std::ffi::{c_void, OsStr};
 use std::mem::{size_of, zeroed};
 use std::os::windows::ffi::OsStrExt;
+use std::ptr::null_mut;
 use std::time::{Duration, SystemTime};
 
 use windows::core::{PCWSTR, PWSTR};
-use windows::Win32::Foundation::{self, HANDLE, STILL_ACTIVE};
+use windows::Win32::Foundation::{self, HANDLE, MAX_PATH, STILL_ACTIVE};
+use windows::Win32::Globalization::GetUserDefaultLocaleName;
+use windows::Win32::Security::{GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY};
 use windows::Win32::System::Diagnostics::ToolHelp::{
     CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W, TH32CS_SNAPPROCESS,
 };
-use windows::Win32::System::ProcessStatus::{K32GetPerformanceInfo, PERFORMANCE_INFORMATION};
+use windows::Win32::System::ProcessStatus::{
+    GetProcessMemoryInfo, K32EnumDeviceDrivers, K32GetDeviceDriverBaseNameW, K32GetPerformanceInfo,
+    PERFORMANCE_INFORMATION, PROCESS_MEMORY_COUNTERS,
+};
 use windows::Win32::System::Registry::{
     RegCloseKey, RegOpenKeyExW, RegQueryValueExW, HKEY, HKEY_LOCAL_MACHINE, KEY_READ, REG_NONE,
 };
@@ -27,7 +35,11 @@ use windows::Win32::System::SystemInformation::{
     ComputerNamePhysicalDnsHostname, GetComputerNameExW, GetTickCount64, GlobalMemoryStatusEx,
     MEMORYSTATUSEX, SYSTEM_INFO,
 };
-use windows::Win32::System::Threading::GetExitCodeProcess;
+use windows::Win32::System::SystemServices::LOCALE_NAME_MAX_LENGTH;
+use windows::Win32::System::Threading::{GetCurrentProcess, GetExitCodeProcess, OpenProcessToken};
+use windows::Win32::System::Time::{
+    GetTimeZoneInformation, TIME_ZONE_ID_INVALID, TIME_ZONE_INFORMATION,
+};
 
 declare_signals! {
     (),
@@ -187,6 +199,20 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn refresh_swap_rates(&mut self) {}
+
+    pub(crate) fn swap_in_per_second(&self) -> f64 {
+        0.
+    }
+
+    pub(crate) fn swap_out_per_second(&self) -> f64 {
+        0.
+    }
+
+    pub(crate) fn major_page_faults_per_second(&self) -> f64 {
+        0.
+    }
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
@@ -196,6 +222,7 @@ impl SystemInner {
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
         refresh_kind: ProcessRefreshKind,
+        on_event: &mut dyn FnMut(ProcessEvent),
     ) -> usize {
         #[inline(always)]
         fn real_filter(e: Pid, filter: &[Pid]) -> bool {
@@ -286,6 +313,7 @@ impl SystemInner {
                     let mut p = ProcessInner::from_process_entry(&process_entry, now);
                     p.update(refresh_kind, nb_cpus, now, false);
                     process_list.insert(proc_id, Process { inner: p });
+                    on_event(ProcessEvent::Started(proc_id));
                 }
 
                 num_procs += 1;
@@ -320,6 +348,14 @@ impl SystemInner {
         self.cpus.cpus()
     }
 
+    // The CPU usage is computed by PDH queries, which cannot be given a reference. The processes
+    // keep their own reference of the global times though.
+    pub(crate) fn cpu_counters(&self) -> CpuCounters {
+        CpuCounters::default()
+    }
+
+    pub(crate) fn restore_cpu_counters(&mut self, _counters: &CpuCounters) {}
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -455,6 +491,228 @@ impl SystemInner {
     pub(crate) fn physical_core_count() -> Option<usize> {
         get_physical_core_count()
     }
+
+    pub(crate) fn physical_socket_count() -> Option<usize> {
+        get_physical_socket_count()
+    }
+
+    pub(crate) fn available_entropy() -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn transparent_huge_pages() -> Option<crate::ThpMode> {
+        None
+    }
+
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        // This key doesn't exist on systems which didn't boot with UEFI.
+        get_reg_value_u32(
+            HKEY_LOCAL_MACHINE,
+            r"SYSTEM\CurrentControlSet\Control\SecureBoot\State",
+            "UEFISecureBootEnabled",
+        )
+        .map(|value| u32::from_le_bytes(value) != 0)
+    }
+
+    pub(crate) fn smt_enabled() -> Option<bool> {
+        threads_per_core().map(|nb| nb > 1)
+    }
+
+    pub(crate) fn threads_per_core() -> Option<usize> {
+        threads_per_core()
+    }
+
+    pub(crate) fn cpus_possible() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_present() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpus_online() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn kernel_modules() -> Vec<KernelModule> {
+        const PTR_SIZE: usize = size_of::<*mut c_void>();
+
+        unsafe {
+            let mut needed = 0;
+            // First call to get the number of drivers.
+            if !K32EnumDeviceDrivers(null_mut(), 0, &mut needed).as_bool() || needed == 0 {
+                return Vec::new();
+            }
+            let mut drivers = vec![null_mut(); needed as usize / PTR_SIZE];
+            if !K32EnumDeviceDrivers(
+                drivers.as_mut_ptr(),
+                (drivers.len() * PTR_SIZE) as _,
+                &mut needed,
+            )
+            .as_bool()
+            {
+                return Vec::new();
+            }
+            // Drivers might have been unloaded in between.
+            drivers.truncate(needed as usize / PTR_SIZE);
+
+            drivers
+                .into_iter()
+                .filter_map(|image_base| {
+                    let mut name = [0u16; MAX_PATH as usize];
+                    let len = K32GetDeviceDriverBaseNameW(image_base, &mut name) as usize;
+                    if len == 0 {
+                        return None;
+                    }
+                    Some(KernelModule {
+                        name: String::from_utf16_lossy(&name[..len]),
+                        size: None,
+                        use_count: None,
+                    })
+                })
+                .collect()
+        }
+    }
+
+    pub(crate) fn current_memory_usage() -> Option<u64> {
+        unsafe {
+            let mut mem_info = PROCESS_MEMORY_COUNTERS::default();
+            if let Err(_error) = GetProcessMemoryInfo(
+                GetCurrentProcess(),
+                &mut mem_info,
+                size_of::<PROCESS_MEMORY_COUNTERS>() as _,
+            ) {
+                sysinfo_debug!("GetProcessMemoryInfo failed: {_error:?}");
+                return None;
+            }
+            Some(mem_info.WorkingSetSize as _)
+        }
+    }
+
+    pub(crate) fn memory_errors() -> Option<MemoryErrors> {
+        None
+    }
+
+    pub(crate) fn interrupts_per_cpu() -> Option<InterruptStats> {
+        None
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        unsafe {
+            let mut info: TIME_ZONE_INFORMATION = zeroed();
+            if GetTimeZoneInformation(&mut info) == TIME_ZONE_ID_INVALID {
+                sysinfo_debug!("GetTimeZoneInformation failed");
+                return None;
+            }
+            let len = info
+                .StandardName
+                .iter()
+                .position(|c| *c == 0)
+                .unwrap_or(info.StandardName.len());
+            String::from_utf16(&info.StandardName[..len])
+                .ok()
+                .filter(|name| !name.is_empty())
+        }
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        let mut name = [0u16; LOCALE_NAME_MAX_LENGTH as usize];
+        // The returned length includes the terminating null character.
+        let len = unsafe { GetUserDefaultLocaleName(&mut name) };
+        if len <= 1 {
+            sysinfo_debug!("GetUserDefaultLocaleName failed");
+            return None;
+        }
+        String::from_utf16(&name[..len as usize - 1]).ok()
+    }
+
+    pub(crate) fn page_size() -> u64 {
+        let mut info = SYSTEM_INFO::default();
+        unsafe { GetSystemInfo(&mut info) };
+        info.dwPageSize as u64
+    }
+
+    pub(crate) fn logical_cpu_count() -> usize {
+        let mut info = SYSTEM_INFO::default();
+        unsafe { GetSystemInfo(&mut info) };
+        info.dwNumberOfProcessors as usize
+    }
+
+    pub(crate) fn balloon_memory() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn conntrack_count() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn conntrack_max() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn vm_setting(_name: &str) -> Option<i64> {
+        None
+    }
+
+    pub(crate) fn supports(feature: crate::Feature) -> bool {
+        use crate::Feature;
+
+        matches!(
+            feature,
+            Feature::ProcessCpuUsage | Feature::ProcessDiskUsage | Feature::ProcessEnvironment
+        )
+    }
+
+    pub(crate) fn tcp_memory() -> Option<crate::TcpMemoryStats> {
+        None
+    }
+
+    pub(crate) fn memory_pressure_avg10() -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn pid_max() -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn os_install_time() -> Option<SystemTime> {
+        // Number of seconds since the UNIX epoch.
+        let install_date = get_reg_value_u32(
+            HKEY_LOCAL_MACHINE,
+            r"SOFTWARE\Microsoft\Windows NT\CurrentVersion",
+            "InstallDate",
+        )?;
+        match u32::from_le_bytes(install_date) {
+            0 => None,
+            secs => Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs as u64)),
+        }
+    }
+
+    pub(crate) fn is_elevated() -> bool {
+        unsafe {
+            let mut token = Default::default();
+            if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token).is_err() {
+                sysinfo_debug!("OpenProcessToken failed");
+                return false;
+            }
+            let Some(token) = HandleWrapper::new(token) else {
+                return false;
+            };
+            let mut elevation = TOKEN_ELEVATION::default();
+            let mut size = 0;
+            if let Err(_err) = GetTokenInformation(
+                *token,
+                TokenElevation,
+                Some((&mut elevation as *mut TOKEN_ELEVATION).cast()),
+                size_of::<TOKEN_ELEVATION>() as u32,
+                &mut size,
+            ) {
+                sysinfo_debug!("GetTokenInformation failed: {_err:?}");
+                return false;
+            }
+            elevation.TokenIsElevated != 0
+        }
+    }
 }
 
 pub(crate) fn is_proc_running(handle: HANDLE) -> bool {
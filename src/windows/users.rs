@@ -159,6 +159,10 @@ unsafe fn get_groups_for_user(username: PCWSTR) -> Vec<Group> {
     groups
 }
 
+pub(crate) fn get_user_name(id: &Uid) -> Option<String> {
+    id.0.account_name()
+}
+
 pub(crate) fn get_users(users: &mut Vec<User>) {
     users.clear();
 
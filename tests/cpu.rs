@@ -50,3 +50,28 @@ fn test_too_rapid_cpu_refresh() {
 
     assert!(s.cpus().iter().any(|c| !c.cpu_usage().is_nan()));
 }
+
+#[test]
+fn test_steal_time_percent() {
+    use sysinfo::System;
+
+    let mut s = System::new();
+    s.refresh_cpu_all();
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_cpu_all();
+
+    if sysinfo::IS_SUPPORTED_SYSTEM && cfg!(target_os = "linux") {
+        let global = s.global_steal_time_percent().expect("no global steal time");
+        assert!((0. ..=100.).contains(&global));
+        for cpu in s.cpus() {
+            let steal = cpu.steal_time_percent().expect("no steal time");
+            assert!((0. ..=100.).contains(&steal));
+        }
+    } else {
+        assert_eq!(s.global_steal_time_percent(), None);
+        assert!(s
+            .cpus()
+            .iter()
+            .all(|cpu| cpu.steal_time_percent().is_none()));
+    }
+}
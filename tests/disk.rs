@@ -184,3 +184,139 @@ fn test_disks_usage() {
     // just verify the number is non-zero.
     assert!(written_bytes > 0);
 }
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disk_for_path() {
+    use sysinfo::{DiskRefreshKind, Disks};
+
+    if should_skip() {
+        return;
+    }
+
+    let disks = Disks::new_with_refreshed_list_specifics(DiskRefreshKind::nothing());
+    for disk in disks.list() {
+        let path = disk.mount_point().join("sysinfo-unlikely-file-name");
+        let found = disks
+            .disk_for_path(&path)
+            .expect("a disk should be found for a path under a mount point");
+        // The same mount point can be listed more than once, so we can't check it's `disk`.
+        assert!(path.starts_with(found.mount_point()));
+        assert!(found.mount_point().starts_with(disk.mount_point()));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "disk", feature = "raw-access", target_os = "linux"))]
+fn test_raw_mount_line() {
+    use sysinfo::{DiskRefreshKind, Disks};
+
+    let disks = Disks::new_with_refreshed_list_specifics(DiskRefreshKind::nothing());
+    for disk in disks.list() {
+        let line = disk.raw_mount_line().expect("no raw mount line");
+        assert!(line.contains(disk.file_system().to_str().unwrap()));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk", target_os = "linux"))]
+fn test_disks_from_process_namespace() {
+    use sysinfo::{Disks, Pid};
+
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut expected = Disks::new_with_refreshed_list()
+        .iter()
+        .map(|disk| (disk.name().to_owned(), disk.mount_point().to_owned()))
+        .collect::<Vec<_>>();
+    // We're in the same mount namespace as ourselves.
+    let mut disks = Disks::from_process_namespace(pid)
+        .iter()
+        .map(|disk| (disk.name().to_owned(), disk.mount_point().to_owned()))
+        .collect::<Vec<_>>();
+    expected.sort();
+    disks.sort();
+    assert_eq!(disks, expected);
+
+    assert!(Disks::from_process_namespace(Pid::from_u32(u32::MAX))
+        .list()
+        .is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_total_and_available_space() {
+    use sysinfo::Disks;
+
+    if should_skip() {
+        return;
+    }
+
+    let disks = Disks::new_with_refreshed_list();
+    let total_space = disks.total_space();
+    assert!(total_space > 0);
+    assert!(disks.available_space() <= total_space);
+    // Duplicates are not counted.
+    assert!(total_space <= disks.iter().map(|disk| disk.total_space()).sum());
+    assert!(total_space >= disks.iter().map(|disk| disk.total_space()).max().unwrap());
+
+    // Listing the same disks twice doesn't change the total (disks without a name are never
+    // deduplicated).
+    if disks.iter().all(|disk| !disk.name().is_empty()) {
+        let mut list = Vec::from(Disks::new_with_refreshed_list());
+        list.extend(Vec::from(Disks::new_with_refreshed_list()));
+        assert_eq!(Disks::from(list).total_space(), total_space);
+    }
+}
+
+#[test]
+#[cfg(feature = "disk")]
+fn test_disks_sorted() {
+    use sysinfo::Disks;
+
+    let disks = Disks::new_with_refreshed_list();
+    let used = |disk: &sysinfo::Disk| {
+        if disk.total_space() == 0 {
+            0.
+        } else {
+            (disk.total_space() - disk.available_space()) as f64 / disk.total_space() as f64
+        }
+    };
+
+    let by_usage = disks.sorted_by_usage();
+    assert_eq!(by_usage.len(), disks.len());
+    assert!(by_usage.windows(2).all(|w| used(w[0]) >= used(w[1])));
+
+    let by_free_space = disks.sorted_by_free_space();
+    assert_eq!(by_free_space.len(), disks.len());
+    assert!(by_free_space
+        .windows(2)
+        .all(|w| w[0].available_space() <= w[1].available_space()));
+}
+
+#[test]
+#[cfg(feature = "disk")]
+fn test_disks_restore_usages() {
+    use sysinfo::{DiskUsage, Disks};
+
+    let mut disks = Disks::new_with_refreshed_list();
+    let mut usages = disks.usages();
+    assert_eq!(usages.len(), disks.len());
+    for usage in usages.values_mut() {
+        usage.total_read_bytes += 1_000;
+        usage.total_written_bytes += 1_000;
+    }
+    // Unknown mount points are ignored.
+    usages.insert("/does/not/exist".into(), DiskUsage::default());
+
+    disks.restore_usages(&usages);
+    for disk in disks.list() {
+        let usage = disk.usage();
+        assert_eq!(usage.read_bytes, 0);
+        assert_eq!(usage.written_bytes, 0);
+        if sysinfo::IS_SUPPORTED_SYSTEM {
+            let saved = usages[disk.mount_point()];
+            assert_eq!(usage.total_read_bytes, saved.total_read_bytes);
+            assert_eq!(usage.total_written_bytes, saved.total_written_bytes);
+        }
+    }
+}
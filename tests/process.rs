@@ -753,6 +753,8 @@ fn test_process_specific_refresh() {
         // These two won't be checked, too much lazyness in testing them...
         assert_eq!(p.disk_usage(), sysinfo::DiskUsage::default());
         assert_eq!(p.cpu_usage(), 0.);
+        assert_eq!(p.network_usage(), None);
+        assert_eq!(p.scheduling_latency(), None);
     }
 
     let mut s = System::new();
@@ -1103,3 +1105,457 @@ fn test_tasks() {
     });
     scheduler.join().expect("Scheduler panicked");
 }
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_wait_channel() {
+    let mut p = start_proc!("3", "WaitChannelSignal");
+    let pid = Pid::from_u32(p.id() as _);
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let wait_channel = s.process(pid).and_then(|p| p.wait_channel());
+    p.kill().expect("Unable to kill process.");
+
+    // The process is sleeping so if we got a value, it should be a kernel function name.
+    if let Some(wait_channel) = wait_channel {
+        assert!(!wait_channel.is_empty());
+        assert_ne!(wait_channel, "0");
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_last_cpu() {
+    let mut s = System::new();
+    s.refresh_cpu_usage();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let last_cpu = s
+        .process(pid)
+        .and_then(|p| p.last_cpu())
+        .expect("failed to get last CPU");
+    assert!(last_cpu < s.cpus().len());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_cpus_used_recently() {
+    let mut s = System::new();
+    s.refresh_cpu_usage();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let cpus = s
+        .process(pid)
+        .and_then(|p| p.cpus_used_recently())
+        .expect("failed to get used CPUs");
+    assert!(cpus >= 1 && cpus <= s.cpus().len());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_network_usage() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let refresh_kind = ProcessRefreshKind::nothing().with_network_usage();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    assert_eq!(s.process(pid).unwrap().network_usage().is_some(), {
+        // The network namespace might only have the loopback interface.
+        std::fs::read_to_string("/proc/self/net/dev")
+            .map(|data| {
+                data.lines()
+                    .skip(2)
+                    .any(|line| !line.trim_start().starts_with("lo:"))
+            })
+            .unwrap_or(false)
+    });
+
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    if let Some(usage) = s.process(pid).unwrap().network_usage() {
+        assert!(usage.received_bytes <= usage.total_received_bytes);
+        assert!(usage.transmitted_bytes <= usage.total_transmitted_bytes);
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_scheduling_latency() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let refresh_kind = ProcessRefreshKind::nothing().with_scheduling_latency();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let latency = s.process(pid).unwrap().scheduling_latency().unwrap();
+    assert_eq!(s.process(pid).unwrap().scheduling_latency_delta(), None);
+
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let process = s.process(pid).unwrap();
+    let new_latency = process.scheduling_latency().unwrap();
+    assert!(new_latency >= latency);
+    assert_eq!(
+        process.scheduling_latency_delta(),
+        Some(new_latency - latency)
+    );
+}
+
+#[test]
+fn test_restore_process_counters() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let refresh_kind = ProcessRefreshKind::nothing().with_disk_usage();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let mut counters = s.process_counters();
+    let saved = counters[&pid];
+    assert_eq!(saved.start_time, s.process(pid).unwrap().start_time());
+
+    // As if the PID was reused by another process.
+    counters.get_mut(&pid).unwrap().start_time += 1;
+    counters.get_mut(&pid).unwrap().disk_usage.total_read_bytes += 1_000;
+    s.restore_process_counters(&counters);
+    assert_eq!(s.process(pid).unwrap().disk_usage(), saved.disk_usage);
+
+    counters.get_mut(&pid).unwrap().start_time = saved.start_time;
+    s.restore_process_counters(&counters);
+    let usage = s.process(pid).unwrap().disk_usage();
+    assert_eq!(
+        usage.total_read_bytes,
+        saved.disk_usage.total_read_bytes + 1_000
+    );
+    assert_eq!(usage.read_bytes, 0);
+}
+
+#[test]
+fn test_restore_process_cpu_counters() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    fn busy_loop(duration: std::time::Duration) {
+        let start = std::time::Instant::now();
+        let mut x = 0u64;
+        while start.elapsed() < duration {
+            x = std::hint::black_box(x.wrapping_add(1));
+        }
+    }
+
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let refresh_kind = ProcessRefreshKind::nothing().with_cpu();
+    // So the process has CPU times to compare to.
+    busy_loop(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    let mut s = System::new();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let cpu_counters = s.cpu_counters();
+    let counters = s.process_counters();
+
+    // The CPU time is spent while no `System` is refreshed, as if the program was restarted.
+    busy_loop(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL * 3);
+
+    let mut s = System::new();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    s.restore_cpu_counters(&cpu_counters);
+    s.restore_process_counters(&counters);
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+
+    let usage = s.process(pid).unwrap().cpu_usage();
+    assert!(usage <= s.cpus().len() as f32 * 100., "{usage}");
+    if cfg!(any(target_os = "linux", target_os = "windows")) {
+        // The busy loop happened before the restore, so it is only taken into account if the
+        // counters were restored.
+        assert!(usage > 10., "{usage}");
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_memory_growth_rate() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let refresh_kind = ProcessRefreshKind::nothing().with_memory_history();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    assert!(s.process(pid).unwrap().memory() > 0);
+    // Not enough samples yet.
+    assert_eq!(s.process(pid).unwrap().memory_growth_rate(), None);
+
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    assert!(s.process(pid).unwrap().memory_growth_rate().is_some());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_socket_count() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let _udp_socket = std::net::UdpSocket::bind("127.0.0.1:0").expect("failed to bind");
+    let _tcp_listener = std::net::TcpListener::bind("127.0.0.1:0").expect("failed to bind");
+    let (tcp, udp) = s.process(pid).unwrap().socket_count().unwrap();
+    // Other tests might be opening sockets as well.
+    assert!(tcp >= 1, "expected at least one TCP socket: {tcp}");
+    assert!(udp >= 1, "expected at least one UDP socket: {udp}");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_fd_breakdown() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let _file = std::fs::File::open("Cargo.toml").expect("failed to open file");
+    let _tcp_listener = std::net::TcpListener::bind("127.0.0.1:0").expect("failed to bind");
+    let fds = s.process(pid).unwrap().fd_breakdown().unwrap();
+    // Other tests might be opening file descriptors as well.
+    assert!(fds.files >= 1, "expected at least one file: {fds:?}");
+    assert!(fds.sockets >= 1, "expected at least one socket: {fds:?}");
+}
+
+#[test]
+fn test_gpu_usage() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    // The tests don't open any DRM file descriptor.
+    assert_eq!(s.process(pid).unwrap().gpu_usage(), None);
+}
+
+#[test]
+fn test_try_environ() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let process = s.process(pid).unwrap();
+    if sysinfo::IS_SUPPORTED_SYSTEM && cfg!(target_os = "linux") {
+        let environ = process.try_environ().expect("failed to get environment");
+        // Set by cargo when running tests.
+        assert!(environ.iter().any(|var| var == "CARGO_PKG_NAME=sysinfo"));
+    } else {
+        assert!(matches!(
+            process.try_environ(),
+            Err(sysinfo::SysinfoError::NotSupported)
+        ));
+    }
+}
+
+#[test]
+fn test_integrity_level() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let level = s.process(pid).unwrap().integrity_level();
+    if sysinfo::IS_SUPPORTED_SYSTEM && cfg!(windows) {
+        assert!(level.is_some());
+    } else {
+        assert_eq!(level, None);
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_try_environ_zombie() {
+    let mut child = std::process::Command::new("true")
+        .env("FOO", "BAR")
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_u32(child.id() as _);
+    let mut s = System::new();
+    // Wait for the child to exit without reaping it so it stays a zombie.
+    for _ in 0..50 {
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+        if s.process(pid).map(|p| p.status()) == Some(sysinfo::ProcessStatus::Zombie) {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    let process = s.process(pid).unwrap();
+    assert_eq!(process.status(), sysinfo::ProcessStatus::Zombie);
+    assert_eq!(
+        process.try_environ().unwrap(),
+        Vec::<std::ffi::OsString>::new()
+    );
+    child.wait().unwrap();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_page_table_and_stack_memory() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let process = s.process(pid).unwrap();
+    assert!(process.page_table_memory().unwrap() > 0);
+    assert!(process.stack_memory().unwrap() > 0);
+}
+
+#[test]
+fn test_locked_memory() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let locked = s.process(pid).unwrap().locked_memory();
+    if sysinfo::IS_SUPPORTED_SYSTEM && cfg!(target_os = "linux") {
+        assert!(locked.is_some());
+    } else {
+        assert_eq!(locked, None);
+    }
+}
+
+#[test]
+fn test_delays() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let delays = s.process(pid).unwrap().delays();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+        assert_eq!(delays, None);
+    } else if let Some(delays) = delays {
+        // The test threads had to wait for a CPU at least once when they were started.
+        assert!(!delays.cpu.is_zero());
+    } else {
+        // The taskstats interface can only be used with the `CAP_NET_ADMIN` capability.
+        const CAP_NET_ADMIN: u32 = 12;
+        let status = std::fs::read_to_string("/proc/self/status").expect("failed to read status");
+        let capabilities = status
+            .lines()
+            .find_map(|line| line.strip_prefix("CapEff:"))
+            .and_then(|caps| u64::from_str_radix(caps.trim(), 16).ok())
+            .expect("failed to get capabilities");
+        assert_eq!(capabilities & (1 << CAP_NET_ADMIN), 0);
+    }
+}
+
+#[test]
+fn test_umask() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let umask = s.process(pid).unwrap().umask();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+        assert_eq!(umask, None);
+    } else if let Some(umask) = umask {
+        assert!(umask <= 0o777);
+    }
+}
+
+#[test]
+fn test_signal_info() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let info = s.process(pid).unwrap().signal_info();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(not(any(target_os = "linux", target_os = "android"))) {
+        assert_eq!(info, None);
+    } else if let Some(info) = info {
+        // The Rust runtime ignores `SIGPIPE` and `SIGKILL` can't be blocked.
+        assert!(info.is_ignoring(sysinfo::Signal::Pipe));
+        assert!(!info.is_blocking(sysinfo::Signal::Kill));
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_syscall_counts() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let refresh_kind = ProcessRefreshKind::nothing().with_disk_usage();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let (reads, _) = s.process(pid).unwrap().syscall_counts().unwrap();
+    assert_eq!(s.process(pid).unwrap().syscall_counts_delta(), None);
+
+    for _ in 0..10 {
+        std::fs::read("/proc/self/stat").expect("failed to read file");
+    }
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let process = s.process(pid).unwrap();
+    let (new_reads, _) = process.syscall_counts().unwrap();
+    assert!(new_reads >= reads + 10);
+    assert_eq!(process.syscall_counts_delta().unwrap().0, new_reads - reads);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_start_time_ticks() {
+    let mut child = std::process::Command::new("sleep")
+        .arg("3")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .expect("failed to spawn child");
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let child_pid = Pid::from_u32(child.id());
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid, child_pid]), false);
+    let current = s.process(pid).unwrap().start_time_ticks().unwrap();
+    let child_ticks = s.process(child_pid).map(|p| p.start_time_ticks());
+    child.kill().expect("failed to kill child");
+    let _ = child.wait();
+
+    assert!(current > 0);
+    // The child was started after the current process.
+    assert!(child_ticks.unwrap().unwrap() >= current);
+}
+
+#[cfg(all(target_os = "linux", feature = "raw-access"))]
+#[test]
+fn test_raw_stat() {
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+
+    let raw_stat = s.process(pid).unwrap().raw_stat().expect("no raw stat");
+    assert!(raw_stat.starts_with(&format!("{pid} (")));
+}
+
+#[test]
+fn test_exe_size() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
+    let p = s.process(pid).expect("failed to get current process");
+    let exe = std::env::current_exe().expect("failed to get current exe");
+
+    assert_eq!(p.exe_size(), std::fs::metadata(exe).ok().map(|m| m.len()));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_exe_identity() {
+    use std::os::unix::fs::MetadataExt;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
+    let p = s.process(pid).expect("failed to get current process");
+    let exe = std::env::current_exe().expect("failed to get current exe");
+    let metadata = std::fs::metadata(exe).expect("failed to get exe metadata");
+
+    assert_eq!(p.exe_identity(), Some((metadata.dev(), metadata.ino())));
+}
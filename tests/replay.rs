@@ -0,0 +1,97 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Tests of the `linux-replay` feature. They are in their own file because the replayed files are
+// used by all the threads of the process.
+
+#![cfg(all(feature = "linux-replay", target_os = "linux"))]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+fn files(list: &[(&str, String)]) -> HashMap<PathBuf, String> {
+    list.iter()
+        .map(|(path, content)| (PathBuf::from(path), content.clone()))
+        .collect()
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn test_replay_system() {
+    use sysinfo::System;
+
+    // user nice system idle iowait irq softirq steal guest guest_nice
+    let stat = |cpu0: [u64; 4], cpu1: [u64; 4]| {
+        let line =
+            |name, v: [u64; 4]| format!("{name} {} 0 {} {} 0 0 0 {} 0 0\n", v[0], v[1], v[2], v[3]);
+        let global = [0, 1, 2, 3].map(|i| cpu0[i] + cpu1[i]);
+        format!(
+            "{}{}{}intr 0\n",
+            line("cpu ", global),
+            line("cpu0", cpu0),
+            line("cpu1", cpu1),
+        )
+    };
+    let meminfo = "\
+MemTotal:        1000 kB
+MemFree:          200 kB
+MemAvailable:     400 kB
+SwapTotal:          0 kB
+SwapFree:           0 kB
+";
+
+    let mut s = System::new();
+    sysinfo::replay(
+        files(&[
+            ("/proc/stat", stat([100, 100, 800, 0], [100, 100, 800, 0])),
+            ("/proc/meminfo", meminfo.to_owned()),
+        ]),
+        || {
+            s.refresh_cpu_all();
+            s.refresh_memory();
+        },
+    );
+    assert_eq!(s.cpus().len(), 2);
+    assert_eq!(s.total_memory(), 1_000 * 1_024);
+    assert_eq!(s.available_memory(), 400 * 1_024);
+
+    // No need to wait between two refreshes while replaying.
+    sysinfo::replay(
+        files(&[(
+            "/proc/stat",
+            // CPU 0: 50 busy ticks out of 100, CPU 1: 10 out of 100.
+            stat([125, 125, 850, 0], [105, 105, 890, 0]),
+        )]),
+        || s.refresh_cpu_usage(),
+    );
+    let is_close = |usage: f32, expected: f32| (usage - expected).abs() < 0.01;
+    assert!(is_close(s.cpus()[0].cpu_usage(), 50.));
+    assert!(is_close(s.cpus()[1].cpu_usage(), 10.));
+    assert!(is_close(s.global_cpu_usage(), 30.));
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_replay_networks() {
+    use sysinfo::Networks;
+
+    let net = |rx: u64, tx: u64| {
+        files(&[
+            ("/sys/class/net/replay0/mtu", "1500".to_owned()),
+            ("/sys/class/net/replay0/statistics/rx_bytes", rx.to_string()),
+            ("/sys/class/net/replay0/statistics/tx_bytes", tx.to_string()),
+        ])
+    };
+
+    let mut networks = Networks::new();
+    sysinfo::replay(net(1_000, 500), || networks.refresh(true));
+    assert_eq!(networks.len(), 1);
+    let data = &networks["replay0"];
+    assert_eq!(data.total_received(), 1_000);
+    assert_eq!(data.mtu(), 1_500);
+
+    sysinfo::replay(net(1_600, 700), || networks.refresh(true));
+    let data = &networks["replay0"];
+    assert_eq!(data.received(), 600);
+    assert_eq!(data.transmitted(), 200);
+    assert_eq!(data.total_transmitted(), 700);
+}
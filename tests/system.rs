@@ -160,12 +160,7 @@ fn test_consecutive_cpu_usage_update() {
         });
     }
 
-    let mut pids = sys
-        .processes()
-        .iter()
-        .map(|(pid, _)| *pid)
-        .take(2)
-        .collect::<Vec<_>>();
+    let mut pids = sys.processes().keys().copied().take(2).collect::<Vec<_>>();
     let pid = std::process::id();
     pids.push(Pid::from_u32(pid));
     assert_eq!(pids.len(), 3);